@@ -0,0 +1,20 @@
+//! Detects nightly compiler for `nightly-diagnostics` feature, which uses unstable `proc_macro` API
+
+use std::{env, process::Command};
+
+fn main() {
+	println!("cargo:rerun-if-changed=build.rs");
+	println!("cargo:rerun-if-env-changed=RUSTC_BOOTSTRAP");
+	if env::var_os("CARGO_FEATURE_NIGHTLY_DIAGNOSTICS").is_none() {
+		return;
+	}
+	let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+	let nightly = Command::new(rustc).arg("-vV").output().is_ok_and(|output| {
+		let version = String::from_utf8_lossy(&output.stdout);
+		version.contains("-nightly") || version.contains("-dev")
+	});
+	// Stable compiler accepts unstable features with `RUSTC_BOOTSTRAP=1`
+	if nightly || env::var_os("RUSTC_BOOTSTRAP").is_some_and(|value| value == "1") {
+		println!("cargo:rustc-cfg=real_c_string_nightly");
+	}
+}
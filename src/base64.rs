@@ -0,0 +1,124 @@
+//! Decoding of base64, used by `real_c_bytes_base64!`, implements RFC 4648 without depending on `base64` crate
+
+/// Alphabet of base64, alphabets differ only in characters of values 62 and 63
+#[derive(Clone, Copy)]
+pub(crate) enum Alphabet {
+	/// `+` and `/`
+	Standard,
+	/// `-` and `_`
+	UrlSafe,
+}
+
+impl Alphabet {
+	/// Returns characters of values 62 and 63
+	fn extra(&self) -> [char; 2] {
+		match self {
+			Self::Standard => ['+', '/'],
+			Self::UrlSafe => ['-', '_'],
+		}
+	}
+
+	/// Returns alphabet, characters of which are used in string, standard one if it's unknown
+	fn detect(string: &str) -> Result<Self, String> {
+		let standard = string.find(['+', '/']);
+		let url_safe = string.find(['-', '_']);
+		match (standard, url_safe) {
+			(Some(standard), Some(url_safe)) => Err(format!(
+				"Base64 mixes standard and URL-safe alphabets, at offsets {} and {}",
+				string[..standard].chars().count(),
+				string[..url_safe].chars().count()
+			)),
+			(None, Some(_)) => Ok(Self::UrlSafe),
+			_ => Ok(Self::Standard),
+		}
+	}
+
+	/// Returns value of character
+	fn value(&self, c: char) -> Option<u32> {
+		let extra = self.extra();
+		Some(match c {
+			'A'..='Z' => c as u32 - 'A' as u32,
+			'a'..='z' => c as u32 - 'a' as u32 + 26,
+			'0'..='9' => c as u32 - '0' as u32 + 52,
+			c if c == extra[0] => 62,
+			c if c == extra[1] => 63,
+			_ => return None,
+		})
+	}
+}
+
+/// Decodes base64 in passed alphabet, or detected one, whitespace is ignored and padding is optional
+///
+/// Returns error message with offset of offending character, offsets count characters.
+pub(crate) fn decode(string: &str, alphabet: Option<Alphabet>) -> Result<Vec<u8>, String> {
+	let alphabet = match alphabet {
+		Some(alphabet) => alphabet,
+		None => Alphabet::detect(string)?,
+	};
+	let mut bytes = Vec::new();
+	let mut buffer = 0u32;
+	let mut digits = 0;
+	// Offset of the first `=`, only padding and whitespace are allowed after it
+	let mut padding: Option<usize> = None;
+	let mut padding_len = 0;
+	// Offset of the last digit, which holds bits not belonging to any byte
+	let mut last = 0;
+	for (offset, c) in string.chars().enumerate() {
+		if c.is_whitespace() {
+			continue;
+		}
+		if c == '=' {
+			padding.get_or_insert(offset);
+			padding_len += 1;
+			continue;
+		}
+		let Some(value) = alphabet.value(c) else {
+			return Err(format!(
+				"Character {:?} at offset {} is not in base64 alphabet",
+				c, offset
+			));
+		};
+		if let Some(padding) = padding {
+			return Err(format!(
+				"Character {:?} at offset {} follows padding at offset {}",
+				c, offset, padding
+			));
+		}
+		buffer = buffer << 6 | value;
+		digits += 1;
+		last = offset;
+		if digits == 4 {
+			bytes.extend_from_slice(&buffer.to_be_bytes()[1..]);
+			buffer = 0;
+			digits = 0;
+		}
+	}
+	// Incomplete group of 2 or 3 digits holds 1 or 2 bytes, and is followed by 2 or 1 padding characters
+	let expected_padding = (4 - digits) % 4;
+	if digits == 1 {
+		return Err(format!(
+			"Base64 ends with a single digit at offset {}, which doesn't hold a byte",
+			last
+		));
+	}
+	if let Some(offset) = padding {
+		if padding_len != expected_padding {
+			return Err(format!(
+				"Invalid padding at offset {}, expected {} `=`, found {}",
+				offset, expected_padding, padding_len
+			));
+		}
+	}
+	if digits > 1 {
+		let unused_bits = 6 * digits - 8 * (digits - 1);
+		if buffer & ((1 << unused_bits) - 1) != 0 {
+			return Err(format!(
+				"Digit at offset {} has bits not belonging to any byte, base64 is not canonical",
+				last
+			));
+		}
+		let value = buffer >> unused_bits;
+		bytes.extend_from_slice(&value.to_be_bytes()[4 - (digits - 1)..]);
+	}
+	Ok(bytes)
+}
@@ -0,0 +1,103 @@
+//! Evaluation of builtin macros passed in place of string literals, i.e `concat!("app-", env!("CARGO_PKG_VERSION"))`
+//!
+//! Proc macros receive unexpanded tokens, so these macros are evaluated here the same way rustc does it.
+
+use syn::{
+	parse::{ParseStream, Result},
+	Token,
+};
+
+/// Returns name of builtin macro, if path refers to one
+fn builtin_name(path: &syn::Path) -> Option<String> {
+	let segments: Vec<_> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+	let name = match segments.as_slice() {
+		[name] if path.leading_colon.is_none() => name,
+		[krate, name] if krate == "core" || krate == "std" => name,
+		_ => return None,
+	};
+	match name.as_str() {
+		"concat" | "env" | "option_env" | "stringify" => Some(name.clone()),
+		_ => None,
+	}
+}
+
+/// Evaluates builtin macro invocation to string
+pub(crate) fn expand(mac: &syn::Macro) -> Result<String> {
+	let name = builtin_name(&mac.path).ok_or_else(|| {
+		syn::Error::new_spanned(&mac.path, "cannot expand non-builtin macro here")
+	})?;
+	match name.as_str() {
+		"concat" => mac.parse_body_with(parse_concat),
+		"env" => mac.parse_body_with(parse_env),
+		"option_env" => mac.parse_body_with(parse_option_env),
+		"stringify" => Ok(mac.tokens.to_string()),
+		_ => unreachable!("checked by builtin_name"),
+	}
+}
+
+/// Parses either nested macro invocation, or literal of any kind allowed by `concat!`
+fn parse_concat_item(input: ParseStream) -> Result<String> {
+	if input.peek(syn::Lit) {
+		return Ok(match input.parse()? {
+			syn::Lit::Str(str) => str.value(),
+			syn::Lit::Char(char) => char.value().to_string(),
+			syn::Lit::Int(int) => int.base10_digits().to_owned(),
+			syn::Lit::Float(float) => float.base10_digits().to_owned(),
+			syn::Lit::Bool(bool) => bool.value.to_string(),
+			other => {
+				return Err(syn::Error::new(
+					other.span(),
+					"cannot concatenate byte literals",
+				))
+			}
+		});
+	}
+	if input.peek(Token![-]) {
+		input.parse::<Token![-]>()?;
+		let number: syn::Lit = input.parse()?;
+		return match number {
+			syn::Lit::Int(int) => Ok(format!("-{}", int.base10_digits())),
+			syn::Lit::Float(float) => Ok(format!("-{}", float.base10_digits())),
+			other => Err(syn::Error::new(other.span(), "expected number after `-`")),
+		};
+	}
+	expand(&input.parse()?)
+}
+
+fn parse_concat(input: ParseStream) -> Result<String> {
+	let mut out = String::new();
+	while !input.is_empty() {
+		out.push_str(&parse_concat_item(input)?);
+		if input.is_empty() {
+			break;
+		}
+		input.parse::<Token![,]>()?;
+	}
+	Ok(out)
+}
+
+/// Parses variable name with optional custom error message
+fn parse_env(input: ParseStream) -> Result<String> {
+	let name: syn::LitStr = input.parse()?;
+	let mut message: Option<syn::LitStr> = None;
+	if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+		message = Some(input.parse()?);
+		input.parse::<Option<Token![,]>>()?;
+	}
+	std::env::var(name.value()).map_err(|_| {
+		let message = message.map(|m| m.value()).unwrap_or_else(|| {
+			format!(
+				"Environment variable `{}` not defined at compile time",
+				name.value()
+			)
+		});
+		syn::Error::new(name.span(), message)
+	})
+}
+
+/// Unset variable is expanded to empty string, as there is no way to represent `None` in C string
+fn parse_option_env(input: ParseStream) -> Result<String> {
+	let name: syn::LitStr = input.parse()?;
+	input.parse::<Option<Token![,]>>()?;
+	Ok(std::env::var(name.value()).unwrap_or_default())
+}
@@ -0,0 +1,106 @@
+//! LZSS compression of strings, decompressed at runtime by code generated by `real_c_string_compressed!`
+//!
+//! Compressed stream consists of groups of up to 8 tokens, each preceded by flag byte, where bit `N`
+//! describes token `N`, starting from least significant bit. Token with clear bit is a literal byte,
+//! token with set bit is a match of 2 bytes, forming little-endian `u16`: upper 12 bits are distance
+//! back minus 1, lower 4 bits are length minus 3.
+
+/// Max distance back to match start
+pub(crate) const MAX_DISTANCE: usize = 1 << 12;
+/// Min length of match, shorter matches are stored as literals
+pub(crate) const MIN_MATCH: usize = 3;
+/// Max length of match
+pub(crate) const MAX_MATCH: usize = MIN_MATCH + 15;
+/// Max number of previous positions checked for every match
+const MAX_CHAIN: usize = 256;
+
+/// Positions of previous bytes, grouped by hash of 3 bytes starting at them
+struct Window<'a> {
+	bytes: &'a [u8],
+	/// Last position with every hash
+	head: Vec<usize>,
+	/// Previous position with the same hash, for every position
+	previous: Vec<usize>,
+}
+
+impl<'a> Window<'a> {
+	fn new(bytes: &'a [u8]) -> Self {
+		Self {
+			bytes,
+			head: vec![usize::MAX; 1 << 16],
+			previous: vec![usize::MAX; bytes.len()],
+		}
+	}
+
+	/// Returns hash of 3 bytes, starting at passed position
+	fn hash(&self, position: usize) -> usize {
+		let value = u32::from(self.bytes[position]) << 16
+			| u32::from(self.bytes[position + 1]) << 8
+			| u32::from(self.bytes[position + 2]);
+		(value.wrapping_mul(2_654_435_761) >> 16) as usize
+	}
+
+	fn insert(&mut self, position: usize) {
+		if position + MIN_MATCH <= self.bytes.len() {
+			let hash = self.hash(position);
+			self.previous[position] = self.head[hash];
+			self.head[hash] = position;
+		}
+	}
+
+	/// Returns length and distance of the longest match for passed position
+	fn longest_match(&self, position: usize) -> (usize, usize) {
+		let mut best = (0, 0);
+		if position + MIN_MATCH > self.bytes.len() {
+			return best;
+		}
+		let mut candidate = self.head[self.hash(position)];
+		let mut chain = 0;
+		while candidate != usize::MAX && position - candidate <= MAX_DISTANCE && chain < MAX_CHAIN {
+			let len = self.bytes[candidate..]
+				.iter()
+				.zip(&self.bytes[position..])
+				.take(MAX_MATCH)
+				.take_while(|(a, b)| a == b)
+				.count();
+			if len > best.0 {
+				best = (len, position - candidate);
+			}
+			candidate = self.previous[candidate];
+			chain += 1;
+		}
+		best
+	}
+}
+
+/// Compresses passed bytes, greedily taking the longest match for every position
+pub(crate) fn compress(bytes: &[u8]) -> Vec<u8> {
+	let mut out = Vec::with_capacity(bytes.len() / 2);
+	let mut window = Window::new(bytes);
+	let mut flags_position = 0;
+	let mut tokens = 8;
+	let mut position = 0;
+	while position < bytes.len() {
+		if tokens == 8 {
+			flags_position = out.len();
+			out.push(0);
+			tokens = 0;
+		}
+		let (len, distance) = window.longest_match(position);
+		if len >= MIN_MATCH {
+			let token = ((distance - 1) << 4 | (len - MIN_MATCH)) as u16;
+			out.extend(token.to_le_bytes());
+			out[flags_position] |= 1 << tokens;
+			for position in position..position + len {
+				window.insert(position);
+			}
+			position += len;
+		} else {
+			out.push(bytes[position]);
+			window.insert(position);
+			position += 1;
+		}
+		tokens += 1;
+	}
+	out
+}
@@ -0,0 +1,68 @@
+//! Spans and warnings of diagnostics, which are precise with `nightly-diagnostics` feature on nightly compiler
+//!
+//! Stable proc macros can only fail with `compile_error!`, which points at the whole string, and can't warn.
+//! On nightly, errors point at the offending character inside of literal, and `proc_macro::Diagnostic`
+//! warnings are emitted, i.e for characters replaced by `lossy`. Messages are the same on both paths.
+
+use proc_macro2::Span;
+use std::ops::Range;
+
+/// Returns byte ranges of characters of value in source of string or character literal, i.e `"a\nb"`
+///
+/// Escapes are a single character, and line continuations are skipped. Returns `None` for other literals.
+pub(crate) fn char_ranges(source: &str) -> Option<Vec<Range<usize>>> {
+	let quote = source.find(['"', '\''])?;
+	let raw = match &source[..quote] {
+		"" => false,
+		prefix if prefix.starts_with('r') && prefix[1..].bytes().all(|b| b == b'#') => true,
+		_ => return None,
+	};
+	// Closing quote is followed by the same number of `#`, as opening one is preceded by
+	let end = source.len().checked_sub(quote.max(1))?;
+	let body = source.get(quote + 1..end)?;
+	let mut ranges = Vec::new();
+	let mut chars = body.char_indices().peekable();
+	while let Some((start, c)) = chars.next() {
+		if !raw && c == '\\' {
+			match chars.next() {
+				Some((_, '\n')) => {
+					while chars.next_if(|&(_, c)| c.is_whitespace()).is_some() {}
+					continue;
+				}
+				Some((_, 'u')) => {
+					while chars.next_if(|&(_, c)| c != '}').is_some() {}
+					chars.next();
+				}
+				Some((_, 'x')) => {
+					chars.next();
+					chars.next();
+				}
+				_ => {}
+			}
+		}
+		let next = chars.peek().map_or(body.len(), |&(next, _)| next);
+		ranges.push(quote + 1 + start..quote + 1 + next);
+	}
+	Some(ranges)
+}
+
+/// Returns span of character at passed index in value of string literal, if subspans are supported
+pub(crate) fn char_span(literal: &proc_macro2::Literal, index: usize) -> Option<Span> {
+	if !cfg!(real_c_string_nightly) {
+		return None;
+	}
+	let range = char_ranges(&literal.to_string())?.into_iter().nth(index)?;
+	literal.subspan(range)
+}
+
+/// Emits warning pointing at passed span, stable proc macros can't warn, so it's only emitted on nightly
+#[cfg(real_c_string_nightly)]
+pub(crate) fn warning(span: Span, message: &str) {
+	if proc_macro::is_available() {
+		span.unwrap().warning(message).emit();
+	}
+}
+
+/// Emits warning pointing at passed span, stable proc macros can't warn, so it's only emitted on nightly
+#[cfg(not(real_c_string_nightly))]
+pub(crate) fn warning(_span: Span, _message: &str) {}
@@ -0,0 +1,257 @@
+//! Legacy single-byte code pages, usable with `encoding = "windows-1251"` option
+//!
+//! Tables are generated from Python codecs, and hold characters for bytes `first..=0xff`, lower bytes are
+//! the same as in ASCII. Bytes undefined in code page are stored as `U+FFFD`, and never produced.
+//! EBCDIC tables cover every byte, IBM1047 differs from IBM037 in `[`, `]`, `^`, `¬`, `Ý` and `¨`.
+
+/// Single-byte encoding, with labels matching WHATWG encoding standard
+pub(crate) struct CodePage {
+	pub name: &'static str,
+	labels: &'static [&'static str],
+	first: u8,
+	table: &'static str,
+}
+
+impl CodePage {
+	/// Returns byte encoding passed character, or `None` if it is not mapped in this code page
+	pub fn encode(&self, value: char) -> Option<u8> {
+		if (value as u32) < u32::from(self.first) {
+			return Some(value as u8);
+		}
+		if value == char::REPLACEMENT_CHARACTER {
+			return None;
+		}
+		let position = self.table.chars().position(|c| c == value)?;
+		Some(self.first + position as u8)
+	}
+}
+
+/// Returns code page by its label, labels are case-insensitive
+pub(crate) fn find(label: &str) -> Option<&'static CodePage> {
+	let label = label.trim().to_ascii_lowercase();
+	CODE_PAGES
+		.iter()
+		.find(|code_page| code_page.labels.contains(&label.as_str()))
+}
+
+/// Returns name of multi-byte encoding by its WHATWG label, such encodings are recognized only to be rejected
+///
+/// Their tables hold thousands of characters each, and their strings can't be processed as one byte per
+/// character, so they are not supported.
+pub(crate) fn find_multi_byte(label: &str) -> Option<&'static str> {
+	let label = label.trim().to_ascii_lowercase();
+	MULTI_BYTE
+		.iter()
+		.find(|(_, labels)| labels.contains(&label.as_str()))
+		.map(|&(name, _)| name)
+}
+
+/// Returns names of every supported code page, for error messages
+pub(crate) fn names() -> String {
+	CODE_PAGES
+		.iter()
+		.map(|code_page| code_page.name)
+		.collect::<Vec<_>>()
+		.join(", ")
+}
+
+/// Multi-byte encodings, which are not supported, with labels matching WHATWG encoding standard
+const MULTI_BYTE: &[(&str, &[&str])] = &[
+	(
+		"Shift_JIS",
+		&[
+			"shift_jis",
+			"csshiftjis",
+			"ms932",
+			"ms_kanji",
+			"shift-jis",
+			"sjis",
+			"windows-31j",
+			"x-sjis",
+		],
+	),
+	(
+		"GBK",
+		&[
+			"gbk",
+			"chinese",
+			"csgb2312",
+			"csiso58gb231280",
+			"gb2312",
+			"gb_2312",
+			"gb_2312-80",
+			"iso-ir-58",
+			"x-gbk",
+			"gb18030",
+		],
+	),
+	(
+		"Big5",
+		&["big5", "big5-hkscs", "cn-big5", "csbig5", "x-x-big5"],
+	),
+];
+
+const CODE_PAGES: &[CodePage] = &[
+	CodePage {
+		name: "windows-1250",
+		labels: &["windows-1250", "cp1250", "x-cp1250"],
+		first: 0x80,
+		table: concat!(
+			"€\u{fffd}‚\u{fffd}„…†‡\u{fffd}‰Š‹ŚŤŽŹ\u{fffd}‘’“”•–—\u{fffd}™š›śťžź",
+			"\u{a0}ˇ˘Ł¤Ą¦§¨©Ş«¬\u{ad}®Ż°±˛ł´µ¶·¸ąş»Ľ˝ľż",
+			"ŔÁÂĂÄĹĆÇČÉĘËĚÍÎĎĐŃŇÓÔŐÖ×ŘŮÚŰÜÝŢß",
+			"ŕáâăäĺćçčéęëěíîďđńňóôőö÷řůúűüýţ˙",
+		),
+	},
+	CodePage {
+		name: "windows-1251",
+		labels: &["windows-1251", "cp1251", "x-cp1251"],
+		first: 0x80,
+		table: concat!(
+			"ЂЃ‚ѓ„…†‡€‰Љ‹ЊЌЋЏђ‘’“”•–—\u{fffd}™љ›њќћџ",
+			"\u{a0}ЎўЈ¤Ґ¦§Ё©Є«¬\u{ad}®Ї°±Ііґµ¶·ё№є»јЅѕї",
+			"АБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ",
+			"абвгдежзийклмнопрстуфхцчшщъыьэюя",
+		),
+	},
+	CodePage {
+		name: "windows-1252",
+		labels: &["windows-1252", "cp1252", "x-cp1252"],
+		first: 0x80,
+		table: concat!(
+			"€\u{fffd}‚ƒ„…†‡ˆ‰Š‹Œ\u{fffd}Ž\u{fffd}\u{fffd}‘’“”•–—˜™š›œ\u{fffd}žŸ",
+			"\u{a0}¡¢£¤¥¦§¨©ª«¬\u{ad}®¯°±²³´µ¶·¸¹º»¼½¾¿",
+			"ÀÁÂÃÄÅÆÇÈÉÊËÌÍÎÏÐÑÒÓÔÕÖ×ØÙÚÛÜÝÞß",
+			"àáâãäåæçèéêëìíîïðñòóôõö÷øùúûüýþÿ",
+		),
+	},
+	CodePage {
+		name: "windows-1253",
+		labels: &["windows-1253", "cp1253", "x-cp1253"],
+		first: 0x80,
+		table: concat!(
+			"€\u{fffd}‚ƒ„…†‡\u{fffd}‰\u{fffd}‹\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}‘’“”•–—\u{fffd}™\u{fffd}›\u{fffd}\u{fffd}\u{fffd}\u{fffd}",
+			"\u{a0}΅Ά£¤¥¦§¨©\u{fffd}«¬\u{ad}®―°±²³΄µ¶·ΈΉΊ»Ό½ΎΏ",
+			"ΐΑΒΓΔΕΖΗΘΙΚΛΜΝΞΟΠΡ\u{fffd}ΣΤΥΦΧΨΩΪΫάέήί",
+			"ΰαβγδεζηθικλμνξοπρςστυφχψωϊϋόύώ\u{fffd}",
+		),
+	},
+	CodePage {
+		name: "windows-1254",
+		labels: &["windows-1254", "cp1254", "x-cp1254"],
+		first: 0x80,
+		table: concat!(
+			"€\u{fffd}‚ƒ„…†‡ˆ‰Š‹Œ\u{fffd}\u{fffd}\u{fffd}\u{fffd}‘’“”•–—˜™š›œ\u{fffd}\u{fffd}Ÿ",
+			"\u{a0}¡¢£¤¥¦§¨©ª«¬\u{ad}®¯°±²³´µ¶·¸¹º»¼½¾¿",
+			"ÀÁÂÃÄÅÆÇÈÉÊËÌÍÎÏĞÑÒÓÔÕÖ×ØÙÚÛÜİŞß",
+			"àáâãäåæçèéêëìíîïğñòóôõö÷øùúûüışÿ",
+		),
+	},
+	CodePage {
+		name: "windows-1255",
+		labels: &["windows-1255", "cp1255", "x-cp1255"],
+		first: 0x80,
+		table: concat!(
+			"€\u{fffd}‚ƒ„…†‡ˆ‰\u{fffd}‹\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}‘’“”•–—˜™\u{fffd}›\u{fffd}\u{fffd}\u{fffd}\u{fffd}",
+			"\u{a0}¡¢£₪¥¦§¨©×«¬\u{ad}®¯°±²³´µ¶·¸¹÷»¼½¾¿",
+			"ְֱֲֳִֵֶַָֹ\u{fffd}ֻּֽ־ֿ׀ׁׂ׃װױײ׳״\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}",
+			"אבגדהוזחטיךכלםמןנסעףפץצקרשת\u{fffd}\u{fffd}\u{200e}\u{200f}\u{fffd}",
+		),
+	},
+	CodePage {
+		name: "windows-1256",
+		labels: &["windows-1256", "cp1256", "x-cp1256"],
+		first: 0x80,
+		table: concat!(
+			"€پ‚ƒ„…†‡ˆ‰ٹ‹Œچژڈگ‘’“”•–—ک™ڑ›œ\u{200c}\u{200d}ں",
+			"\u{a0}،¢£¤¥¦§¨©ھ«¬\u{ad}®¯°±²³´µ¶·¸¹؛»¼½¾؟",
+			"ہءآأؤإئابةتثجحخدذرزسشصض×طظعغـفقك",
+			"àلâمنهوçèéêëىيîïًٌٍَôُِ÷ّùْûü\u{200e}\u{200f}ے",
+		),
+	},
+	CodePage {
+		name: "windows-1257",
+		labels: &["windows-1257", "cp1257", "x-cp1257"],
+		first: 0x80,
+		table: concat!(
+			"€\u{fffd}‚\u{fffd}„…†‡\u{fffd}‰\u{fffd}‹\u{fffd}¨ˇ¸\u{fffd}‘’“”•–—\u{fffd}™\u{fffd}›\u{fffd}¯˛\u{fffd}",
+			"\u{a0}\u{fffd}¢£¤\u{fffd}¦§Ø©Ŗ«¬\u{ad}®Æ°±²³´µ¶·ø¹ŗ»¼½¾æ",
+			"ĄĮĀĆÄÅĘĒČÉŹĖĢĶĪĻŠŃŅÓŌÕÖ×ŲŁŚŪÜŻŽß",
+			"ąįāćäåęēčéźėģķīļšńņóōõö÷ųłśūüżž˙",
+		),
+	},
+	CodePage {
+		name: "windows-1258",
+		labels: &["windows-1258", "cp1258", "x-cp1258"],
+		first: 0x80,
+		table: concat!(
+			"€\u{fffd}‚ƒ„…†‡ˆ‰\u{fffd}‹Œ\u{fffd}\u{fffd}\u{fffd}\u{fffd}‘’“”•–—˜™\u{fffd}›œ\u{fffd}\u{fffd}Ÿ",
+			"\u{a0}¡¢£¤¥¦§¨©ª«¬\u{ad}®¯°±²³´µ¶·¸¹º»¼½¾¿",
+			"ÀÁÂĂÄÅÆÇÈÉÊË̀ÍÎÏĐÑ̉ÓÔƠÖ×ØÙÚÛÜỮß",
+			"àáâăäåæçèéêë́íîïđṇ̃óôơö÷øùúûüư₫ÿ",
+		),
+	},
+	CodePage {
+		name: "KOI8-R",
+		labels: &["koi8-r", "koi8", "koi8_r", "cskoi8r"],
+		first: 0x80,
+		table: concat!(
+			"─│┌┐└┘├┤┬┴┼▀▄█▌▐░▒▓⌠■∙√≈≤≥\u{a0}⌡°²·÷",
+			"═║╒ё╓╔╕╖╗╘╙╚╛╜╝╞╟╠╡Ё╢╣╤╥╦╧╨╩╪╫╬©",
+			"юабцдефгхийклмнопярстужвьызшэщчъ",
+			"ЮАБЦДЕФГХИЙКЛМНОПЯРСТУЖВЬЫЗШЭЩЧЪ",
+		),
+	},
+	CodePage {
+		name: "KOI8-U",
+		labels: &["koi8-u", "koi8-ru"],
+		first: 0x80,
+		table: concat!(
+			"─│┌┐└┘├┤┬┴┼▀▄█▌▐░▒▓⌠■∙√≈≤≥\u{a0}⌡°²·÷",
+			"═║╒ёє╔ії╗╘╙╚╛ґ╝╞╟╠╡ЁЄ╣ІЇ╦╧╨╩╪Ґ╬©",
+			"юабцдефгхийклмнопярстужвьызшэщчъ",
+			"ЮАБЦДЕФГХИЙКЛМНОПЯРСТУЖВЬЫЗШЭЩЧЪ",
+		),
+	},
+	CodePage {
+		name: "IBM866",
+		labels: &["ibm866", "866", "cp866", "csibm866"],
+		first: 0x80,
+		table: concat!(
+			"АБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ",
+			"абвгдежзийклмноп░▒▓│┤╡╢╖╕╣║╗╝╜╛┐",
+			"└┴┬├─┼╞╟╚╔╩╦╠═╬╧╨╤╥╙╘╒╓╫╪┘┌█▄▌▐▀",
+			"рстуфхцчшщъыьэюяЁёЄєЇїЎў°∙·√№¤■\u{a0}",
+		),
+	},
+	CodePage {
+		name: "IBM037",
+		labels: &["ibm037", "cp037", "ebcdic-cp-us"],
+		first: 0x00,
+		table: concat!(
+			"\u{0}\u{1}\u{2}\u{3}\u{9c}\u{9}\u{86}\u{7f}\u{97}\u{8d}\u{8e}\u{b}\u{c}\u{d}\u{e}\u{f}\u{10}\u{11}\u{12}\u{13}\u{9d}\u{85}\u{8}\u{87}\u{18}\u{19}\u{92}\u{8f}\u{1c}\u{1d}\u{1e}\u{1f}",
+			"\u{80}\u{81}\u{82}\u{83}\u{84}\u{a}\u{17}\u{1b}\u{88}\u{89}\u{8a}\u{8b}\u{8c}\u{5}\u{6}\u{7}\u{90}\u{91}\u{16}\u{93}\u{94}\u{95}\u{96}\u{4}\u{98}\u{99}\u{9a}\u{9b}\u{14}\u{15}\u{9e}\u{1a}",
+			" \u{a0}âäàáãåçñ¢.<(+|&éêëèíîïìß!$*);¬",
+			"-/ÂÄÀÁÃÅÇÑ¦,%_>?øÉÊËÈÍÎÏÌ`:#@'=\"",
+			"Øabcdefghi«»ðýþ±°jklmnopqrªºæ¸Æ¤",
+			"µ~stuvwxyz¡¿ÐÝÞ®^£¥·©§¶¼½¾[]¯¨´×",
+			"{ABCDEFGHI\u{ad}ôöòóõ}JKLMNOPQR¹ûüùúÿ",
+			"\\÷STUVWXYZ²ÔÖÒÓÕ0123456789³ÛÜÙÚ\u{9f}",
+		),
+	},
+	CodePage {
+		name: "IBM1047",
+		labels: &["ibm1047", "cp1047"],
+		first: 0x00,
+		table: concat!(
+			"\u{0}\u{1}\u{2}\u{3}\u{9c}\u{9}\u{86}\u{7f}\u{97}\u{8d}\u{8e}\u{b}\u{c}\u{d}\u{e}\u{f}\u{10}\u{11}\u{12}\u{13}\u{9d}\u{85}\u{8}\u{87}\u{18}\u{19}\u{92}\u{8f}\u{1c}\u{1d}\u{1e}\u{1f}",
+			"\u{80}\u{81}\u{82}\u{83}\u{84}\u{a}\u{17}\u{1b}\u{88}\u{89}\u{8a}\u{8b}\u{8c}\u{5}\u{6}\u{7}\u{90}\u{91}\u{16}\u{93}\u{94}\u{95}\u{96}\u{4}\u{98}\u{99}\u{9a}\u{9b}\u{14}\u{15}\u{9e}\u{1a}",
+			" \u{a0}âäàáãåçñ¢.<(+|&éêëèíîïìß!$*);^",
+			"-/ÂÄÀÁÃÅÇÑ¦,%_>?øÉÊËÈÍÎÏÌ`:#@'=\"",
+			"Øabcdefghi«»ðýþ±°jklmnopqrªºæ¸Æ¤",
+			"µ~stuvwxyz¡¿Ð[Þ®¬£¥·©§¶¼½¾Ý¨¯]´×",
+			"{ABCDEFGHI\u{ad}ôöòóõ}JKLMNOPQR¹ûüùúÿ",
+			"\\÷STUVWXYZ²ÔÖÒÓÕ0123456789³ÛÜÙÚ\u{9f}",
+		),
+	},
+];
@@ -0,0 +1,85 @@
+//! Interpretation of C escape sequences, used by `real_c_string_escaped!`
+
+use crate::Literal;
+use proc_macro2::Span;
+use std::{iter::Peekable, str::CharIndices};
+
+/// Consumes up to `max` digits of passed radix, returns their value and count
+fn digits(chars: &mut Peekable<CharIndices>, radix: u32, max: usize) -> (u32, usize) {
+	let mut value: u32 = 0;
+	let mut count = 0;
+	while count < max {
+		let Some(digit) = chars.peek().and_then(|&(_, c)| c.to_digit(radix)) else {
+			break;
+		};
+		chars.next();
+		value = value.saturating_mul(radix).saturating_add(digit);
+		count += 1;
+	}
+	(value, count)
+}
+
+/// Decodes C escape sequences in string, returns error message with offset of malformed escape
+///
+/// Escapes denote characters, so `\xff` is `ÿ`, and values which are not valid characters,
+/// i.e `\ud800`, are returned as explicit code units, spanned to passed span.
+pub(crate) fn unescape(string: &str, span: Span) -> Result<Vec<Literal>, String> {
+	let mut literals = Vec::new();
+	let mut current = String::new();
+	let mut chars = string.char_indices().peekable();
+	while let Some((offset, c)) = chars.next() {
+		if c != '\\' {
+			current.push(c);
+			continue;
+		}
+		let malformed = |sequence: &str| {
+			format!(
+				"Malformed escape sequence `\\{}` at offset {}",
+				sequence,
+				string[..offset].chars().count()
+			)
+		};
+		let Some((_, kind)) = chars.next() else {
+			return Err(malformed(""));
+		};
+		let value = match kind {
+			'n' => '\n' as u32,
+			't' => '\t' as u32,
+			'r' => '\r' as u32,
+			'a' => 0x07,
+			'b' => 0x08,
+			'f' => 0x0c,
+			'v' => 0x0b,
+			'\\' | '\'' | '"' | '?' => kind as u32,
+			'0'..='7' => {
+				let (rest, count) = digits(&mut chars, 8, 2);
+				(kind as u32 - '0' as u32) * 8u32.pow(count as u32) + rest
+			}
+			'x' => match digits(&mut chars, 16, usize::MAX) {
+				(_, 0) => return Err(malformed("x")),
+				(value, _) => value,
+			},
+			'u' | 'U' => {
+				let len = if kind == 'u' { 4 } else { 8 };
+				match digits(&mut chars, 16, len) {
+					(value, count) if count == len => value,
+					_ => return Err(malformed(&kind.to_string())),
+				}
+			}
+			other => return Err(malformed(&other.to_string())),
+		};
+		match char::from_u32(value) {
+			Some(c) => current.push(c),
+			None => {
+				if !current.is_empty() {
+					literals.push(Literal::Str(std::mem::take(&mut current)));
+				}
+				literals.push(Literal::Int(value, span));
+			}
+		}
+	}
+	if !current.is_empty() || literals.is_empty() {
+		literals.push(Literal::Str(current));
+	}
+	Ok(literals)
+}
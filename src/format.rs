@@ -0,0 +1,86 @@
+//! Formatting of literals at expansion time, used by `real_c_format!`
+
+/// Replaces `{}` and `{N}` in template with arguments, `{{` and `}}` are escapes of braces
+///
+/// Arguments are already formatted, as only literals are accepted. Same as `format!`, every
+/// argument should be used, which is not required for passed named values, i.e `{timestamp}`.
+/// Returns error message with offset of offending placeholder.
+pub(crate) fn format(
+	template: &str,
+	arguments: &[String],
+	named: &[(&str, &str)],
+) -> Result<String, String> {
+	let mut out = String::with_capacity(template.len());
+	let mut used = vec![false; arguments.len()];
+	let mut next = 0;
+	let mut chars = template.chars().enumerate().peekable();
+	while let Some((offset, c)) = chars.next() {
+		match c {
+			'{' if chars.next_if(|&(_, c)| c == '{').is_some() => out.push('{'),
+			'}' if chars.next_if(|&(_, c)| c == '}').is_some() => out.push('}'),
+			'}' => {
+				return Err(format!(
+					"Unmatched `}}` at offset {}, use `}}}}` for literal brace",
+					offset
+				))
+			}
+			'{' => {
+				let mut placeholder = String::new();
+				let closed = loop {
+					match chars.next() {
+						Some((_, '}')) => break true,
+						Some((_, c)) => placeholder.push(c),
+						None => break false,
+					}
+				};
+				if !closed {
+					return Err(format!(
+						"Unclosed `{{` at offset {}, use `{{{{` for literal brace",
+						offset
+					));
+				}
+				if let Some((_, value)) = named.iter().find(|(name, _)| *name == placeholder) {
+					out.push_str(value);
+					continue;
+				}
+				let index = if placeholder.is_empty() {
+					next += 1;
+					next - 1
+				} else if let Ok(index) = placeholder.parse::<usize>() {
+					index
+				} else if placeholder.contains(':') {
+					return Err(format!(
+						"Format spec in `{{{}}}` at offset {} is not supported, only `{{}}` and `{{N}}` are",
+						placeholder, offset
+					));
+				} else {
+					return Err(format!(
+						"Named argument `{{{}}}` at offset {} is not supported, only `{{}}` and `{{N}}` are",
+						placeholder, offset
+					));
+				};
+				let Some(argument) = arguments.get(index) else {
+					return Err(format!(
+						"Placeholder `{{{}}}` at offset {} refers to argument {}, but there {} {}",
+						placeholder,
+						offset,
+						index,
+						if arguments.len() == 1 { "is" } else { "are" },
+						match arguments.len() {
+							0 => "no arguments".to_owned(),
+							1 => "1 argument".to_owned(),
+							len => format!("{} arguments", len),
+						}
+					));
+				};
+				used[index] = true;
+				out.push_str(argument);
+			}
+			c => out.push(c),
+		}
+	}
+	if let Some(unused) = used.iter().position(|&used| !used) {
+		return Err(format!("Argument {} is never used", unused));
+	}
+	Ok(out)
+}
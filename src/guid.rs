@@ -0,0 +1,89 @@
+//! Validation and conversion of GUIDs, used by `real_c_guid_wstring!` and `real_c_guid!`
+
+/// Offsets of `-` in GUID without braces, i.e `0002DF01-0000-0000-C000-000000000046`
+const DASHES: [usize; 4] = [8, 13, 18, 23];
+
+/// Length of GUID without braces
+const LEN: usize = 36;
+
+/// GUID, in the same layout as `GUID` struct of Windows
+pub(crate) struct Guid {
+	data1: u32,
+	data2: u16,
+	data3: u16,
+	data4: [u8; 8],
+}
+
+impl Guid {
+	/// Parses GUID, with or without braces, digits are case-insensitive
+	///
+	/// Returns error message naming the problem, offsets count characters of passed string.
+	pub fn parse(string: &str) -> Result<Self, String> {
+		let chars: Vec<char> = string.chars().collect();
+		let braced = chars.first() == Some(&'{');
+		if braced && chars.last() != Some(&'}') {
+			return Err("GUID starting with `{` should end with `}`".to_owned());
+		}
+		let guid = if braced {
+			&chars[1..chars.len() - 1]
+		} else {
+			&chars[..]
+		};
+		if guid.len() != LEN {
+			return Err(format!(
+				"GUID should have {} characters, i.e `0002DF01-0000-0000-C000-000000000046`, found {}",
+				LEN,
+				guid.len()
+			));
+		}
+		let mut digits = Vec::with_capacity(32);
+		for (i, &c) in guid.iter().enumerate() {
+			let offset = i + usize::from(braced);
+			if DASHES.contains(&i) {
+				if c != '-' {
+					return Err(format!("Expected `-` at offset {}, found {:?}", offset, c));
+				}
+				continue;
+			}
+			match c.to_digit(16) {
+				Some(digit) => digits.push(digit as u8),
+				None => {
+					return Err(format!(
+						"Character {:?} at offset {} is not a hex digit",
+						c, offset
+					))
+				}
+			}
+		}
+		let byte = |i: usize| digits[i * 2] << 4 | digits[i * 2 + 1];
+		let mut data4 = [0; 8];
+		for (i, b) in data4.iter_mut().enumerate() {
+			*b = byte(8 + i);
+		}
+		Ok(Self {
+			data1: u32::from_be_bytes([byte(0), byte(1), byte(2), byte(3)]),
+			data2: u16::from_be_bytes([byte(4), byte(5)]),
+			data3: u16::from_be_bytes([byte(6), byte(7)]),
+			data4,
+		})
+	}
+
+	/// Returns braced uppercase form, as produced by `StringFromGUID2`
+	pub fn canonical(&self) -> String {
+		let d = &self.data4;
+		format!(
+			"{{{:08X}-{:04X}-{:04X}-{:02X}{:02X}-{:02X}{:02X}{:02X}{:02X}{:02X}{:02X}}}",
+			self.data1, self.data2, self.data3, d[0], d[1], d[2], d[3], d[4], d[5], d[6], d[7]
+		)
+	}
+
+	/// Returns bytes of `GUID` struct, first three fields are little-endian
+	pub fn to_bytes(&self) -> [u8; 16] {
+		let mut bytes = [0; 16];
+		bytes[..4].copy_from_slice(&self.data1.to_le_bytes());
+		bytes[4..6].copy_from_slice(&self.data2.to_le_bytes());
+		bytes[6..8].copy_from_slice(&self.data3.to_le_bytes());
+		bytes[8..].copy_from_slice(&self.data4);
+		bytes
+	}
+}
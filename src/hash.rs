@@ -0,0 +1,60 @@
+//! Hashes of encoded strings, computed at expansion time by `real_c_string_hash_*!` macros
+
+/// FNV-1a hash with 32-bit state
+pub(crate) fn fnv1a32(bytes: &[u8]) -> u32 {
+	let mut hash: u32 = 0x811c_9dc5;
+	for &byte in bytes {
+		hash ^= u32::from(byte);
+		hash = hash.wrapping_mul(0x0100_0193);
+	}
+	hash
+}
+
+/// FNV-1a hash with 64-bit state
+pub(crate) fn fnv1a64(bytes: &[u8]) -> u64 {
+	let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+	for &byte in bytes {
+		hash ^= u64::from(byte);
+		hash = hash.wrapping_mul(0x0100_0000_01b3);
+	}
+	hash
+}
+
+/// Initial state of djb2 hash
+pub(crate) const DJB2_SEED: u32 = 5381;
+
+/// Classic djb2 hash, `hash * 33 + byte`, with bytes taken as unsigned
+pub(crate) fn djb2(bytes: &[u8], seed: u32) -> u32 {
+	bytes.iter().fold(seed, |hash, &byte| {
+		hash.wrapping_mul(33).wrapping_add(u32::from(byte))
+	})
+}
+
+/// Reflected CRC-32 polynomial of IEEE 802.3, used by zlib, PNG and Ethernet
+pub(crate) const CRC32_IEEE: u32 = 0xedb8_8320;
+
+/// Reflected CRC-32C polynomial by Castagnoli, used by iSCSI, SSE 4.2 and ext4
+pub(crate) const CRC32_CASTAGNOLI: u32 = 0x82f6_3b78;
+
+/// Reflected CRC-32 with passed polynomial, initial value and final xor are `0xffffffff`
+pub(crate) fn crc32(bytes: &[u8], polynomial: u32) -> u32 {
+	let mut crc = !0u32;
+	for &byte in bytes {
+		crc ^= u32::from(byte);
+		for _ in 0..8 {
+			crc = if crc & 1 == 1 {
+				(crc >> 1) ^ polynomial
+			} else {
+				crc >> 1
+			};
+		}
+	}
+	!crc
+}
+
+/// Hash, which rotates state right by 13 bits and adds every byte, often used to resolve imports
+pub(crate) fn ror13(bytes: &[u8]) -> u32 {
+	bytes.iter().fold(0, |hash: u32, &byte| {
+		hash.rotate_right(13).wrapping_add(u32::from(byte))
+	})
+}
@@ -0,0 +1,44 @@
+//! Decoding of hex dumps, used by `real_c_bytes_hex!`
+
+/// Decodes pairs of hex digits, separated by whitespace or commas, every group may be prefixed with `0x`
+///
+/// Returns error message with offset of offending character, offsets count characters.
+pub(crate) fn decode(string: &str) -> Result<Vec<u8>, String> {
+	let chars: Vec<char> = string.chars().collect();
+	let mut bytes = Vec::new();
+	// Offset and value of first digit of byte, which is being decoded
+	let mut high: Option<(usize, u8)> = None;
+	let mut group_start = true;
+	let unpaired = |offset: usize| format!("Hex digit at offset {} has no pair", offset);
+	let mut i = 0;
+	while i < chars.len() {
+		let c = chars[i];
+		if c.is_whitespace() || c == ',' {
+			if let Some((offset, _)) = high {
+				return Err(unpaired(offset));
+			}
+			group_start = true;
+			i += 1;
+			continue;
+		}
+		if group_start && c == '0' && matches!(chars.get(i + 1), Some('x' | 'X')) {
+			group_start = false;
+			i += 2;
+			continue;
+		}
+		group_start = false;
+		let digit = c
+			.to_digit(16)
+			.ok_or_else(|| format!("Character {:?} at offset {} is not a hex digit", c, i))?
+			as u8;
+		match high.take() {
+			Some((_, value)) => bytes.push(value << 4 | digit),
+			None => high = Some((i, digit)),
+		}
+		i += 1;
+	}
+	match high {
+		Some((offset, _)) => Err(unpaired(offset)),
+		None => Ok(bytes),
+	}
+}
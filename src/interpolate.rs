@@ -0,0 +1,71 @@
+//! Substitution of environment variables in string literals, enabled by `interpolate` option
+
+/// Returns true if character may be used in variable name, digits can't start it
+fn is_name_char(c: char) -> bool {
+	c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Replaces every `${NAME}` with value of variable, and `${NAME:-fallback}` with fallback if it's unset or empty
+///
+/// `$$` is an escape of dollar sign. Returns error message naming unset variable, or offset of malformed reference.
+pub(crate) fn interpolate(
+	string: &str,
+	var: impl Fn(&str) -> Option<String>,
+) -> Result<String, String> {
+	let mut out = String::with_capacity(string.len());
+	let mut chars = string.chars().enumerate().peekable();
+	while let Some((offset, c)) = chars.next() {
+		if c != '$' {
+			out.push(c);
+			continue;
+		}
+		match chars.next() {
+			Some((_, '$')) => {
+				out.push('$');
+				continue;
+			}
+			Some((_, '{')) => {}
+			_ => {
+				return Err(format!(
+					"Lone `$` at offset {}, use `${{NAME}}` for variables, or `$$` for dollar sign",
+					offset
+				))
+			}
+		}
+		let mut reference = String::new();
+		let closed = loop {
+			match chars.next() {
+				Some((_, '}')) => break true,
+				Some((_, c)) => reference.push(c),
+				None => break false,
+			}
+		};
+		if !closed {
+			return Err(format!("Unclosed `${{` at offset {}", offset));
+		}
+		let (name, fallback) = match reference.split_once(":-") {
+			Some((name, fallback)) => (name, Some(fallback)),
+			None => (reference.as_str(), None),
+		};
+		let valid = name.chars().next().is_some_and(|c| !c.is_ascii_digit())
+			&& name.chars().all(is_name_char);
+		if !valid {
+			return Err(format!(
+				"`{}` at offset {} is not a valid variable name, expected ASCII letters, digits and `_`",
+				name, offset
+			));
+		}
+		match (var(name), fallback) {
+			(Some(value), Some(fallback)) if value.is_empty() => out.push_str(fallback),
+			(Some(value), _) => out.push_str(&value),
+			(None, Some(fallback)) => out.push_str(fallback),
+			(None, None) => {
+				return Err(format!(
+					"Environment variable `{}` not defined at compile time, use `${{{}:-fallback}}` for default value",
+					name, name
+				))
+			}
+		}
+	}
+	Ok(out)
+}
@@ -0,0 +1,35 @@
+//! Detection of bidirectional control and invisible characters, which make source read differently than string
+//!
+//! Such characters are used in Trojan Source attacks, and are rejected unless `allow_invisible` is passed.
+
+/// Ranges of rejected characters, with their names
+const INVISIBLE: &[(char, char, &str)] = &[
+	('\u{00ad}', '\u{00ad}', "SOFT HYPHEN"),
+	('\u{061c}', '\u{061c}', "ARABIC LETTER MARK"),
+	('\u{180e}', '\u{180e}', "MONGOLIAN VOWEL SEPARATOR"),
+	('\u{200b}', '\u{200b}', "ZERO WIDTH SPACE"),
+	('\u{200c}', '\u{200c}', "ZERO WIDTH NON-JOINER"),
+	('\u{200d}', '\u{200d}', "ZERO WIDTH JOINER"),
+	('\u{200e}', '\u{200e}', "LEFT-TO-RIGHT MARK"),
+	('\u{200f}', '\u{200f}', "RIGHT-TO-LEFT MARK"),
+	('\u{202a}', '\u{202a}', "LEFT-TO-RIGHT EMBEDDING"),
+	('\u{202b}', '\u{202b}', "RIGHT-TO-LEFT EMBEDDING"),
+	('\u{202c}', '\u{202c}', "POP DIRECTIONAL FORMATTING"),
+	('\u{202d}', '\u{202d}', "LEFT-TO-RIGHT OVERRIDE"),
+	('\u{202e}', '\u{202e}', "RIGHT-TO-LEFT OVERRIDE"),
+	('\u{2060}', '\u{2060}', "WORD JOINER"),
+	('\u{2061}', '\u{2064}', "INVISIBLE OPERATOR"),
+	('\u{2066}', '\u{2066}', "LEFT-TO-RIGHT ISOLATE"),
+	('\u{2067}', '\u{2067}', "RIGHT-TO-LEFT ISOLATE"),
+	('\u{2068}', '\u{2068}', "FIRST STRONG ISOLATE"),
+	('\u{2069}', '\u{2069}', "POP DIRECTIONAL ISOLATE"),
+	('\u{feff}', '\u{feff}', "ZERO WIDTH NO-BREAK SPACE"),
+];
+
+/// Returns name of character, if it is bidirectional control or invisible one
+pub(crate) fn name(c: char) -> Option<&'static str> {
+	INVISIBLE
+		.iter()
+		.find(|&&(first, last, _)| (first..=last).contains(&c))
+		.map(|&(_, _, name)| name)
+}
@@ -1,148 +1,8098 @@
+//! Proc macros producing strings with the same representation as that of strings in C at ASM level
+//!
+//! # Options
+//!
+//! Options may be passed before string, i.e `real_c_string!(no_nul "TAG!")`,
+//! every option may be followed by comma. Supported options are:
+//! - `no_nul`: don't append terminating zero.
+//!   **Result is not a valid C string**, only use it for fixed-size fields, which are not terminated.
+//! - `with_nul`: append terminating zero to strings, which are not terminated by default, i.e Pascal
+//!   strings, and include it in bytes hashed by hash macros.
+//! - `terminators = N`: append `N` terminating zeros instead of one, i.e for `pFrom` and `pTo` of
+//!   `SHFILEOPSTRUCT`, which are lists of paths, ending with two NULs even if there is only one path.
+//!   Lengths exclude every terminator, while sizes and `max_len` include them.
+//! - `terminator = 0xff`: append passed code unit instead of zero, i.e for protocols terminating strings
+//!   with `0xff` or ETX. Terminator should fit in code unit, and is rejected if it appears in string, as
+//!   consumers couldn't find end of string then, unless `allow_terminator_in_content` is passed. Lengths
+//!   and sizes count it the same way as zero, and hash macros include it with `with_nul`.
+//! - `max_len = N`: fail compilation if string doesn't fit in `N` code units, including terminator.
+//! - `dedent`: remove common leading whitespace from every line, the same way `indoc` does it,
+//!   spaces and tabs both count as one character, first line is removed if empty.
+//! - `interpolate`: replace every `${NAME}` with value of environment variable at compile time, before
+//!   other options are applied and string is checked, `${NAME:-fallback}` gives fallback if variable
+//!   is unset or empty, and `$$` is an escape of dollar sign. Unset variables without fallback fail
+//!   compilation. Changes of variables don't trigger recompilation, same as for `env!` in place of literals.
+//! - `strip_comments`: remove `//` and `/* */` comments from C-like source, i.e embedded shaders, and collapse
+//!   runs of whitespace to a newline, if they contain one, or to a space. Comments inside of string and character
+//!   literals are kept, unterminated block comments and literals fail compilation. It is applied before
+//!   other options, except for `interpolate`.
+//! - `trim_end`: remove trailing whitespace from every line, line endings are kept.
+//! - `skip_empty`: omit empty lines from arrays produced by `real_c_lines!`, and empty strings joined by
+//!   `real_c_string_join!`.
+//! - `allow_invisible`: allow bidirectional control characters, i.e `U+202E RIGHT-TO-LEFT OVERRIDE`,
+//!   and invisible ones, i.e `U+200D ZERO WIDTH JOINER`, which are rejected by default, because they
+//!   make source read differently than string, as in Trojan Source attacks. Escapes of them are rejected too.
+//! - `translit`: replace non-ASCII characters with ASCII transliterations, i.e `"Müller – café"` with
+//!   `"Muller - cafe"`, after other options are applied. Latin letters lose diacritics, Cyrillic and Greek
+//!   letters are romanized, and typographic punctuation is replaced with ASCII one. Characters without
+//!   transliteration fail compilation, unless `lossy` is passed. Requires `transliteration` feature.
+//! - `lossy`: replace characters, which can't be stored in string, with `?` instead of failing compilation,
+//!   i.e `"–"` in narrow string, or characters rejected by `ascii`. `replacement = '*'` replaces them with
+//!   passed character instead, and implies `lossy`. Replacement itself should be possible to store.
+//!   Stable proc macros can't emit warnings, so replacements are silent, unless `nightly-diagnostics`
+//!   feature is enabled on nightly compiler, and should only be used for strings, which don't need to be
+//!   exact, i.e debug labels.
+//! - `allow_interior_nul`: allow NUL characters inside of string, which are rejected by default,
+//!   because C consumers would stop reading string at the first NUL. Explicit zero code units,
+//!   i.e `"a", 0, "b"`, are always allowed.
+//! - `newlines = "lf"` or `newlines = "crlf"`: replace every line ending, including lone `\r`,
+//!   with the specified one.
+//! - `ascii`: fail compilation on any character outside of printable ASCII, `0x20..=0x7e`, also
+//!   checks bytes of byte strings. `ascii_whitespace` additionally allows `\t`, `\r` and `\n`.
+//! - `printf_safe`: fail compilation if string contains `printf` conversion specifiers, which
+//!   consume arguments, only `%%` is allowed. Use it for strings, passed to `printf` as format.
+//! - `printf_format`: fail compilation if string is not a valid `printf` format: specifiers are
+//!   incomplete or unknown, `%n` is used, or positional arguments are mixed with sequential ones.
+//!   Offsets in errors of both checks count characters.
+//! - `upper` or `lower`: convert strings to uppercase or lowercase. Only ASCII characters are converted,
+//!   unless `unicode-case` feature is enabled, with it full Unicode mapping is used, which may change
+//!   length of string, i.e `"ß"` becomes `"SS"`.
+//! - `normalize = "nfc"` or `normalize = "nfd"`: convert strings to specified Unicode normalization form,
+//!   so strings compare bytewise equal regardless of how they were typed. Requires `normalization`
+//!   feature.
+//! - `encoding = "windows-1251"`: encode narrow strings in legacy single-byte code page, characters
+//!   missing in it are rejected. Supported code pages are `windows-1250` to `windows-1258`, `KOI8-R`,
+//!   `KOI8-U`, `IBM866`, and EBCDIC `IBM037` and `IBM1047`, labels are the same as in WHATWG encoding
+//!   standard, i.e `cp1251`. Multi-byte `Shift_JIS`, `GBK` and `Big5` are not supported.
+//! - `ebcdic`: same as `encoding = "IBM1047"`, which is the default EBCDIC code page on z/OS.
+//! - `big_endian`: store code units of wide strings in big-endian byte order, i.e for UTF-16BE consumers.
+//!   Every code unit is converted with `to_be` for the target, so on little-endian targets `"A"`
+//!   gives `0x4100`, and on big-endian targets this option changes nothing. By default, code units
+//!   are stored in native byte order of target.
+//! - `bom`: prepend byte order mark `U+FEFF` to wide strings, it is swapped by `big_endian` too,
+//!   and counted by `max_len` and lengths.
+//! - `utf16le`: treat byte strings as UTF-16LE code units, i.e for data exported by Windows tools, which
+//!   isn't representable as `str`. Bytes are paired, so odd length fails compilation, and leading byte order
+//!   mark is removed, `bom` should be used to store it. Code units should form valid UTF-16, unless `raw`
+//!   is passed. Only supported by UTF-16 strings.
+//! - `ucs2`: reject characters above `U+FFFF` in wide strings, instead of encoding them as UTF-16
+//!   surrogate pairs, for consumers which only support UCS-2.
+//! - `symbol = "name"`: store string in static, exported with passed symbol name, i.e to be found and
+//!   patched by external tools after linking. Name should consist of ASCII letters, digits, `_`, `.` and `$`,
+//!   and not start with digit. Only macros returning pointers support it, every invocation with the same
+//!   name should be unique, otherwise linking fails.
+//! - `section = ".vmp1"`: store string in static, placed in passed link section instead of default
+//!   read-only data section. Name is passed to linker verbatim, as conventions differ between formats:
+//!   Mach-O requires segment and section, i.e `section = "__DATA,__vmp1"`. Only macros returning
+//!   pointers support it.
+//! - `align = 16`: store string in static, aligned to passed number of bytes, which should be
+//!   a power of two, i.e for DMA buffers. Only macros returning pointers support it.
+//! - `keep`: store string in `#[used]` static, so it's kept by linker even if it is never used,
+//!   i.e for markers, which are only searched by external tools. Only macros returning pointers support it.
+//! - `addr_stable`: store string in static, unique to invocation, so every evaluation of invocation gives
+//!   the same address, and it differs from addresses of other invocations, even with the same content,
+//!   i.e when address is used as a key. Strings are not merged with identical ones then, so it opts out
+//!   of deduplication, described at `real_c_string!`. Only macros returning pointers support it.
+//! - `dedup`: store string in static, exported with symbol derived from hash of crate, encoding and content,
+//!   i.e `__real_c_str_0123456789abcdef`, so invocations of crate with the same string share it, even in
+//!   different modules. Strings of different crates are not merged, as linker rejects symbol defined twice.
+//!   It can't be combined with `symbol` and `addr_stable`. Only macros returning pointers support it.
+//! - `sync = Type`: wrap pointer in `Sync` type, declared by `real_c_sync_ptr!`, so it can be stored in
+//!   statics. Only macros returning pointers support it, pointer type should match wrapped one.
+//! - `no_register`: don't add string to registry, enabled by `registry` feature, i.e for secrets, which
+//!   shouldn't be enumerated. Option is accepted without the feature too.
+//! - `utf8`: encode narrow strings as UTF-8, so characters above `U+00FF` are accepted and take
+//!   several code units. By default every character is stored as a single byte, and characters
+//!   which don't fit are rejected. Offsets in errors and `max_len` count bytes, not characters.
+//!
+//! ```rust
+//! use real_c_string::{
+//!     real_c_string_array, real_c_string_len, real_c_string_ptr_len, real_c_string_size,
+//!     real_c_wstring_array,
+//! };
+//! let tag: [i8; 4] = real_c_string_array!(no_nul "TAG!");
+//! assert_eq!(tag, [84i8, 65i8, 71i8, 33i8]);
+//! let (_, len) = real_c_string_ptr_len!(no_nul, "TAG!");
+//! assert_eq!(len, 4);
+//!
+//! let name: [i8; 32] = real_c_string_array!(max_len = 32, "exactly thirty one characters..");
+//!
+//! let shader = real_c_string_array!(dedent "
+//!     void main() {
+//!         gl_FragColor = vec4(1.0);
+//!     }
+//! ");
+//! assert_eq!(shader, b"void main() {\n    gl_FragColor = vec4(1.0);\n}\n\0".map(|b| b as i8));
+//!
+//! let minified = real_c_string_array!(strip_comments "
+//!     #version 330 // GLSL 3.30
+//!     /* Multiline
+//!        comment */ void   main() {
+//!         printf(\"// not a comment\");
+//!     }
+//! ");
+//! assert_eq!(minified, b"#version 330\nvoid main() {\nprintf(\"// not a comment\");\n}\0".map(|b| b as i8));
+//!
+//! // Whitespace-only lines are not counted, and tabs count as single character
+//! let mixed = real_c_string_array!(dedent "first\n\t\t\tsecond\n  \n    \t\n  third");
+//! assert_eq!(mixed, b"first\n\tsecond\n\n  \t\nthird\0".map(|b| b as i8));
+//!
+//! let crlf = real_c_string_array!(newlines = "crlf", "a\r\nb\nc\rd");
+//! assert_eq!(crlf, b"a\r\nb\r\nc\r\nd\0".map(|b| b as i8));
+//! let lf = real_c_string_array!(newlines = "lf", "a\r\nb\nc\rd\n\r");
+//! assert_eq!(lf, b"a\nb\nc\nd\n\n\0".map(|b| b as i8));
+//!
+//! let double_terminated = real_c_string_array!(allow_interior_nul "a\0b");
+//! assert_eq!(double_terminated, [97i8, 0i8, 98i8, 0i8]);
+//! let explicit = real_c_string_array!("a", 0, "b");
+//! assert_eq!(explicit, [97i8, 0i8, 98i8, 0i8]);
+//!
+//! let request = real_c_string_array!(ascii_whitespace "GET / HTTP/1.1\r\n");
+//! assert_eq!(request, b"GET / HTTP/1.1\r\n\0".map(|b| b as i8));
+//!
+//! let progress = real_c_string_array!(printf_safe "Load: 95%% done");
+//! let format = real_c_string_array!(printf_format "%-8s %5.2f%% %lld " "%*d");
+//! let positional = real_c_string_array!(printf_format "%2$s %1$s");
+//!
+//! let header = real_c_string_array!(lower "Content-Type");
+//! assert_eq!(header, b"content-type\0".map(|b| b as i8));
+//! let key = real_c_wstring_array!(upper "Software\\MyApp");
+//! assert_eq!(key, real_c_wstring_array!("SOFTWARE\\MYAPP"));
+//!
+//! let greek = real_c_string_array!(encoding = "windows-1253", "Ωμέγα");
+//! assert_eq!(greek, [0xd9u8, 0xec, 0xdd, 0xe3, 0xe1, 0].map(|b| b as i8));
+//!
+//! let user = real_c_string_array!(ebcdic "USERID0");
+//! assert_eq!(user, [0xe4u8, 0xe2, 0xc5, 0xd9, 0xc9, 0xc4, 0xf0, 0x00].map(|b| b as i8));
+//! let brackets = real_c_string_array!(encoding = "cp037", "A0[]");
+//! assert_eq!(brackets, [0xc1u8, 0xf0, 0xba, 0xbb, 0x00].map(|b| b as i8));
+//! let brackets = real_c_string_array!(encoding = "cp1047", "A0[]");
+//! assert_eq!(brackets, [0xc1u8, 0xf0, 0xad, 0xbd, 0x00].map(|b| b as i8));
+//!
+//! let user_agent = real_c_string_array!(interpolate "${CARGO_PKG_NAME}/${CARGO_PKG_VERSION} $$HOME");
+//! assert_eq!(user_agent, b"real_c_string/1.0.1 $HOME\0".map(|b| b as i8));
+//! let channel = real_c_string_array!(interpolate "${REAL_C_STRING_SURELY_UNSET_VARIABLE:-stable}");
+//! assert_eq!(channel, b"stable\0".map(|b| b as i8));
+//!
+//! let label = real_c_string_array!(lossy "Load – done");
+//! assert_eq!(label, b"Load ? done\0".map(|b| b as i8));
+//! let label = real_c_string_array!(ascii, replacement = '_', "naïve…");
+//! assert_eq!(label, b"na_ve_\0".map(|b| b as i8));
+//! let label = real_c_wstring_array!(ucs2, lossy "🎉!");
+//! assert_eq!(label, [b'?' as i16, b'!' as i16, 0]);
+//!
+//! let hello = real_c_string_array!(utf8 "Привет");
+//! assert_eq!(hello.len(), "Привет".chars().count() * 2 + 1);
+//! let bytes: Vec<i8> = "Привет".bytes().map(|b| b as i8).chain([0]).collect();
+//! assert_eq!(hello[..], bytes[..]);
+//!
+//! let framed = real_c_string_array!(terminator = 0xff, "OK");
+//! assert_eq!(framed, [0x4f, 0x4b, -1]);
+//! let text = real_c_string_array!(terminator = 0x03, terminators = 2, "\x02DATA");
+//! assert_eq!(text, [0x02, 0x44, 0x41, 0x54, 0x41, 0x03, 0x03]);
+//! assert_eq!(real_c_string_size!(terminator = 0xff, "OK"), 3);
+//! let raw = real_c_string_array!(terminator = 0xff, allow_terminator_in_content, "\u{ff}");
+//! assert_eq!(raw, [-1, -1]);
+//!
+//! let padded = real_c_string_array!(terminators = 3, "FW");
+//! assert_eq!(padded, [0x46, 0x57, 0, 0, 0]);
+//! // `pFrom` of `SHFILEOPSTRUCTW` is a list of paths, ending with two NULs
+//! let from = real_c_wstring_array!(terminators = 2, "C:\\Temp\\old.log");
+//! assert_eq!(from[from.len() - 3..], [0x67, 0, 0]);
+//! assert_eq!(real_c_string_len!(terminators = 3, "FW"), 2);
+//! assert_eq!(real_c_string_size!(terminators = 3, "FW"), 5);
+//! assert_eq!(real_c_string_ptr_len!(terminators = 2, "FW").1, 2);
+//! ```
+//!
+//! ```rust
+//! use real_c_string::{real_c_string, real_c_wstring};
+//! let marker = real_c_string!(symbol = "vmp_marker_begin", "MARKER");
+//! let wide = real_c_wstring!(symbol = "vmp_wide_marker", "Ж");
+//!
+//! extern "C" {
+//!     static vmp_marker_begin: [i8; 7];
+//!     static vmp_wide_marker: [i16; 2];
+//! }
+//! assert_eq!(unsafe { vmp_marker_begin }, b"MARKER\0".map(|b| b as i8));
+//! assert_eq!(unsafe { vmp_marker_begin.as_ptr() }, marker);
+//! assert_eq!(unsafe { vmp_wide_marker }, [0x416, 0]);
+//! assert_eq!(unsafe { vmp_wide_marker.as_ptr() }, wide);
+//!
+//! #[cfg(not(target_vendor = "apple"))]
+//! let secret = real_c_string!(section = ".vmp1", "secret");
+//! #[cfg(target_vendor = "apple")]
+//! let secret = real_c_string!(section = "__DATA,__vmp1", "secret");
+//! assert_eq!(unsafe { std::ffi::CStr::from_ptr(secret) }.to_bytes(), b"secret");
+//!
+//! #[cfg(not(target_vendor = "apple"))]
+//! let secret = real_c_wstring!(section = ".vmp1", symbol = "vmp_wide_secret", "секрет");
+//! #[cfg(target_vendor = "apple")]
+//! let secret = real_c_wstring!(section = "__DATA,__vmp1", symbol = "vmp_wide_secret", "секрет");
+//! assert_eq!(unsafe { *secret.add(5) }, 0x442);
+//!
+//! let aligned = real_c_string!(align = 16, "DMA");
+//! assert_eq!(aligned as usize % 16, 0);
+//! assert_eq!(unsafe { std::ffi::CStr::from_ptr(aligned) }.to_bytes(), b"DMA");
+//! let aligned = real_c_wstring!(align = 4096, symbol = "dma_page", "DMA");
+//! assert_eq!(aligned as usize % 4096, 0);
+//! assert_eq!(unsafe { *aligned.add(3) }, 0);
+//!
+//! // Marker is never used, but is present in binary
+//! let _ = real_c_string!(keep, section = ".vmp1", "KEEP_MARKER_0xfe");
+//! let binary = std::fs::read(std::env::current_exe().unwrap()).unwrap();
+//! let marker: Vec<u8> = b"\0efx0_REKRAM_PEEK".iter().rev().copied().collect();
+//! assert!(binary.windows(marker.len()).any(|window| window == marker));
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // Unterminated block comment at offset 10
+//! let shader = real_c_string!(strip_comments "void main /* never closed");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // alignment should be a power of two, not greater than 2^29
+//! let aligned = real_c_string!(align = 24, "DMA");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // `1marker` is not a valid symbol name
+//! let marker = real_c_string!(symbol = "1marker", "MARKER");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // Character '\u{a0}' (U+00A0) at offset 4 is not printable ASCII
+//! let verb = real_c_string!(ascii "MAIL\u{a0}FROM");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // Character '\r' (U+000D) at offset 3 is not printable ASCII
+//! let method = real_c_string!(ascii "GET\r\n");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // Format specifier `% d` at offset 8 consumes argument, use `%%` for literal percent sign
+//! let progress = real_c_string!(printf_safe "Load: 95% done");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // Format specifier `%n` at offset 2 writes to memory, and is not allowed
+//! let format = real_c_string!(printf_format "%s%n");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! let name = real_c_string!(max_len = 32, "exactly thirty two characters...");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // Environment variable `REAL_C_STRING_SURELY_UNSET_VARIABLE` not defined at compile time, ...
+//! let host = real_c_string!(interpolate "https://${REAL_C_STRING_SURELY_UNSET_VARIABLE}/api");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // Values of variables are checked, as any other characters: author of crate is "Лач <iam@lach.pw>"
+//! let authors = real_c_string!(ascii interpolate "${CARGO_PKG_AUTHORS}");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // Invisible character U+202E (RIGHT-TO-LEFT OVERRIDE) at offset 5 makes source read differently than string, ...
+//! let command = real_c_string!("rm -r\u{202e}/ fr-");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_wstring;
+//! // Invisible character U+200B (ZERO WIDTH SPACE) at offset 5 makes source read differently than string, ...
+//! let user = real_c_wstring!("admin\u{200b}");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // Interior NUL character at offset 3, C consumers will stop reading string there, ...
+//! let c_string = real_c_string!("abc\0def");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // Terminator 0xff appears at offset 1, so consumers can't find end of string, ...
+//! let framed = real_c_string!(terminator = 0xff, "y\u{ff}");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_wstring;
+//! // Terminator 0x10000 doesn't fit, max is 0xffff
+//! let framed = real_c_wstring!(terminator = 0x10000, "OK");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // Unsupported character '–' (U+2013) at offset 5
+//! let label = real_c_string!("Load – done");
+//! ```
+//!
+//! ```compile_fail
+//! use real_c_string::real_c_string;
+//! // Replacement character '�' (U+FFFD) can't be stored in string itself
+//! let label = real_c_string!(replacement = '\u{fffd}', "Load – done");
+//! ```
+//!
+//! # Diagnostics
+//!
+//! On stable compiler, errors about content of string point at the whole string, as `compile_error!` can't
+//! point inside of literals, and no warnings are emitted. With `nightly-diagnostics` feature on nightly
+//! compiler, errors point at the offending character, i.e at invisible one, while text isn't changed by
+//! options, and characters replaced by `lossy` are reported as warnings. Messages are the same for both.
+//! Feature has no effect on stable compiler, so it can be enabled unconditionally.
+//!
+//! # Minimum supported Rust version
+//!
+//! Crate requires Rust 1.88, as declared by `rust-version`, and so does code expanded by its macros.
+//!
+//! # Constant evaluation
+//!
+//! `real_c_string!`, `real_c_wstring!` and other macros returning pointers to string data expand to inline
+//! `const` blocks, so they are guaranteed to be evaluated at compile time, and can be used in `const` items
+//! and `const fn`. Strings stored in statics by `symbol`, `section`, `align` or
+//! `keep` options, and strings built at runtime, i.e by `real_c_string_obf!`, are not constants.
+//!
+//! Pointers are not `Sync`, so they can't be stored in statics as is. Use `real_c_static!`, which
+//! produces arrays, or wrap pointer in type implementing `Sync`, which is declared by `real_c_sync_ptr!`,
+//! and is produced by macros returning pointers with `sync = Type` option:
+//!
+//! ```rust
+//! use real_c_string::{real_c_string, real_c_wstring};
+//! const GREETING: *const i8 = real_c_string!("hi");
+//!
+//! const fn class_name() -> *const i16 {
+//!     real_c_wstring!("Window")
+//! }
+//!
+//! struct StaticCString(*const i8);
+//! // Pointed string is immutable, and lives as long as the program
+//! unsafe impl Sync for StaticCString {}
+//! static NAME: StaticCString = StaticCString(real_c_string!("name"));
+//!
+//! assert_eq!(unsafe { std::ffi::CStr::from_ptr(GREETING) }.to_bytes(), b"hi");
+//! assert_eq!(unsafe { *class_name() }, 'W' as i16);
+//! assert_eq!(unsafe { std::ffi::CStr::from_ptr(NAME.0) }.to_bytes(), b"name");
+//!
+//! real_c_string::real_c_sync_ptr!(pub struct CStrPtr(*const i8));
+//! static NAMES: [CStrPtr; 3] = [
+//!     real_c_string!(sync = CStrPtr, "a"),
+//!     real_c_string!(sync = CStrPtr, "b"),
+//!     real_c_string!(sync = CStrPtr, utf8 "ц"),
+//! ];
+//! let lengths = std::thread::spawn(|| {
+//!     NAMES.map(|name| unsafe { std::ffi::CStr::from_ptr(name.as_ptr()) }.to_bytes().len())
+//! });
+//! assert_eq!(lengths.join().unwrap(), [1, 1, 2]);
+//! ```
+
+#![cfg_attr(real_c_string_nightly, feature(proc_macro_diagnostic))]
+
 extern crate proc_macro;
 
+#[cfg(feature = "base64")]
+mod base64;
+mod builtin;
+#[cfg(feature = "compression")]
+mod compress;
+mod diagnostic;
+mod encoding;
+mod escape;
+mod format;
+mod guid;
+mod hash;
+mod hex;
+mod interpolate;
+mod invisible;
+mod nonce;
+#[cfg(feature = "normalization")]
+mod normalize;
+mod obfuscate;
+mod options;
+mod path;
+mod printf;
+#[cfg(feature = "registry")]
+mod registry;
+mod rename;
+#[cfg(test)]
+mod tests;
+mod text;
+mod timestamp;
+#[cfg(feature = "transliteration")]
+mod translit;
+
+use options::Options;
+use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{
 	parse::{Parse, ParseStream, Result},
 	parse_macro_input,
 };
 
+/// Literal passed to proc macro
+#[derive(Clone)]
+enum Literal {
+	Str(String),
+	/// Bytes are passed through verbatim, so only supported by narrow strings
+	ByteStr(Vec<u8>),
+	/// Single code unit, passed through verbatim, i.e `0x1f`
+	Int(u32, proc_macro2::Span),
+}
+
+impl Literal {
+	/// Returns true if next token may start a literal, or a builtin macro expanding to one
+	fn peek(input: ParseStream) -> bool {
+		input.peek(syn::Lit) || input.peek(syn::Ident) || input.peek(syn::Token![::])
+	}
+
+	/// Parses literal, or code units spliced verbatim, i.e `raw(0xd800, 0x41)`, which give several literals
+	fn parse_all(input: ParseStream) -> Result<Vec<Self>> {
+		let is_raw = input.peek(syn::Ident)
+			&& input.peek2(syn::token::Paren)
+			&& input.fork().parse::<syn::Ident>().is_ok_and(|i| i == "raw");
+		if !is_raw {
+			return Ok(vec![input.parse()?]);
+		}
+		let name: syn::Ident = input.parse()?;
+		let content;
+		syn::parenthesized!(content in input);
+		let units = content.parse_terminated(syn::LitInt::parse, syn::Token![,])?;
+		if units.is_empty() {
+			return Err(syn::Error::new(
+				name.span(),
+				"expected code units, i.e `raw(0xd800)`",
+			));
+		}
+		units
+			.iter()
+			.map(|unit| Ok(Literal::Int(unit.base10_parse()?, unit.span())))
+			.collect()
+	}
+}
+
+/// Describes what macros accept, appended to parse errors
+const EXPECTED_INPUT: &str =
+	"macro takes string literals, optionally preceded by options, i.e `no_nul \"TAG\"`";
+
+/// Returns error spanned on the next token, naming it
+fn unexpected(input: ParseStream, expected: &str) -> syn::Error {
+	match input.fork().parse::<proc_macro2::TokenTree>() {
+		Ok(token) => syn::Error::new(
+			token.span(),
+			format!(
+				"expected {}, found `{}`, {}",
+				expected, token, EXPECTED_INPUT
+			),
+		),
+		Err(_) => input.error(format!("expected {}, {}", expected, EXPECTED_INPUT)),
+	}
+}
+
+impl Parse for Literal {
+	fn parse(input: ParseStream) -> Result<Self> {
+		if !input.peek(syn::Lit) {
+			if input.fork().parse::<syn::Macro>().is_err() {
+				return Err(unexpected(input, "string literal"));
+			}
+			return Ok(Literal::Str(builtin::expand(&input.parse()?)?));
+		}
+		Ok(match input.parse()? {
+			syn::Lit::Str(str) => Literal::Str(str.value()),
+			syn::Lit::ByteStr(str) => Literal::ByteStr(str.value()),
+			// Single character is treated as a string of one character
+			syn::Lit::Char(char) => Literal::Str(char.value().to_string()),
+			syn::Lit::Int(int) => Literal::Int(int.base10_parse()?, int.span()),
+			syn::Lit::Byte(byte) => Literal::Int(byte.value() as u32, byte.span()),
+			other => {
+				let found = match other {
+					syn::Lit::Float(_) => "float literal",
+					syn::Lit::Bool(_) => "bool literal",
+					_ => "unknown literal",
+				};
+				return Err(syn::Error::new(
+					other.span(),
+					format!(
+						"expected string, byte string, char or integer literal, found {}",
+						found
+					),
+				));
+			}
+		})
+	}
+}
+
 /// Contains string parsed from tokens passed to proc macro
+///
+/// Adjacent literals are concatenated, as in C: `"first " "second"`, literals may also be separated
+/// by commas, to mix them with code units: `"MAGIC", 0x02, "v1"`
 struct RealCString {
-	string: String,
+	options: Options,
+	literals: Vec<Literal>,
+	/// Span of string literals, errors about their content point at it
+	span: proc_macro2::Span,
+	/// Tokens of string literals, in order of `literals`, kept while text is not changed by options
+	sources: Vec<Option<proc_macro2::Literal>>,
+}
+
+impl RealCString {
+	/// Replaces every string literal with result of passed function
+	fn map_strings(&mut self, f: impl Fn(&str) -> String) {
+		for literal in &mut self.literals {
+			if let Literal::Str(string) = literal {
+				*string = f(string);
+			}
+		}
+	}
+
+	/// Returns span of character at passed index of passed argument, or span of string if it's unknown
+	fn char_span(&self, argument: usize, index: usize) -> proc_macro2::Span {
+		self.sources
+			.get(argument)
+			.and_then(Option::as_ref)
+			.and_then(|source| diagnostic::char_span(source, index))
+			.unwrap_or(self.span)
+	}
+
+	/// Applies text transformations, requested by options
+	fn apply_options(&mut self) -> Result<()> {
+		let code_points = self.code_points();
+		if self.options.interpolate {
+			let span = self.span;
+			for literal in &mut self.literals {
+				if let Literal::Str(string) = literal {
+					*string = interpolate::interpolate(string, |name| std::env::var(name).ok())
+						.map_err(|message| syn::Error::new(span, message))?;
+				}
+			}
+		}
+		if self.options.strip_comments {
+			let span = self.span;
+			for literal in &mut self.literals {
+				if let Literal::Str(string) = literal {
+					*string = text::strip_comments(string)
+						.map_err(|message| syn::Error::new(span, message))?;
+				}
+			}
+		}
+		if self.options.dedent {
+			self.map_strings(text::dedent);
+		}
+		if self.options.trim_end {
+			self.map_strings(text::trim_line_ends);
+		}
+		if let Some(newlines) = self.options.newlines {
+			self.map_strings(|s| text::normalize_newlines(s, newlines.as_str()));
+		}
+		if let Some(case) = self.options.case {
+			self.map_strings(match case {
+				options::Case::Upper => text::to_upper,
+				options::Case::Lower => text::to_lower,
+			});
+		}
+		#[cfg(feature = "normalization")]
+		if let Some(normalization) = self.options.normalize {
+			self.map_strings(match normalization {
+				options::Normalization::Nfc => normalize::nfc,
+				options::Normalization::Nfd => normalize::nfd,
+			});
+		}
+		#[cfg(feature = "transliteration")]
+		if self.options.translit {
+			let (span, replacement) = (self.span, self.options.lossy);
+			for literal in &mut self.literals {
+				if let Literal::Str(string) = literal {
+					*string = translit::transliterate(string, replacement)
+						.map_err(|message| syn::Error::new(span, message))?;
+				}
+			}
+		}
+		// Characters of changed text don't match ones in tokens anymore
+		if self.code_points() != code_points {
+			self.sources.clear();
+		}
+		Ok(())
+	}
+
+	/// Splits string on `\n` and `\r\n` in string literals, as `str::lines` does, line endings are removed
+	///
+	/// Other literals are kept in the line they are in, empty lines are skipped with `skip_empty` option.
+	fn into_lines(self) -> Vec<RealCString> {
+		let mut lines = Vec::new();
+		let mut current = Vec::new();
+		let mut finish = |mut line: Vec<Literal>| {
+			if let Some(Literal::Str(last)) = line.last_mut() {
+				if last.ends_with('\r') {
+					last.pop();
+				}
+			}
+			let empty = line
+				.iter()
+				.all(|l| matches!(l, Literal::Str(s) if s.is_empty()));
+			if empty && self.options.skip_empty {
+				return;
+			}
+			lines.push(RealCString {
+				options: self.options.clone(),
+				literals: if empty {
+					vec![Literal::Str(String::new())]
+				} else {
+					line
+				},
+				span: self.span,
+				sources: Vec::new(),
+			});
+		};
+		for literal in self.literals.iter() {
+			let Literal::Str(string) = literal else {
+				current.push(literal.clone());
+				continue;
+			};
+			let mut parts = string.split('\n');
+			current.extend(parts.next().map(|part| Literal::Str(part.to_owned())));
+			for part in parts {
+				finish(std::mem::take(&mut current));
+				current.push(Literal::Str(part.to_owned()));
+			}
+		}
+		// Last line is only present if it isn't empty, as in `str::lines`
+		if !current
+			.iter()
+			.all(|l| matches!(l, Literal::Str(s) if s.is_empty()))
+		{
+			finish(current);
+		}
+		lines
+	}
+
+	/// Returns concatenated string, code units which are not characters are replaced with `U+FFFD`
+	fn text(&self) -> String {
+		self.code_points()
+			.into_iter()
+			.map(|c| char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER))
+			.collect()
+	}
+
+	/// Returns code points of concatenated string, bytes of byte strings are returned as is
+	fn code_points(&self) -> Vec<u32> {
+		self.literals
+			.iter()
+			.flat_map(|literal| -> Vec<u32> {
+				match literal {
+					Literal::Str(string) => string.chars().map(|c| c as u32).collect(),
+					Literal::ByteStr(bytes) => bytes.iter().map(|&b| b as u32).collect(),
+					Literal::Int(value, _) => vec![*value],
+				}
+			})
+			.collect()
+	}
+}
+
+impl RealCString {
+	/// Parses string, its options override passed ones
+	fn parse_with(input: ParseStream, options: Options) -> Result<Self> {
+		let options = Options::parse_with(input, options)?;
+		if input.is_empty() {
+			return Err(unexpected(input, "string literal"));
+		}
+		let first = input.span();
+		let mut last = first;
+		let mut literals = Vec::new();
+		let mut sources = Vec::new();
+		loop {
+			// Tokens are kept, so errors can point at characters inside of literals
+			sources.push(match input.fork().parse() {
+				Ok(syn::Lit::Str(string)) => Some(string.token()),
+				Ok(syn::Lit::Char(char)) => Some(char.token()),
+				_ => None,
+			});
+			literals.extend(Literal::parse_all(input)?);
+			sources.resize(literals.len(), None);
+			if input.peek(syn::Token![,]) {
+				input.parse::<syn::Token![,]>()?;
+				if input.is_empty() {
+					break;
+				}
+			} else if !Literal::peek(input) {
+				break;
+			}
+			last = input.span();
+		}
+		// Spans can only be joined on nightly, otherwise errors point at the first literal
+		let span = first.join(last).unwrap_or(first);
+		let mut string = RealCString {
+			options,
+			literals,
+			span,
+			sources,
+		};
+		string.apply_options()?;
+		Ok(string)
+	}
 }
 
 impl Parse for RealCString {
 	fn parse(input: ParseStream) -> Result<Self> {
-		if let syn::Lit::Str(str) = input.parse()? {
-			Ok(RealCString {
-				string: str.value(),
-			})
-		} else {
-			Err(input.error("expected Str instead of ByteStr"))
+		let string = Self::parse_with(input, Options::default())?;
+		if !input.is_empty() {
+			return Err(unexpected(input, "`,` or string literal"));
 		}
+		Ok(string)
 	}
 }
 
-#[derive(Copy, Clone)]
-enum TransformType {
-	CString,
-	CWString,
+/// Contains string with optional size of resulting array before it, i.e `64, "string"`
+struct SizedRealCString {
+	size: Option<usize>,
+	string: RealCString,
 }
 
-impl TransformType {
-	/// Returns max character that can fit into this transform
-	fn max_char(&self) -> u32 {
-		match self {
-			Self::CString => 0xff,
-			Self::CWString => 0xffff,
+impl Parse for SizedRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let size = if input.peek(syn::LitInt) {
+			let size: syn::LitInt = input.parse()?;
+			input.parse::<syn::Token![,]>()?;
+			Some(size.base10_parse()?)
+		} else {
+			None
+		};
+		Ok(SizedRealCString {
+			size,
+			string: input.parse()?,
+		})
+	}
+}
+
+/// String, preceded by label of code page it should be encoded in, i.e `"windows-1251", "Привет"`
+struct EncodedRealCString(RealCString);
+
+impl Parse for EncodedRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let code_page = Options::code_page(&input.parse()?)?;
+		input.parse::<syn::Token![,]>()?;
+		let mut string: RealCString = input.parse()?;
+		string.options.code_page = Some(code_page);
+		Ok(EncodedRealCString(string))
+	}
+}
+
+/// String, followed by pairs of substrings and their replacements, i.e `"a/b", "/", "\\"`
+struct ReplacedRealCString(RealCString);
+
+impl ReplacedRealCString {
+	/// Parses string literal or builtin macro invocation, used for string and replacement pairs
+	fn string(input: ParseStream) -> Result<String> {
+		let span = input.span();
+		match input.parse()? {
+			Literal::Str(string) => Ok(string),
+			_ => Err(syn::Error::new(span, "expected string literal")),
 		}
 	}
 }
 
-/// Transforms passed string to needed form, used by proc macro at bottom
-fn transform(input: RealCString, transform_type: TransformType) -> proc_macro::TokenStream {
-	use TransformType::{CString, CWString};
-
-	let stream = {
-		let bytes: Vec<_> = input
-			.string
-			.chars()
-			.enumerate()
-			.map(|(offset, cur_char)| {
-				let out = if cur_char as u32 <= transform_type.max_char() {
-					cur_char
-				} else {
-					return quote! {
-						::core::compile_error!(
-							::core::concat!(
-								"Unsupported character \"", #cur_char, "\" at offset ", #offset
-							)
-						),
-					};
+impl Parse for ReplacedRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let options = Options::parse(input)?;
+		let span = input.span();
+		let mut string = Self::string(input)?;
+		while input.parse::<Option<syn::Token![,]>>()?.is_some() && !input.is_empty() {
+			let span = input.span();
+			let from = Self::string(input)?;
+			if from.is_empty() {
+				return Err(syn::Error::new(span, "replaced substring can't be empty"));
+			}
+			input.parse::<syn::Token![,]>()?;
+			let to = Self::string(input)?;
+			string = string.replace(&from, &to);
+		}
+		let mut string = RealCString {
+			options,
+			literals: vec![Literal::Str(string)],
+			span,
+			sources: Vec::new(),
+		};
+		string.apply_options()?;
+		Ok(ReplacedRealCString(string))
+	}
+}
+
+/// Comma-separated strings, sharing options, i.e `no_nul "Text files", "*.txt"`
+struct RealCStringList {
+	options: Options,
+	strings: Vec<(String, proc_macro2::Span)>,
+}
+
+impl RealCStringList {
+	/// Returns every string as separate `RealCString`, with options applied
+	fn into_strings(self) -> Result<Vec<RealCString>> {
+		let options = self.options;
+		self.strings
+			.into_iter()
+			.map(|(string, span)| {
+				let mut string = RealCString {
+					options: options.clone(),
+					literals: vec![Literal::Str(string)],
+					span,
+					sources: Vec::new(),
 				};
-				match transform_type {
-					CString => {
-						let res = out as i8;
-						quote! {#res,}
-					}
-					CWString => {
-						let res = out as i16;
-						quote! {#res,}
-					}
-				}
+				string.apply_options()?;
+				Ok(string)
 			})
-			.collect();
-		match transform_type {
-			CString => quote! {
-				&[#(#bytes)* 0i8,] as *const i8
+			.collect()
+	}
+}
+
+impl Parse for RealCStringList {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let options = Options::parse(input)?;
+		let mut strings = Vec::new();
+		while !input.is_empty() {
+			let span = input.span();
+			match input.parse()? {
+				Literal::Str(string) => strings.push((string, span)),
+				_ => return Err(syn::Error::new(span, "expected string literal")),
+			}
+			if input.is_empty() {
+				break;
+			}
+			input.parse::<syn::Token![,]>()?;
+		}
+		if strings.is_empty() {
+			return Err(input.error("expected at least one string"));
+		}
+		Ok(RealCStringList { options, strings })
+	}
+}
+
+/// Contains string prefixed with optional initial state of hash, i.e `seed = 0, "string"`
+struct SeededRealCString {
+	seed: Option<u32>,
+	string: RealCString,
+}
+
+/// Parses argument of macro, which is passed before options, i.e `seed = 0,`, if next tokens are it
+fn named_argument<T: Parse>(input: ParseStream, name: &str) -> Result<Option<T>> {
+	let fork = input.fork();
+	if !input.peek2(syn::Token![=]) || !fork.parse::<syn::Ident>().is_ok_and(|i| i == name) {
+		return Ok(None);
+	}
+	input.parse::<syn::Ident>()?;
+	input.parse::<syn::Token![=]>()?;
+	let value = input.parse()?;
+	input.parse::<Option<syn::Token![,]>>()?;
+	Ok(Some(value))
+}
+
+impl Parse for SeededRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let seed = match named_argument::<syn::LitInt>(input, "seed")? {
+			Some(seed) => Some(seed.base10_parse()?),
+			None => None,
+		};
+		Ok(SeededRealCString {
+			seed,
+			string: input.parse()?,
+		})
+	}
+}
+
+/// Contains string prefixed with optional hash algorithm, i.e `algorithm = "ror13", "string"`
+struct CaseInsensitiveRealCString {
+	algorithm: fn(&[u8]) -> u32,
+	string: RealCString,
+}
+
+impl Parse for CaseInsensitiveRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let algorithm = match named_argument::<syn::LitStr>(input, "algorithm")? {
+			None => hash::fnv1a32,
+			Some(name) => match name.value().as_str() {
+				"fnv1a" => hash::fnv1a32,
+				"ror13" => hash::ror13,
+				_ => {
+					return Err(syn::Error::new(
+						name.span(),
+						"expected \"fnv1a\" or \"ror13\"",
+					))
+				}
+			},
+		};
+		let mut string: RealCString = input.parse()?;
+		string.map_strings(|s| s.to_ascii_uppercase());
+		Ok(CaseInsensitiveRealCString { algorithm, string })
+	}
+}
+
+/// Contains string prefixed with optional number of chunks, i.e `chunks = 4, "string"`
+struct SplitRealCString {
+	chunks: usize,
+	string: RealCString,
+}
+
+impl Parse for SplitRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let chunks = match named_argument::<syn::LitInt>(input, "chunks")? {
+			None => 4,
+			Some(chunks) => match chunks.base10_parse()? {
+				0 => {
+					return Err(syn::Error::new(
+						chunks.span(),
+						"string should be split into at least one chunk",
+					))
+				}
+				value => value,
+			},
+		};
+		Ok(SplitRealCString {
+			chunks,
+			string: input.parse()?,
+		})
+	}
+}
+
+/// Contains string prefixed with optional obfuscation key, i.e `key = 0x5a, "string"`
+struct KeyedRealCString {
+	key: Option<u32>,
+	string: RealCString,
+}
+
+impl Parse for KeyedRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let key = match named_argument::<syn::LitInt>(input, "key")? {
+			Some(key) => match key.base10_parse()? {
+				0 => return Err(syn::Error::new(key.span(), "key should be non-zero")),
+				value => Some(value),
+			},
+			None => None,
+		};
+		Ok(KeyedRealCString {
+			key,
+			string: input.parse()?,
+		})
+	}
+}
+
+/// Contains string prefixed with optional CRC-32 polynomial, i.e `poly = "crc32c", "string"`
+struct Crc32RealCString {
+	polynomial: u32,
+	string: RealCString,
+}
+
+impl Parse for Crc32RealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let polynomial = match named_argument::<syn::LitStr>(input, "poly")? {
+			None => hash::CRC32_IEEE,
+			Some(name) => match name.value().as_str() {
+				"ieee" => hash::CRC32_IEEE,
+				"crc32c" => hash::CRC32_CASTAGNOLI,
+				_ => {
+					return Err(syn::Error::new(
+						name.span(),
+						"expected \"ieee\" or \"crc32c\"",
+					))
+				}
 			},
-			CWString => quote! {
-				&[#(#bytes)* 0i16,] as *const i16
+		};
+		Ok(Crc32RealCString {
+			polynomial,
+			string: input.parse()?,
+		})
+	}
+}
+
+/// Contains string prefixed with optional byte order, i.e `endian = "big", "RIFF"`
+struct FourccRealCString {
+	big_endian: bool,
+	string: RealCString,
+}
+
+impl Parse for FourccRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let big_endian = match named_argument::<syn::LitStr>(input, "endian")? {
+			None => false,
+			Some(endian) => match endian.value().as_str() {
+				"little" => false,
+				"big" => true,
+				_ => {
+					return Err(syn::Error::new(
+						endian.span(),
+						"expected \"little\" or \"big\"",
+					))
+				}
 			},
+		};
+		let string: RealCString = input.parse()?;
+		Ok(FourccRealCString {
+			big_endian: big_endian || string.options.big_endian,
+			string,
+		})
+	}
+}
+
+/// String, preceded by required size of buffer, i.e `64, "my-hostname"`
+struct PaddedRealCString(SizedRealCString);
+
+impl Parse for PaddedRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		if !input.peek(syn::LitInt) {
+			return Err(input.error("expected size of buffer"));
 		}
-	};
-	proc_macro::TokenStream::from(stream)
+		Ok(PaddedRealCString(input.parse()?))
+	}
 }
 
-/// Produces a C-string literal with the same representation as that of strings in C at ASM level
-/// Used in vmprotect crate, because vmprotect disassembles code and finds usages like this.
-///
-/// The result of this macro invocation is of type `*const i8`.
-///
-/// ```rust
-/// use real_c_string::real_c_string;
-/// assert_eq!(0i8, unsafe { *real_c_string!("") });
-///
-/// let c_string = real_c_string!("Hello world!");
-/// let same_as_array_of_bytes: [i8; 13] =
-///     [72i8, 101i8, 108i8, 108i8, 111i8, 32i8, 119i8, 111i8, 114i8, 108i8, 100i8, 33i8, 0i8];
-/// for i in 0..13 {
-///     assert_eq!(
-///         same_as_array_of_bytes[i],
-///         unsafe { *c_string.offset(i as isize) }
-///     );
-/// }
-/// ```
-#[proc_macro]
-pub fn real_c_string(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-	transform(
-		parse_macro_input!(input as RealCString),
-		TransformType::CString,
-	)
+/// Strings, preceded by size of row and optional count of rows, i.e `4, 16, "north", "south"`
+struct MatrixRealCString {
+	rows: Option<(usize, proc_macro2::Span)>,
+	width: usize,
+	strings: RealCStringList,
 }
 
-/// Same as `real_c_string`, but used for wchar_t* strings
-///
-/// The result of this macro invocation is of type `*const i16`.
-///
-/// ```rust
-/// use real_c_string::real_c_wstring;
-/// assert_eq!(0i16, unsafe { *real_c_wstring!("") });
-///
-/// let c_wstring = real_c_wstring!("Hello world!");
-/// let same_as_array_of_bytes: [i16; 13] =
-///     [72i16, 101i16, 108i16, 108i16, 111i16, 32i16, 119i16, 111i16, 114i16, 108i16, 100i16, 33i16, 0i16];
-/// for i in 0..13 {
-///     assert_eq!(
-///         same_as_array_of_bytes[i],
-///         unsafe { *c_wstring.offset(i as isize) },
-///     );
-/// }
-///
-/// let c_wstring = real_c_wstring!("Привет world!");
-/// let same_as_array_of_bytes: [i16; 14]
-///     = [1055i16, 1088i16, 1080i16, 1074i16, 1077i16, 1090i16, 32i16, 119i16, 111i16, 114i16, 108i16, 100i16, 33i16, 0i16];
-/// for i in 0..13 {
-///     assert_eq!(
-///         same_as_array_of_bytes[i],
-///         unsafe { *c_wstring.offset(i as isize) },
-///     );
-/// }
-/// ```
-#[proc_macro]
-pub fn real_c_wstring(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-	transform(
-		parse_macro_input!(input as RealCString),
-		TransformType::CWString,
-	)
+impl Parse for MatrixRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		if !input.peek(syn::LitInt) {
+			return Err(input.error("expected size of row, optionally preceded by count of rows"));
+		}
+		let first: syn::LitInt = input.parse()?;
+		input.parse::<syn::Token![,]>()?;
+		let (rows, width) = if input.peek(syn::LitInt) {
+			let width: syn::LitInt = input.parse()?;
+			input.parse::<syn::Token![,]>()?;
+			(Some((first.base10_parse()?, first.span())), width)
+		} else {
+			(None, first)
+		};
+		let size = width.base10_parse()?;
+		if size == 0 {
+			return Err(syn::Error::new(
+				width.span(),
+				"size of row should not be zero",
+			));
+		}
+		Ok(MatrixRealCString {
+			rows,
+			width: size,
+			strings: input.parse()?,
+		})
+	}
+}
+
+/// Max size of repeated string in characters, protecting from typos in repeat count
+const MAX_REPEAT_LEN: usize = 4 * 1024 * 1024;
+
+/// String, followed by count of its repetitions, i.e `"-", 79`
+struct RepeatedRealCString(RealCString);
+
+impl Parse for RepeatedRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let options = Options::parse(input)?;
+		let span = input.span();
+		let Literal::Str(string) = input.parse()? else {
+			return Err(syn::Error::new(span, "expected string literal"));
+		};
+		input.parse::<syn::Token![,]>()?;
+		let count_literal: syn::LitInt = input.parse()?;
+		input.parse::<Option<syn::Token![,]>>()?;
+		let count: usize = count_literal.base10_parse()?;
+		if string.chars().count().saturating_mul(count) > MAX_REPEAT_LEN {
+			return Err(syn::Error::new(
+				count_literal.span(),
+				format!(
+					"Repeated string exceeds limit of {} characters",
+					MAX_REPEAT_LEN
+				),
+			));
+		}
+		let mut string = RealCString {
+			options,
+			literals: vec![Literal::Str(string.repeat(count))],
+			span,
+			sources: Vec::new(),
+		};
+		string.apply_options()?;
+		Ok(RepeatedRealCString(string))
+	}
+}
+
+/// Named string of `RealCStringTable`, i.e `pub APP_NAME = "MyApp";`
+struct RealCStringTableEntry {
+	attrs: Vec<syn::Attribute>,
+	visibility: syn::Visibility,
+	name: syn::Ident,
+	string: RealCString,
+}
+
+/// Named strings, preceded by options applied to every string, i.e `#![wide, utf8] APP_NAME = "MyApp";`
+struct RealCStringTable {
+	transform_type: TransformType,
+	/// Whether entries with the same content share storage, `dedup`, only supported by pools
+	dedup: bool,
+	entries: Vec<RealCStringTableEntry>,
+}
+
+impl RealCStringTable {
+	/// Returns true if next token is a flag of table, which is passed before options
+	fn peek_flag(input: ParseStream, flag: &str) -> Result<bool> {
+		let found = input.fork().parse::<syn::Ident>().is_ok_and(|i| i == flag);
+		if found {
+			input.parse::<syn::Ident>()?;
+			input.parse::<Option<syn::Token![,]>>()?;
+		}
+		Ok(found)
+	}
+
+	/// Parses `#![...]` with flags and options applied to every string, returning table without entries
+	fn parse_header(input: ParseStream) -> Result<(Self, Options)> {
+		let mut table = RealCStringTable {
+			transform_type: TransformType::CString,
+			dedup: false,
+			entries: Vec::new(),
+		};
+		let mut options = Options::default();
+		if input.peek(syn::Token![#]) && input.peek2(syn::Token![!]) {
+			input.parse::<syn::Token![#]>()?;
+			input.parse::<syn::Token![!]>()?;
+			let content;
+			syn::bracketed!(content in input);
+			if Self::peek_flag(&content, "wide")? {
+				table.transform_type = TransformType::CWString;
+			}
+			table.dedup = Self::peek_flag(&content, "dedup")?;
+			options = Options::parse(&content)?;
+			if !content.is_empty() {
+				return Err(
+					content.error("expected option, `wide` and `dedup` should be the first ones")
+				);
+			}
+		}
+		Ok((table, options))
+	}
+
+	/// Parses entries until end of input, applying passed options to every string
+	fn parse_entries(&mut self, input: ParseStream, options: Options) -> Result<()> {
+		while !input.is_empty() {
+			let attrs = input.call(syn::Attribute::parse_outer)?;
+			let visibility = input.parse()?;
+			let name = input.parse()?;
+			input.parse::<syn::Token![=]>()?;
+			let string = RealCString::parse_with(input, options.clone())?;
+			self.entries.push(RealCStringTableEntry {
+				attrs,
+				visibility,
+				name,
+				string,
+			});
+			if input.is_empty() {
+				break;
+			}
+			input.parse::<syn::Token![;]>()?;
+		}
+		Ok(())
+	}
+}
+
+impl Parse for RealCStringTable {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let (mut table, options) = Self::parse_header(input)?;
+		if table.dedup {
+			return Err(input.error("`dedup` is only supported by `real_c_string_pool`"));
+		}
+		table.parse_entries(input, options)?;
+		Ok(table)
+	}
+}
+
+/// Named strings, stored in one static, which is declared after header, i.e `pub static ERRORS; E_OK = "ok";`
+struct RealCStringPool {
+	attrs: Vec<syn::Attribute>,
+	visibility: syn::Visibility,
+	name: syn::Ident,
+	table: RealCStringTable,
+}
+
+impl Parse for RealCStringPool {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let (mut table, options) = RealCStringTable::parse_header(input)?;
+		let attrs = input.call(syn::Attribute::parse_outer)?;
+		let visibility = input.parse()?;
+		input.parse::<syn::Token![static]>()?;
+		let name = input.parse()?;
+		input.parse::<syn::Token![;]>()?;
+		table.parse_entries(input, options)?;
+		if table.entries.is_empty() {
+			return Err(input.error("expected at least one `NAME = \"string\";` entry"));
+		}
+		Ok(RealCStringPool {
+			attrs,
+			visibility,
+			name,
+			table,
+		})
+	}
+}
+
+/// Function returning string by numeric ID, declared after header, i.e `fn msg(u32) -> *const i8; 1 => "ready"`
+struct RealCStringLookup {
+	attrs: Vec<syn::Attribute>,
+	visibility: syn::Visibility,
+	abi: Option<syn::Abi>,
+	name: syn::Ident,
+	id_type: syn::Type,
+	return_type: syn::Type,
+	transform_type: TransformType,
+	arms: Vec<(TokenStream, RealCString)>,
+	default: RealCString,
+}
+
+impl RealCStringLookup {
+	/// Parses string of arm, which is a single literal, as arms are separated by commas
+	fn parse_string(input: ParseStream, options: &Options) -> Result<RealCString> {
+		let span = input.span();
+		let mut string = RealCString {
+			options: options.clone(),
+			literals: Literal::parse_all(input)?,
+			span,
+			sources: Vec::new(),
+		};
+		string.apply_options()?;
+		Ok(string)
+	}
+}
+
+impl Parse for RealCStringLookup {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let (table, options) = RealCStringTable::parse_header(input)?;
+		if table.dedup {
+			return Err(input.error("`dedup` is only supported by `real_c_string_pool`"));
+		}
+		let attrs = input.call(syn::Attribute::parse_outer)?;
+		let visibility = input.parse()?;
+		let abi = input.parse()?;
+		input.parse::<syn::Token![fn]>()?;
+		let name = input.parse()?;
+		let content;
+		syn::parenthesized!(content in input);
+		let id_type = content.parse()?;
+		input.parse::<syn::Token![->]>()?;
+		let return_type = input.parse()?;
+		input.parse::<syn::Token![;]>()?;
+		let mut arms = Vec::new();
+		let mut ids: Vec<(i128, proc_macro2::Span)> = Vec::new();
+		let mut default = None;
+		let mut errors: Option<syn::Error> = None;
+		while !input.is_empty() {
+			if input.peek(syn::Token![_]) {
+				let underscore: syn::Token![_] = input.parse()?;
+				input.parse::<syn::Token![=>]>()?;
+				let string = Self::parse_string(input, &options)?;
+				if default.replace(string).is_some() {
+					return Err(syn::Error::new(
+						underscore.span,
+						"Default arm is already specified",
+					));
+				}
+			} else {
+				let negative: Option<syn::Token![-]> = input.parse()?;
+				let id: syn::LitInt = input.parse()?;
+				let value: i128 = id.base10_parse()?;
+				let value = if negative.is_some() { -value } else { value };
+				input.parse::<syn::Token![=>]>()?;
+				let string = Self::parse_string(input, &options)?;
+				if let Some((_, first)) = ids.iter().find(|(other, _)| *other == value) {
+					let mut error = syn::Error::new(id.span(), format!("Duplicate ID {}", id));
+					error.combine(syn::Error::new(*first, "first used here"));
+					match &mut errors {
+						Some(errors) => errors.combine(error),
+						None => errors = Some(error),
+					}
+				}
+				ids.push((value, id.span()));
+				arms.push((quote! { #negative #id }, string));
+			}
+			if input.is_empty() {
+				break;
+			}
+			input.parse::<syn::Token![,]>()?;
+		}
+		if let Some(errors) = errors {
+			return Err(errors);
+		}
+		let Some(default) = default else {
+			return Err(input.error("expected default `_ => \"string\"` arm for unmatched IDs"));
+		};
+		Ok(RealCStringLookup {
+			attrs,
+			visibility,
+			abi,
+			name,
+			id_type,
+			return_type,
+			transform_type: table.transform_type,
+			arms,
+			default,
+		})
+	}
+}
+
+/// Function returning string, i.e `pub extern "C" fn app_version() -> *const i8 = "1.4.2"`
+struct RealCStringFn {
+	attrs: Vec<syn::Attribute>,
+	visibility: syn::Visibility,
+	abi: syn::Abi,
+	name: syn::Ident,
+	return_type: syn::TypePtr,
+	transform_type: TransformType,
+	string: RealCString,
+	/// Whether symbol name is overridden by `#[export_name = "name"]`, so `#[no_mangle]` is not added
+	export_name: bool,
+}
+
+impl Parse for RealCStringFn {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(syn::Attribute::parse_outer)?;
+		let mut export_name = false;
+		for attr in &attrs {
+			if !attr.path().is_ident("export_name") {
+				continue;
+			}
+			match &attr.meta {
+				syn::Meta::NameValue(syn::MetaNameValue {
+					value:
+						syn::Expr::Lit(syn::ExprLit {
+							lit: syn::Lit::Str(name),
+							..
+						}),
+					..
+				}) => Options::symbol(name)?,
+				_ => {
+					return Err(syn::Error::new_spanned(
+						attr,
+						"expected `#[export_name = \"name\"]`",
+					))
+				}
+			};
+			export_name = true;
+		}
+		let visibility = input.parse()?;
+		let abi = match input.parse()? {
+			Some(abi) => abi,
+			None => syn::parse_quote! { extern "C" },
+		};
+		input.parse::<syn::Token![fn]>()?;
+		let name: syn::Ident = input.call(syn::ext::IdentExt::parse_any)?;
+		let symbol = syn::ext::IdentExt::unraw(&name).to_string();
+		if !export_name
+			&& !symbol
+				.chars()
+				.all(|c| c.is_ascii_alphanumeric() || c == '_')
+		{
+			return Err(syn::Error::new(
+				name.span(),
+				format!(
+					"`{}` is not a valid C identifier, use `#[export_name = \"name\"]` to export it with another name",
+					symbol
+				),
+			));
+		}
+		let content;
+		syn::parenthesized!(content in input);
+		if !content.is_empty() {
+			return Err(content.error("getter takes no arguments"));
+		}
+		input.parse::<syn::Token![->]>()?;
+		let return_type: syn::Type = input.parse()?;
+		let element = match &return_type {
+			syn::Type::Ptr(pointer) if pointer.const_token.is_some() => match &*pointer.elem {
+				syn::Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+				_ => None,
+			},
+			_ => None,
+		};
+		let transform_type = match element.as_deref() {
+			Some("i8") => TransformType::CString,
+			Some("u8") => TransformType::CStringU8,
+			Some("c_char") => TransformType::CCharString,
+			Some("i16") => TransformType::CWString,
+			Some("u16") => TransformType::CWStringU16,
+			Some("u32") => TransformType::CU32String,
+			_ => {
+				return Err(syn::Error::new_spanned(
+					return_type,
+					"expected `*const i8`, `*const u8`, `*const c_char`, `*const i16`, `*const u16` or `*const u32`",
+				))
+			}
+		};
+		let syn::Type::Ptr(return_type) = return_type else {
+			unreachable!("checked above")
+		};
+		input.parse::<syn::Token![=]>()?;
+		let string = RealCString::parse_with(input, Options::default())?;
+		Ok(RealCStringFn {
+			attrs,
+			visibility,
+			abi,
+			name,
+			return_type,
+			transform_type,
+			string,
+			export_name,
+		})
+	}
+}
+
+/// Getters, separated by `;`
+struct RealCStringFns(Vec<RealCStringFn>);
+
+impl Parse for RealCStringFns {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let mut getters = vec![input.parse()?];
+		while input.parse::<Option<syn::Token![;]>>()?.is_some() && !input.is_empty() {
+			getters.push(input.parse()?);
+		}
+		if !input.is_empty() {
+			return Err(input.error("expected `;` before next getter"));
+		}
+		Ok(RealCStringFns(getters))
+	}
+}
+
+/// Strings of `RealCStringList`, joined with NUL separators
+struct MultiRealCString(RealCString);
+
+impl Parse for MultiRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let span = input.span();
+		let list: RealCStringList = input.parse()?;
+		let mut literals = Vec::new();
+		for (string, span) in list.strings {
+			if string.is_empty() {
+				return Err(syn::Error::new(
+					span,
+					"Empty string would terminate multi-string early",
+				));
+			}
+			literals.push(Literal::Str(string));
+			// Explicit zero code unit is not reported as interior NUL
+			literals.push(Literal::Int(0, span));
+		}
+		let mut string = RealCString {
+			options: list.options,
+			literals,
+			span,
+			sources: Vec::new(),
+		};
+		string.apply_options()?;
+		Ok(MultiRealCString(string))
+	}
+}
+
+/// Strings joined with separator, preceded by options, i.e `skip_empty ":", "/usr/lib", "/usr/local/lib"`
+struct JoinedRealCString(RealCString);
+
+impl Parse for JoinedRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let options = Options::parse(input)?;
+		let span = input.span();
+		let string = |input: ParseStream| {
+			let span = input.span();
+			match input.parse()? {
+				Literal::Str(string) => Ok(string),
+				_ => Err(syn::Error::new(span, "expected string literal")),
+			}
+		};
+		let separator = string(input)?;
+		let mut elements = Vec::new();
+		while !input.is_empty() {
+			input.parse::<syn::Token![,]>()?;
+			if input.is_empty() {
+				break;
+			}
+			elements.push(string(input)?);
+		}
+		if options.skip_empty {
+			elements.retain(|element| !element.is_empty());
+		}
+		let mut string = RealCString {
+			options,
+			literals: vec![Literal::Str(elements.join(&separator))],
+			span,
+			sources: Vec::new(),
+		};
+		string.apply_options()?;
+		Ok(JoinedRealCString(string))
+	}
+}
+
+/// Path of included file, preceded by options applied to its content, i.e `strip_comments "shader.glsl"`
+struct IncludedFile {
+	options: Options,
+	path: syn::LitStr,
+}
+
+impl Parse for IncludedFile {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let options = Options::parse(input)?;
+		let path = input.parse()?;
+		if !input.is_empty() {
+			return Err(input.error("expected end of input, only one file can be included"));
+		}
+		Ok(IncludedFile { options, path })
+	}
+}
+
+/// Declaration of pointer wrapper, i.e `pub struct CStrPtr(*const i8);`
+struct SyncPointer {
+	attrs: Vec<syn::Attribute>,
+	visibility: syn::Visibility,
+	name: syn::Ident,
+	pointer: syn::TypePtr,
+}
+
+impl Parse for SyncPointer {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(syn::Attribute::parse_outer)?;
+		let visibility = input.parse()?;
+		input.parse::<syn::Token![struct]>()?;
+		let name = input.parse()?;
+		let content;
+		syn::parenthesized!(content in input);
+		let pointer: syn::Type = content.parse()?;
+		let pointer = match pointer {
+			syn::Type::Ptr(pointer) if pointer.const_token.is_some() => pointer,
+			pointer => {
+				return Err(syn::Error::new_spanned(
+					pointer,
+					"expected `*const` pointer, i.e `*const i8`, as strings are immutable",
+				))
+			}
+		};
+		input.parse::<Option<syn::Token![;]>>()?;
+		Ok(SyncPointer {
+			attrs,
+			visibility,
+			name,
+			pointer,
+		})
+	}
+}
+
+/// Environment block of `KEY=VALUE` strings, sorted by key, i.e `"PATH" => "C:\\app", "MY_FLAG" => "1"`
+struct EnvBlock(RealCString);
+
+impl Parse for EnvBlock {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let span = input.span();
+		let mut options = Options::parse(input)?;
+		// Block is terminated by NUL after the last string, which can't be omitted
+		options.terminated = true;
+		options.terminators = 1;
+		options.terminator = 0;
+		let mut entries: Vec<(String, String, proc_macro2::Span)> = Vec::new();
+		while !input.is_empty() {
+			let key: syn::LitStr = input.parse()?;
+			input.parse::<syn::Token![=>]>()?;
+			let value_span = input.span();
+			let value = match input.parse()? {
+				Literal::Str(value) => value,
+				_ => return Err(syn::Error::new(value_span, "expected string literal")),
+			};
+			let name = key.value();
+			if name.is_empty() {
+				return Err(syn::Error::new(
+					key.span(),
+					"Environment variable name is empty",
+				));
+			}
+			if let Some(c) = name.chars().find(|&c| c == '=' || c == '\0') {
+				return Err(syn::Error::new(
+					key.span(),
+					format!("Environment variable name can't contain {:?}", c),
+				));
+			}
+			if value.contains('\0') {
+				return Err(syn::Error::new(
+					value_span,
+					"Value of environment variable can't contain NUL, it would terminate entry early",
+				));
+			}
+			// Names are case-insensitive on Windows
+			let upper = name.to_uppercase();
+			if entries
+				.iter()
+				.any(|(other, _, _)| other.to_uppercase() == upper)
+			{
+				return Err(syn::Error::new(
+					key.span(),
+					format!(
+						"Environment variable `{}` is defined twice, names are case-insensitive",
+						name
+					),
+				));
+			}
+			entries.push((name, value, key.span()));
+			if input.is_empty() {
+				break;
+			}
+			input.parse::<syn::Token![,]>()?;
+		}
+		if entries.is_empty() {
+			return Err(input.error("expected at least one `\"NAME\" => \"value\"` entry"));
+		}
+		// Windows requires block to be sorted by uppercase name, compared as UTF-16 code units
+		entries.sort_by_cached_key(|(name, _, _)| {
+			name.to_uppercase().encode_utf16().collect::<Vec<_>>()
+		});
+		let mut literals = Vec::new();
+		for (name, value, span) in entries {
+			literals.push(Literal::Str(format!("{}={}", name, value)));
+			literals.push(Literal::Int(0, span));
+		}
+		let mut string = RealCString {
+			options,
+			literals,
+			span,
+			sources: Vec::new(),
+		};
+		string.apply_options()?;
+		Ok(EnvBlock(string))
+	}
+}
+
+/// String literal with C escape sequences, i.e `r"\x1b[2J"`
+struct EscapedRealCString(RealCString);
+
+impl Parse for EscapedRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let options = Options::parse(input)?;
+		let literal: syn::LitStr = input.parse()?;
+		input.parse::<Option<syn::Token![,]>>()?;
+		let literals = escape::unescape(&literal.value(), literal.span())
+			.map_err(|message| syn::Error::new(literal.span(), message))?;
+		let mut string = RealCString {
+			options,
+			literals,
+			span: literal.span(),
+			sources: Vec::new(),
+		};
+		string.apply_options()?;
+		Ok(EscapedRealCString(string))
+	}
+}
+
+/// Windows path, converted by `path::to_windows`
+struct WindowsPath(RealCString);
+
+impl Parse for WindowsPath {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let span = input.span();
+		let mut string: RealCString = input.parse()?;
+		let mut path = String::new();
+		for literal in &string.literals {
+			match literal {
+				Literal::Str(part) => path.push_str(part),
+				_ => return Err(syn::Error::new(span, "paths can only consist of strings")),
+			}
+		}
+		let path = path::to_windows(&path, string.options.long_path)
+			.map_err(|message| syn::Error::new(span, message))?;
+		string.literals = vec![Literal::Str(path)];
+		Ok(WindowsPath(string))
+	}
+}
+
+/// GUID, with or without braces, string holds its canonical braced uppercase form
+struct GuidString {
+	string: RealCString,
+	guid: guid::Guid,
+}
+
+impl Parse for GuidString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let mut string: RealCString = input.parse()?;
+		let mut text = String::new();
+		for literal in &string.literals {
+			match literal {
+				Literal::Str(part) => text.push_str(part),
+				_ => {
+					return Err(syn::Error::new(
+						string.span,
+						"GUID can only consist of strings",
+					))
+				}
+			}
+		}
+		let guid =
+			guid::Guid::parse(&text).map_err(|message| syn::Error::new(string.span, message))?;
+		string.literals = vec![Literal::Str(guid.canonical())];
+		Ok(GuidString { string, guid })
+	}
+}
+
+/// Bytes decoded from hex dump, preceded by options, i.e `with_nul "DE AD BE EF"`
+struct HexBytes(Vec<u8>);
+
+impl Parse for HexBytes {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let options = Options::parse(input)?;
+		let dump: syn::LitStr = input.parse()?;
+		let mut bytes =
+			hex::decode(&dump.value()).map_err(|message| syn::Error::new(dump.span(), message))?;
+		if options.with_nul {
+			bytes.push(0);
+		}
+		Ok(HexBytes(bytes))
+	}
+}
+
+/// Bytes decoded from base64, preceded by optional alphabet and options, i.e `alphabet = "url_safe", "3q2-7w"`
+#[cfg(feature = "base64")]
+struct Base64Bytes(Vec<u8>);
+
+#[cfg(feature = "base64")]
+impl Parse for Base64Bytes {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let alphabet = match named_argument::<syn::LitStr>(input, "alphabet")? {
+			None => None,
+			Some(name) => Some(match name.value().as_str() {
+				"standard" => base64::Alphabet::Standard,
+				"url_safe" => base64::Alphabet::UrlSafe,
+				_ => {
+					return Err(syn::Error::new(
+						name.span(),
+						"expected \"standard\" or \"url_safe\"",
+					))
+				}
+			}),
+		};
+		let options = Options::parse(input)?;
+		let encoded: syn::LitStr = input.parse()?;
+		let mut bytes = base64::decode(&encoded.value(), alphabet)
+			.map_err(|message| syn::Error::new(encoded.span(), message))?;
+		if options.with_nul {
+			bytes.push(0);
+		}
+		Ok(Base64Bytes(bytes))
+	}
+}
+
+/// String formatted from template and literal arguments, preceded by options, i.e `"{}-{}", "plugin", 2`
+struct FormattedRealCString(RealCString);
+
+/// Parses argument of `FormattedRealCString`, and formats it as `format!` does
+fn format_argument(input: ParseStream) -> Result<String> {
+	let minus = input.parse::<Option<syn::Token![-]>>()?;
+	if minus.is_none() && input.fork().parse::<syn::Macro>().is_ok() {
+		return builtin::expand(&input.parse()?);
+	}
+	if !input.peek(syn::Lit) {
+		let found = match input.fork().parse::<proc_macro2::TokenTree>() {
+			Ok(token) => format!("`{}`", token),
+			Err(_) => "end of input".to_owned(),
+		};
+		return Err(input.error(format!(
+			"expected literal, found {}, arguments should be literals or builtin macros, as formatting is done at compile time",
+			found
+		)));
+	}
+	let literal: syn::Lit = input.parse()?;
+	let value = match &literal {
+		syn::Lit::Int(int) => return format_integer(int, minus.is_some()),
+		_ if minus.is_some() => {
+			return Err(syn::Error::new(
+				literal.span(),
+				"only integer literals can be negated",
+			))
+		}
+		syn::Lit::Str(string) => string.value(),
+		syn::Lit::Char(char) => char.value().to_string(),
+		syn::Lit::Bool(bool) => bool.value.to_string(),
+		_ => {
+			return Err(syn::Error::new(
+				literal.span(),
+				"expected string, char, bool or integer literal",
+			))
+		}
+	};
+	Ok(value)
+}
+
+/// Formats integer literal, negated if needed, as `format!` does, checking that it fits into type of suffix
+///
+/// Unsuffixed literals are accepted in range of `i128` and `u128`, as their type is not known. Proc macro
+/// doesn't know pointer width of target, so `isize` and `usize` literals should fit into 32 bits.
+fn format_integer(int: &syn::LitInt, negative: bool) -> Result<String> {
+	let (signed, bits) = match int.suffix() {
+		"" => (!negative, 128),
+		"i8" => (true, 8),
+		"i16" => (true, 16),
+		"i32" => (true, 32),
+		"isize" => (true, 32),
+		"i64" => (true, 64),
+		"i128" => (true, 128),
+		"u8" => (false, 8),
+		"u16" => (false, 16),
+		"u32" => (false, 32),
+		"usize" => (false, 32),
+		"u64" => (false, 64),
+		"u128" => (false, 128),
+		suffix => {
+			return Err(syn::Error::new(
+				int.span(),
+				format!("invalid suffix `{}` for integer literal", suffix),
+			))
+		}
+	};
+	if negative && !signed && !int.suffix().is_empty() {
+		return Err(syn::Error::new(
+			int.span(),
+			format!("cannot apply unary operator `-` to type `{}`", int.suffix()),
+		));
+	}
+	let out_of_range = || {
+		let kind = match int.suffix() {
+			"" => "`i128` and `u128`".to_owned(),
+			suffix @ ("isize" | "usize") => format!("`{}` of 32-bit targets", suffix),
+			suffix => format!("`{}`", suffix),
+		};
+		syn::Error::new(
+			int.span(),
+			format!("integer literal `{}` is out of range for {}", int, kind),
+		)
+	};
+	let magnitude: u128 = int.base10_parse().map_err(|_| out_of_range())?;
+	// Negative values may be one greater than positive ones
+	let max = match (signed, negative) {
+		(false, _) => u128::MAX >> (128 - bits),
+		(true, false) => (1 << (bits - 1)) - 1,
+		(true, true) => 1 << (bits - 1),
+	};
+	if magnitude > max {
+		return Err(out_of_range());
+	}
+	Ok(match magnitude {
+		0 => "0".to_owned(),
+		_ if negative => format!("-{}", magnitude),
+		_ => magnitude.to_string(),
+	})
+}
+
+impl FormattedRealCString {
+	/// Parses template and arguments, template may also refer to passed named values
+	fn parse_with(input: ParseStream, named: &[(&str, &str)]) -> Result<Self> {
+		let options = Options::parse(input)?;
+		let template: syn::LitStr = input.parse()?;
+		let mut arguments = Vec::new();
+		while !input.is_empty() {
+			input.parse::<syn::Token![,]>()?;
+			if input.is_empty() {
+				break;
+			}
+			arguments.push(format_argument(input)?);
+		}
+		let string = format::format(&template.value(), &arguments, named)
+			.map_err(|message| syn::Error::new(template.span(), message))?;
+		let mut string = RealCString {
+			options,
+			literals: vec![Literal::Str(string)],
+			span: template.span(),
+			sources: Vec::new(),
+		};
+		string.apply_options()?;
+		Ok(FormattedRealCString(string))
+	}
+}
+
+impl Parse for FormattedRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		Self::parse_with(input, &[])
+	}
+}
+
+/// Returns build time in RFC 3339 format, i.e `2024-01-31T23:59:59Z`, error is spanned on invocation
+fn build_timestamp() -> Result<String> {
+	timestamp::build_time()
+		.map(timestamp::rfc3339)
+		.map_err(|message| syn::Error::new(proc_macro2::Span::call_site(), message))
+}
+
+/// Build time, preceded by options, i.e `keep`
+struct TimestampRealCString(RealCString);
+
+impl Parse for TimestampRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let options = Options::parse(input)?;
+		if !input.is_empty() {
+			return Err(input.error("expected options, build time is not passed as string"));
+		}
+		let mut string = RealCString {
+			options,
+			literals: vec![Literal::Str(build_timestamp()?)],
+			span: proc_macro2::Span::call_site(),
+			sources: Vec::new(),
+		};
+		string.apply_options()?;
+		Ok(TimestampRealCString(string))
+	}
+}
+
+/// Location of invocation, preceded by options, i.e `ascii`
+///
+/// File is the same as returned by `file!()`, which is known to proc macro through span of invocation.
+struct LocationRealCString(RealCString);
+
+impl LocationRealCString {
+	/// Parses options, and makes string of file of invocation, followed by line if `with_line` is passed
+	fn parse_with_line(input: ParseStream, with_line: bool) -> Result<Self> {
+		let options = Options::parse(input)?;
+		if !input.is_empty() {
+			return Err(input.error("expected options, location is not passed as string"));
+		}
+		let site = proc_macro::Span::call_site();
+		let location = if with_line {
+			format!("{}:{}", site.file(), site.line())
+		} else {
+			site.file()
+		};
+		let mut string = RealCString {
+			options,
+			literals: vec![Literal::Str(location)],
+			span: proc_macro2::Span::call_site(),
+			sources: Vec::new(),
+		};
+		string.apply_options()?;
+		Ok(LocationRealCString(string))
+	}
+
+	/// Parses options of `real_c_file`
+	fn parse_file(input: ParseStream) -> Result<Self> {
+		Self::parse_with_line(input, false)
+	}
+
+	/// Parses options of `real_c_location`
+	fn parse_location(input: ParseStream) -> Result<Self> {
+		Self::parse_with_line(input, true)
+	}
+}
+
+/// Random alphanumeric string of passed length, preceded by options, i.e `keep 16`
+struct NonceRealCString {
+	options: Options,
+	len: usize,
+	span: proc_macro2::Span,
+}
+
+impl Parse for NonceRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let options = Options::parse(input)?;
+		let len_literal: syn::LitInt = input.parse()?;
+		let len: usize = len_literal.base10_parse()?;
+		if len == 0 || len > nonce::MAX_LEN {
+			return Err(syn::Error::new(
+				len_literal.span(),
+				format!("nonce length should be from 1 to {}", nonce::MAX_LEN),
+			));
+		}
+		Ok(NonceRealCString {
+			options,
+			len,
+			span: len_literal.span(),
+		})
+	}
+}
+
+impl NonceRealCString {
+	/// Returns nonce for invocation at passed location, i.e `src/main.rs:10:5`, with options applied
+	fn string(self, location: &str) -> Result<RealCString> {
+		let mut string = RealCString {
+			options: self.options,
+			literals: vec![Literal::Str(nonce::generate(self.len, location))],
+			span: self.span,
+			sources: Vec::new(),
+		};
+		string.apply_options()?;
+		Ok(string)
+	}
+}
+
+/// Returns location of invocation of proc macro, only available while macro is expanded
+fn call_site_location() -> String {
+	let site = proc_macro::Span::call_site();
+	format!("{}:{}:{}", site.file(), site.line(), site.column())
+}
+
+/// Strings selected by `cfg` predicates, i.e `windows => "plugin.dll", _ => "libplugin.so"`
+struct CfgRealCString {
+	transform_type: TransformType,
+	arms: Vec<(syn::Meta, RealCString)>,
+	fallback: RealCString,
+}
+
+impl CfgRealCString {
+	/// Parses string of arm, which ends at comma followed by the next arm
+	fn arm_string(input: ParseStream) -> Result<RealCString> {
+		let mut tokens = TokenStream::new();
+		while !input.is_empty() {
+			if input.peek(syn::Token![,]) {
+				let fork = input.fork();
+				fork.parse::<syn::Token![,]>()?;
+				let next_arm = fork.peek(syn::Token![_])
+					|| (fork.peek(syn::Ident)
+						&& !fork.peek2(syn::Token![!])
+						&& !fork.peek2(syn::Token![::]));
+				if fork.is_empty() || next_arm {
+					input.parse::<syn::Token![,]>()?;
+					break;
+				}
+			}
+			tokens.extend([input.parse::<proc_macro2::TokenTree>()?]);
+		}
+		if tokens.is_empty() {
+			return Err(unexpected(input, "string literal"));
+		}
+		syn::parse2(tokens)
+	}
+}
+
+impl Parse for CfgRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let mut transform_type = TransformType::CString;
+		let wide = input
+			.fork()
+			.parse::<syn::Ident>()
+			.is_ok_and(|i| i == "wide");
+		if wide && input.peek2(syn::Token![,]) {
+			input.parse::<syn::Ident>()?;
+			input.parse::<syn::Token![,]>()?;
+			transform_type = TransformType::CWString;
+		}
+		let mut arms = Vec::new();
+		while !input.peek(syn::Token![_]) {
+			if input.is_empty() {
+				return Err(input.error(
+					"expected `_ => \"string\"` as the last arm, which is used if no predicate is true",
+				));
+			}
+			// `=>` is peeked as `=` by `Meta`, so predicate is collected first
+			let mut predicate = TokenStream::new();
+			while !input.is_empty() && !input.peek(syn::Token![=>]) {
+				predicate.extend([input.parse::<proc_macro2::TokenTree>()?]);
+			}
+			let predicate: syn::Meta = syn::parse2(predicate)?;
+			input.parse::<syn::Token![=>]>()?;
+			arms.push((predicate, Self::arm_string(input)?));
+		}
+		input.parse::<syn::Token![_]>()?;
+		input.parse::<syn::Token![=>]>()?;
+		let fallback = Self::arm_string(input)?;
+		if !input.is_empty() {
+			return Err(input.error("`_` arm should be the last one"));
+		}
+		Ok(CfgRealCString {
+			transform_type,
+			arms,
+			fallback,
+		})
+	}
+}
+
+/// Integer formatted in passed base, preceded by optional base, prefix flag and options, i.e `base = 16, prefix, -255`
+struct ItoaRealCString(RealCString);
+
+impl Parse for ItoaRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let base = match named_argument::<syn::LitInt>(input, "base")? {
+			None => 10,
+			Some(base) => match base.base10_parse()? {
+				value @ (2 | 8 | 10 | 16) => value,
+				_ => {
+					return Err(syn::Error::new(
+						base.span(),
+						"base should be 2, 8, 10 or 16",
+					))
+				}
+			},
+		};
+		let prefixed = input
+			.fork()
+			.parse::<syn::Ident>()
+			.is_ok_and(|i| i == "prefix");
+		if prefixed {
+			input.parse::<syn::Ident>()?;
+			input.parse::<Option<syn::Token![,]>>()?;
+		}
+		let options = Options::parse(input)?;
+		let negative = input.parse::<Option<syn::Token![-]>>()?.is_some();
+		let literal: syn::LitInt = input.parse()?;
+		let magnitude: u64 = match literal.base10_parse() {
+			Ok(value) if !negative || value <= 1 << 63 => value,
+			_ => {
+				return Err(syn::Error::new(
+					literal.span(),
+					"integer should fit into i64 or u64",
+				))
+			}
+		};
+		let digits = match base {
+			2 => format!("{:b}", magnitude),
+			8 => format!("{:o}", magnitude),
+			16 => format!("{:x}", magnitude),
+			_ => magnitude.to_string(),
+		};
+		// Prefixes are the same as in C
+		let prefix = match base {
+			2 if prefixed => "0b",
+			8 if prefixed => "0",
+			16 if prefixed => "0x",
+			_ => "",
+		};
+		let sign = if negative && magnitude != 0 { "-" } else { "" };
+		let mut string = RealCString {
+			options,
+			literals: vec![Literal::Str(format!("{}{}{}", sign, prefix, digits))],
+			span: literal.span(),
+			sources: Vec::new(),
+		};
+		string.apply_options()?;
+		Ok(ItoaRealCString(string))
+	}
+}
+
+/// Same as `FormattedRealCString`, but template may refer to build time as `{timestamp}`
+struct BuildInfoRealCString(RealCString);
+
+impl Parse for BuildInfoRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let timestamp = build_timestamp()?;
+		let string = FormattedRealCString::parse_with(input, &[("timestamp", &timestamp)])?;
+		Ok(BuildInfoRealCString(string.0))
+	}
+}
+
+/// Pointer to C string, followed by expected string, i.e `pointer, utf8 "Привет"`
+struct AssertedRealCString {
+	pointer: syn::Expr,
+	expected: RealCString,
+}
+
+impl Parse for AssertedRealCString {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let pointer = input.parse()?;
+		input.parse::<syn::Token![,]>()?;
+		Ok(AssertedRealCString {
+			pointer,
+			expected: input.parse()?,
+		})
+	}
+}
+
+#[derive(Copy, Clone)]
+enum TransformType {
+	CString,
+	CStringU8,
+	CCharString,
+	CWString,
+	CWStringU16,
+	CLString,
+	CU32String,
+}
+
+impl TransformType {
+	/// Returns max character that can fit into this transform
+	fn max_char(&self) -> u32 {
+		match self {
+			Self::CString | Self::CStringU8 | Self::CCharString => 0xff,
+			Self::CWString | Self::CWStringU16 => 0xffff,
+			// Every unicode scalar value fits into 32 bits
+			Self::CLString | Self::CU32String => char::MAX as u32,
+		}
+	}
+
+	/// Returns code units encoding passed character, or `None` if it can't be represented
+	///
+	/// Narrow strings store one byte per character unless `utf8` or `encoding` is passed,
+	/// wide strings are UTF-16 unless `ucs2` is passed.
+	fn encode(&self, value: char, options: &Options) -> Option<Vec<u32>> {
+		match self.max_char() {
+			0xff if options.code_page.is_some() => {
+				let code_page = options.code_page.expect("checked by guard");
+				code_page.encode(value).map(|b| vec![u32::from(b)])
+			}
+			0xff if options.utf8 => {
+				let mut buf = [0; 4];
+				Some(value.encode_utf8(&mut buf).bytes().map(u32::from).collect())
+			}
+			0xffff if !options.ucs2 => {
+				let mut buf = [0; 2];
+				Some(
+					value
+						.encode_utf16(&mut buf)
+						.iter()
+						.map(|&u| u32::from(u))
+						.collect(),
+				)
+			}
+			max_char if value as u32 <= max_char => Some(vec![value as u32]),
+			_ => None,
+		}
+	}
+
+	/// Returns size of single code unit in bytes
+	fn unit_size(&self) -> usize {
+		match self.max_char() {
+			0xff => 1,
+			0xffff => 2,
+			_ => 4,
+		}
+	}
+
+	/// Returns mask of bits, which fit into single code unit
+	fn unit_mask(&self) -> u32 {
+		match self.unit_size() {
+			1 => 0xff,
+			2 => 0xffff,
+			_ => 0xffff_ffff,
+		}
+	}
+
+	/// Returns type of single array element
+	fn element_type(&self) -> TokenStream {
+		match self {
+			Self::CString => quote! {i8},
+			Self::CStringU8 => quote! {u8},
+			Self::CCharString => quote! {::core::ffi::c_char},
+			Self::CWString => quote! {i16},
+			Self::CWStringU16 => quote! {u16},
+			Self::CLString => quote! {i32},
+			Self::CU32String => quote! {u32},
+		}
+	}
+
+	/// Returns array element holding passed character, which should fit in `max_char`
+	fn element(&self, value: u32) -> TokenStream {
+		match self {
+			Self::CString => {
+				let res = value as i8;
+				quote! {#res}
+			}
+			Self::CStringU8 => {
+				let res = value as u8;
+				quote! {#res}
+			}
+			// c_char signedness depends on target, so cast from unsigned instead of using a suffixed literal
+			Self::CCharString => {
+				let res = value as u8;
+				quote! {#res as ::core::ffi::c_char}
+			}
+			Self::CWString => {
+				let res = value as i16;
+				quote! {#res}
+			}
+			Self::CWStringU16 => {
+				let res = value as u16;
+				quote! {#res}
+			}
+			Self::CLString => {
+				let res = value as i32;
+				quote! {#res}
+			}
+			Self::CU32String => {
+				let res = value;
+				quote! {#res}
+			}
+		}
+	}
+
+	/// Same as `element`, but swaps bytes of code unit if `big_endian` is passed
+	///
+	/// Swap is done with `to_be` by the invoking crate, as it knows target endianness, and proc macro doesn't.
+	fn unit(&self, value: u32, options: &Options) -> TokenStream {
+		let element = self.element(value);
+		if !options.big_endian || self.max_char() == 0xff {
+			return element;
+		}
+		let element_type = self.element_type();
+		quote! {#element_type::to_be(#element)}
+	}
+}
+
+/// Returns error message, if character is not allowed by `ascii` option
+fn non_ascii(input: &RealCString, value: char, offset: usize) -> Option<String> {
+	if input.options.ascii?.allows(value) {
+		return None;
+	}
+	Some(format!(
+		"Character {:?} (U+{:04X}) at offset {} is not printable ASCII",
+		value, value as u32, offset
+	))
+}
+
+/// Transforms passed string to code units without terminating zero, or returns every error found in it
+///
+/// Rejected characters are counted as a single code unit in offsets of errors.
+fn transform_units(
+	input: &RealCString,
+	transform_type: TransformType,
+) -> std::result::Result<Vec<u32>, syn::Error> {
+	let mut units = Vec::new();
+	let mut errors = Vec::new();
+	let error = |message: String| syn::Error::new(input.span, message);
+	// Offset of first NUL character or byte in string literals, explicit code units are ignored
+	let mut interior_nul = None;
+	// Characters which can't be encoded, with their offsets, reported in a single error
+	let mut unsupported = Vec::new();
+	let mut skipped = 0;
+	if input.options.bom && transform_type.max_char() > 0xff {
+		units.push(0xfeff);
+	}
+	if input.options.raw && !input.options.utf16le {
+		return Err(error(
+			"`raw` option only applies to `utf16le` byte strings, use `raw(...)` for code units"
+				.to_owned(),
+		));
+	}
+	let replacement = match input.options.lossy {
+		Some(c) => match transform_type.encode(c, &input.options) {
+			Some(replacement) if non_ascii(input, c, 0).is_none() => Some(replacement),
+			_ => {
+				return Err(error(format!(
+					"Replacement character {:?} (U+{:04X}) can't be stored in string itself",
+					c, c as u32
+				)))
+			}
+		},
+		None => None,
+	};
+	let replaced = |span, c: char, offset| {
+		let replacement = input.options.lossy.expect("only called with replacement");
+		let message = format!(
+			"Character {:?} (U+{:04X}) at offset {} can't be stored in string, and is replaced with {:?}",
+			c, c as u32, offset, replacement
+		);
+		diagnostic::warning(span, &message);
+	};
+	for (argument, literal) in input.literals.iter().enumerate() {
+		match literal {
+			Literal::Str(string) => {
+				for (index, cur_char) in string.chars().enumerate() {
+					let offset = units.len() + skipped;
+					let span = input.char_span(argument, index);
+					if cur_char == '\0' {
+						interior_nul.get_or_insert((offset, span));
+					}
+					if let Some(name) = invisible::name(cur_char) {
+						if !input.options.allow_invisible {
+							errors.push(syn::Error::new(
+								span,
+								format!(
+								"Invisible character U+{:04X} ({}) at offset {} makes source read differently \
+								than string, use `allow_invisible` if this is intended",
+								cur_char as u32, name, offset
+							),
+							));
+							skipped += 1;
+							continue;
+						}
+					}
+					if let Some(message) = non_ascii(input, cur_char, offset) {
+						match &replacement {
+							Some(replacement) => {
+								replaced(span, cur_char, offset);
+								units.extend(replacement);
+							}
+							None => {
+								errors.push(syn::Error::new(span, message));
+								skipped += 1;
+							}
+						}
+						continue;
+					}
+					let Some(code_units) = transform_type.encode(cur_char, &input.options) else {
+						match &replacement {
+							Some(replacement) => {
+								replaced(span, cur_char, offset);
+								units.extend(replacement);
+							}
+							None => {
+								unsupported.push((cur_char, offset));
+								skipped += 1;
+							}
+						}
+						continue;
+					};
+					units.extend(code_units);
+				}
+			}
+			Literal::ByteStr(_) if input.options.utf16le && transform_type.max_char() != 0xffff => {
+				return Err(error(
+					"`utf16le` is only supported by UTF-16 strings, i.e `real_c_wstring`"
+						.to_owned(),
+				));
+			}
+			Literal::ByteStr(bytes) if input.options.utf16le => {
+				if bytes.len() % 2 != 0 {
+					errors.push(error(format!(
+						"UTF-16LE byte string has odd length {}, code units are pairs of bytes",
+						bytes.len()
+					)));
+					continue;
+				}
+				let mut code_units: Vec<u16> = bytes
+					.as_chunks()
+					.0
+					.iter()
+					.map(|&pair| u16::from_le_bytes(pair))
+					.collect();
+				// Byte order mark of exported data is not a part of text
+				if code_units.first() == Some(&0xfeff) {
+					code_units.remove(0);
+				}
+				let offset = units.len() + skipped;
+				if let Some(position) = code_units.iter().position(|&unit| unit == 0) {
+					interior_nul.get_or_insert((offset + position, input.span));
+				}
+				let mut position = 0;
+				while position < code_units.len() && !input.options.raw {
+					let unit = code_units[position];
+					let paired = (0xd800..0xdc00).contains(&unit)
+						&& code_units
+							.get(position + 1)
+							.is_some_and(|next| (0xdc00..0xe000).contains(next));
+					if paired {
+						position += 2;
+						continue;
+					}
+					if (0xd800..0xe000).contains(&unit) {
+						errors.push(error(format!(
+							"Unpaired surrogate {:#x} at offset {} of UTF-16LE byte string, use `raw` if this is intended",
+							unit,
+							offset + position
+						)));
+					}
+					position += 1;
+				}
+				units.extend(code_units.iter().map(|&unit| u32::from(unit)));
+			}
+			Literal::ByteStr(_) if transform_type.max_char() > 0xff => {
+				return Err(error(
+					"Byte strings are only supported by narrow strings, as their encoding is unknown"
+						.to_owned(),
+				));
+			}
+			Literal::ByteStr(bytes) => {
+				let offset = units.len() + skipped;
+				if let Some(position) = bytes.iter().position(|&b| b == 0) {
+					interior_nul.get_or_insert((offset + position, input.span));
+				}
+				errors.extend(
+					bytes
+						.iter()
+						.enumerate()
+						.filter_map(|(i, &b)| non_ascii(input, b as char, offset + i))
+						.map(error),
+				);
+				units.extend(bytes.iter().map(|&b| b as u32));
+			}
+			Literal::Int(value, span) => {
+				if *value > transform_type.max_char() {
+					let message = format!(
+						"Code unit {:#x} at argument {} doesn't fit, max is {:#x}",
+						value,
+						argument + 1,
+						transform_type.max_char()
+					);
+					errors.push(syn::Error::new(*span, message));
+					skipped += 1;
+					continue;
+				}
+				units.push(*value);
+			}
+		}
+	}
+	if !unsupported.is_empty() {
+		let characters: Vec<String> = unsupported
+			.iter()
+			.map(|(c, offset)| format!("{:?} (U+{:04X}) at offset {}", c, *c as u32, offset))
+			.collect();
+		errors.push(error(format!(
+			"Unsupported character{} {}",
+			if unsupported.len() > 1 { "s" } else { "" },
+			characters.join(", ")
+		)));
+	}
+	if let Some((offset, span)) = interior_nul {
+		if !input.options.allow_interior_nul {
+			errors.push(syn::Error::new(
+				span,
+				format!(
+				"Interior NUL character at offset {}, C consumers will stop reading string there, \
+				use `allow_interior_nul` if this is intended",
+				offset
+			),
+			));
+		}
+	}
+	if let Some(check) = input.options.printf {
+		let result = match check {
+			options::Printf::Safe => printf::check_safe(&input.text()),
+			options::Printf::Format => printf::check_format(&input.text()),
+		};
+		if let Err(message) = result {
+			errors.push(error(message));
+		}
+	}
+	let terminator = input.options.terminator;
+	if terminator > transform_type.max_char() {
+		errors.push(error(format!(
+			"Terminator {:#x} doesn't fit, max is {:#x}",
+			terminator,
+			transform_type.max_char()
+		)));
+	} else if terminator != 0 && !input.options.allow_terminator_in_content {
+		if let Some(offset) = units.iter().position(|&unit| unit == terminator) {
+			errors.push(error(format!(
+				"Terminator {:#x} appears at offset {}, so consumers can't find end of string, \
+				use `allow_terminator_in_content` if this is intended",
+				terminator, offset
+			)));
+		}
+	}
+	if let Some(max_len) = input.options.max_len {
+		let len = units.len() + skipped + transform_terminator(input, transform_type).len();
+		if len > max_len {
+			errors.push(error(format!(
+				"String of size {} exceeds max_len of {}",
+				len, max_len
+			)));
+		}
+	}
+	match errors.into_iter().reduce(|mut errors, error| {
+		errors.combine(error);
+		errors
+	}) {
+		Some(errors) => Err(errors),
+		None => Ok(units),
+	}
+}
+
+/// Transforms passed string to array elements, one per code unit, without terminating zero
+fn transform_elements(
+	input: &RealCString,
+	transform_type: TransformType,
+) -> std::result::Result<Vec<TokenStream>, syn::Error> {
+	Ok(transform_units(input, transform_type)?
+		.into_iter()
+		.map(|unit| transform_type.unit(unit, &input.options))
+		.collect())
+}
+
+/// Transforms passed string to reference to array with terminator, using as few tokens as possible
+///
+/// Narrow strings are byte string literals, and wide ones are arrays of unsuffixed integers, typed by the first one.
+/// Returns reference, type of array elements, which has the same size as `element_type`, and length of array.
+fn transform_compact(
+	input: &RealCString,
+	transform_type: TransformType,
+) -> std::result::Result<(TokenStream, TokenStream, usize), syn::Error> {
+	let mut units = transform_units(input, transform_type)?;
+	if input.options.terminated {
+		units.extend(terminator_units(input));
+	}
+	let len = units.len();
+	if transform_type.unit_size() == 1 {
+		let bytes: Vec<u8> = units.iter().map(|&unit| unit as u8).collect();
+		let literal = proc_macro2::Literal::byte_string(&bytes);
+		return Ok((quote! {#literal}, quote! {u8}, len));
+	}
+	// Swap is done by the invoking crate, as in `unit`
+	if input.options.big_endian {
+		let element_type = transform_type.element_type();
+		let elements = units
+			.iter()
+			.map(|&unit| transform_type.unit(unit, &input.options));
+		return Ok((quote! {&[#(#elements),*]}, element_type, len));
+	}
+	let (element_type, suffixed): (_, fn(u32) -> proc_macro2::Literal) =
+		match transform_type.unit_size() {
+			2 => (quote! {u16}, |unit| {
+				proc_macro2::Literal::u16_suffixed(unit as u16)
+			}),
+			_ => (quote! {u32}, proc_macro2::Literal::u32_suffixed),
+		};
+	let Some((&first, rest)) = units.split_first() else {
+		let zero = suffixed(0);
+		return Ok((quote! {&[#zero; 0]}, element_type, 0));
+	};
+	let first = suffixed(first);
+	let rest = rest
+		.iter()
+		.map(|&unit| proc_macro2::Literal::u32_unsuffixed(unit));
+	Ok((quote! {&[#first #(, #rest)*]}, element_type, len))
+}
+
+/// Returns errors, which should be the only output of macro, for use in expression position
+fn error_expression(errors: syn::Error) -> TokenStream {
+	let count = errors.clone().into_iter().count();
+	let errors = errors.to_compile_error();
+	if count == 1 {
+		errors
+	} else {
+		quote! {{ #errors }}
+	}
+}
+
+/// Returns array elements, which should be appended after string
+fn transform_terminator(input: &RealCString, transform_type: TransformType) -> Vec<TokenStream> {
+	terminator_units(input)
+		.into_iter()
+		.map(|unit| transform_type.element(unit))
+		.collect()
+}
+
+/// Returns code units, which should be appended after string, zeros unless `terminator` is passed
+fn terminator_units(input: &RealCString) -> Vec<u32> {
+	if input.options.terminated {
+		vec![input.options.terminator; input.options.terminators]
+	} else {
+		vec![]
+	}
+}
+
+/// Transforms passed string to zero-terminated array expression
+fn transform_array(input: &RealCString, transform_type: TransformType) -> TokenStream {
+	let elements = match transform_elements(input, transform_type) {
+		Ok(elements) => elements,
+		Err(errors) => return error_expression(errors),
+	};
+	let terminator = transform_terminator(input, transform_type);
+	quote! {
+		[#(#elements,)* #(#terminator,)*]
+	}
+}
+
+/// Returns error if passed string contains NUL characters or isn't terminated, for types which require it
+fn check_c_str(input: &RealCString, type_name: &str) -> Option<TokenStream> {
+	if !input.options.terminated {
+		let message = format!(
+			"{} should be terminated, `no_nul` is not supported",
+			type_name
+		);
+		return Some(quote! {
+			::core::compile_error!(#message)
+		});
+	}
+	if input.options.terminator != 0 {
+		let message = format!(
+			"{} should be terminated by NUL, `terminator` is not supported",
+			type_name
+		);
+		return Some(quote! {
+			::core::compile_error!(#message)
+		});
+	}
+	if input.options.terminators > 1 {
+		let message = format!(
+			"{} can't contain NUL character, `terminators` is not supported",
+			type_name
+		);
+		return Some(quote! {
+			::core::compile_error!(#message)
+		});
+	}
+	let offset = input.code_points().iter().position(|&c| c == 0)?;
+	let message = format!(
+		"{} can't contain NUL character, found at offset ",
+		type_name
+	);
+	Some(quote! {
+		::core::compile_error!(::core::concat!(#message, #offset))
+	})
+}
+
+/// Reads file at path relative to `CARGO_MANIFEST_DIR`, checking each character to fit in transform
+///
+/// Returns string with file content and its absolute path, or compile errors, pointing at line and column
+fn include_file(
+	path: &syn::LitStr,
+	options: Options,
+	transform_type: TransformType,
+) -> Result<(RealCString, String)> {
+	let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+	let full_path = std::path::Path::new(&manifest_dir).join(path.value());
+	let content = std::fs::read_to_string(&full_path).map_err(|e| {
+		syn::Error::new(
+			path.span(),
+			format!("couldn't read {}: {}", full_path.display(), e),
+		)
+	})?;
+	let mut string = RealCString {
+		options,
+		literals: vec![Literal::Str(content.clone())],
+		span: path.span(),
+		sources: Vec::new(),
+	};
+	string.apply_options()?;
+	let mut unsupported: Vec<char> = Vec::new();
+	if string.options.lossy.is_none() {
+		for c in string.text().chars() {
+			if transform_type.encode(c, &string.options).is_none() && !unsupported.contains(&c) {
+				unsupported.push(c);
+			}
+		}
+	}
+	// Options may remove lines and whitespace, so positions are taken from file content,
+	// skipping characters in comments, which don't reach encoded text
+	let comments = if string.options.strip_comments {
+		text::comment_ranges(&content)
+	} else {
+		Vec::new()
+	};
+	let mut errors: Option<syn::Error> = None;
+	let mut error = |message: String| {
+		let error = syn::Error::new(path.span(), message);
+		match &mut errors {
+			Some(errors) => errors.combine(error),
+			None => errors = Some(error),
+		}
+	};
+	for &c in &unsupported {
+		let mut found = false;
+		let (mut line, mut column) = (1, 1);
+		for (offset, cur) in content.chars().enumerate() {
+			if cur == c && !comments.iter().any(|comment| comment.contains(&offset)) {
+				found = true;
+				error(format!(
+					"Unsupported character \"{}\" at {}:{}:{}",
+					c,
+					full_path.display(),
+					line,
+					column
+				));
+			}
+			if cur == '\n' {
+				line += 1;
+				column = 1;
+			} else {
+				column += 1;
+			}
+		}
+		if !found {
+			error(format!(
+				"Unsupported character \"{}\", produced by options from {}",
+				c,
+				full_path.display()
+			));
+		}
+	}
+	if let Some(errors) = errors {
+		return Err(errors);
+	}
+	Ok((string, full_path.display().to_string()))
+}
+
+/// Same as `transform_array`, but pads array with zeroes to passed size, if any
+fn transform_padded(
+	input: &RealCString,
+	size: Option<usize>,
+	transform_type: TransformType,
+) -> TokenStream {
+	let elements = match transform_elements(input, transform_type) {
+		Ok(elements) => elements,
+		Err(errors) => return error_expression(errors),
+	};
+	let terminator = transform_terminator(input, transform_type);
+	let len = elements.len() + terminator.len();
+	let size = size.unwrap_or(len);
+	if len > size {
+		return quote! {
+			::core::compile_error!(
+				::core::concat!("String of size ", #len, " doesn't fit into buffer of size ", #size)
+			)
+		};
+	}
+	let padding = (len..size).map(|_| transform_type.element(0));
+	quote! {
+		[#(#elements,)* #(#terminator,)* #(#padding,)*]
+	}
+}
+
+/// Returns bytes of string without terminator, after checking that there are exactly `size` of them
+fn tag_bytes(input: &RealCString, size: usize) -> std::result::Result<Vec<u8>, TokenStream> {
+	let units = transform_units(input, TransformType::CStringU8).map_err(error_expression)?;
+	if units.len() != size {
+		let message = format!(
+			"String of size {} doesn't match tag of size {}",
+			units.len(),
+			size
+		);
+		return Err(error_expression(syn::Error::new(input.span, message)));
+	}
+	// Units are checked to fit into byte by transform
+	Ok(units.into_iter().map(|unit| unit as u8).collect())
+}
+
+/// Transforms passed string to four-character code, packed to `u32` in passed byte order
+fn transform_fourcc(input: FourccRealCString) -> TokenStream {
+	let bytes = match tag_bytes(&input.string, 4) {
+		Ok(bytes) => [bytes[0], bytes[1], bytes[2], bytes[3]],
+		Err(error) => return error,
+	};
+	let value = if input.big_endian {
+		u32::from_be_bytes(bytes)
+	} else {
+		u32::from_le_bytes(bytes)
+	};
+	quote! {#value}
+}
+
+/// Transforms passed string to array of bytes of passed size, without terminator
+fn transform_tag(input: PaddedRealCString) -> TokenStream {
+	let SizedRealCString { size, string } = input.0;
+	let size = size.expect("size is checked by parser");
+	match tag_bytes(&string, size) {
+		Ok(bytes) => {
+			let bytes = bytes.iter();
+			quote! {[#(#bytes,)*]}
+		}
+		Err(error) => error,
+	}
+}
+
+/// Transforms every string to row of passed size, padded with zeroes, and collects them to array of rows
+fn transform_matrix(input: MatrixRealCString, transform_type: TransformType) -> TokenStream {
+	let width = input.width;
+	let strings = match input.strings.into_strings() {
+		Ok(strings) => strings,
+		Err(errors) => return error_expression(errors),
+	};
+	let rows = match input.rows {
+		Some((rows, span)) if strings.len() > rows => {
+			let message = format!(
+				"{} strings don't fit into matrix of {} row{}",
+				strings.len(),
+				rows,
+				if rows == 1 { "" } else { "s" }
+			);
+			return syn::Error::new(span, message).to_compile_error();
+		}
+		Some((rows, _)) => rows,
+		None => strings.len(),
+	};
+	let mut errors: Option<syn::Error> = None;
+	let mut matrix = Vec::new();
+	for (row, string) in strings.iter().enumerate() {
+		let error = match transform_elements(string, transform_type) {
+			Ok(elements) => {
+				let terminator = transform_terminator(string, transform_type);
+				let len = elements.len() + terminator.len();
+				if len <= width {
+					let padding = (len..width).map(|_| transform_type.element(0));
+					matrix.push(quote! { [#(#elements,)* #(#terminator,)* #(#padding,)*] });
+					continue;
+				}
+				syn::Error::new(
+					string.span,
+					format!(
+						"String of size {} at row {} doesn't fit into row of size {}",
+						len, row, width
+					),
+				)
+			}
+			Err(error) => error,
+		};
+		match &mut errors {
+			Some(errors) => errors.combine(error),
+			None => errors = Some(error),
+		}
+	}
+	if let Some(errors) = errors {
+		return error_expression(errors);
+	}
+	let empty = (0..width).map(|_| transform_type.element(0));
+	let empty = quote! { [#(#empty,)*] };
+	let empty_rows = (matrix.len()..rows).map(|_| &empty);
+	quote! {
+		[#(#matrix,)* #(#empty_rows,)*]
+	}
+}
+
+/// Returns passed value of length of string, after checking that string transforms without errors
+fn transform_checked_value(
+	input: &RealCString,
+	transform_type: TransformType,
+	value: impl FnOnce(usize) -> usize,
+) -> TokenStream {
+	match transform_units(input, transform_type) {
+		Ok(units) => {
+			let value = value(units.len());
+			quote! {#value}
+		}
+		Err(errors) => error_expression(errors),
+	}
+}
+
+/// Returns value computed by passed function over bytes of string, as they are laid out in memory
+///
+/// Terminator is only included if `with_nul` is passed. Byte order of wide strings depends on target,
+/// unless `big_endian` is passed, so value is computed for both orders, and the invoking crate picks one.
+fn transform_hash(
+	input: &RealCString,
+	transform_type: TransformType,
+	hash: impl Fn(&[u8]) -> TokenStream,
+) -> TokenStream {
+	let mut units = match transform_units(input, transform_type) {
+		Ok(units) => units,
+		Err(errors) => return error_expression(errors),
+	};
+	if input.options.with_nul {
+		units.push(input.options.terminator);
+	}
+	let size = transform_type.unit_size();
+	let bytes = |big_endian: bool| -> Vec<u8> {
+		units
+			.iter()
+			.flat_map(|unit| {
+				let bytes = if big_endian {
+					unit.to_be_bytes()
+				} else {
+					unit.to_le_bytes()
+				};
+				let used = if big_endian { 4 - size..4 } else { 0..size };
+				bytes[used].to_vec()
+			})
+			.collect()
+	};
+	let big = hash(&bytes(true));
+	if size == 1 || input.options.big_endian {
+		return big;
+	}
+	let little = hash(&bytes(false));
+	quote! {
+		if ::core::cfg!(target_endian = "big") { #big } else { #little }
+	}
+}
+
+/// Returns code units of string including terminator, and key to XOR them with, passed or derived from them
+fn obfuscation_key(
+	input: &KeyedRealCString,
+	transform_type: TransformType,
+) -> std::result::Result<(Vec<u32>, u32), TokenStream> {
+	let mut units = match transform_units(&input.string, transform_type) {
+		Ok(units) => units,
+		Err(errors) => return Err(error_expression(errors)),
+	};
+	units.extend(terminator_units(&input.string));
+	let mask = transform_type.unit_mask();
+	let key = match input.key {
+		Some(key) if key & !mask != 0 => {
+			let message = format!(
+				"Key {:#x} doesn't fit into code unit, max is {:#x}",
+				key, mask
+			);
+			return Err(quote! {
+				::core::compile_error!(#message)
+			});
+		}
+		Some(key) => key,
+		None => obfuscate::key(&units) & mask,
+	};
+	Ok((units, key))
+}
+
+/// Returns item, which makes rustc rebuild invoking crate when passed environment variable changes
+fn env_tracking(name: &str) -> TokenStream {
+	quote! {
+		const _: ::core::option::Option<&str> = ::core::option_env!(#name);
+	}
+}
+
+/// Transforms passed string to pointer to buffer, which is decoded at runtime from XOR-ed code units
+///
+/// Buffer is a temporary, extended to enclosing block when expression is assigned with `let`.
+fn transform_obfuscated(input: &KeyedRealCString, transform_type: TransformType) -> TokenStream {
+	let (units, key) = match obfuscation_key(input, transform_type) {
+		Ok(key) => key,
+		Err(errors) => return errors,
+	};
+	let storage_type = match transform_type.unit_size() {
+		1 => quote! {u8},
+		2 => quote! {u16},
+		_ => quote! {u32},
+	};
+	let encoded = units
+		.iter()
+		.map(|unit| proc_macro2::Literal::u32_unsuffixed(unit ^ key));
+	let key = proc_macro2::Literal::u32_unsuffixed(key);
+	let len = units.len();
+	let element_type = transform_type.element_type();
+	let decoded = if input.string.options.big_endian && transform_type.unit_size() > 1 {
+		quote! {#element_type::to_be((encoded[i] ^ #key) as #element_type)}
+	} else {
+		quote! {(encoded[i] ^ #key) as #element_type}
+	};
+	let seed_tracking = env_tracking(obfuscate::SEED_VAR);
+	quote! {
+		&{
+			#seed_tracking
+			const ENCODED: [#storage_type; #len] = [#(#encoded,)*];
+			// Keeps optimizer from decoding string at compile time, which would put plaintext into binary
+			let encoded = ::core::hint::black_box(ENCODED);
+			let mut decoded = [0 as #element_type; #len];
+			let mut i = 0;
+			while i < #len {
+				decoded[i] = #decoded;
+				i += 1;
+			}
+			decoded
+		} as *const [#element_type; #len] as *const #element_type
+	}
+}
+
+/// Returns key, with which string is XOR-ed by `transform_obfuscated`
+fn transform_obfuscation_key(
+	input: &KeyedRealCString,
+	transform_type: TransformType,
+) -> TokenStream {
+	let (_, key) = match obfuscation_key(input, transform_type) {
+		Ok(key) => key,
+		Err(errors) => return errors,
+	};
+	let key = match transform_type.unit_size() {
+		1 => {
+			let key = key as u8;
+			quote! {#key}
+		}
+		2 => {
+			let key = key as u16;
+			quote! {#key}
+		}
+		_ => quote! {#key},
+	};
+	let seed_tracking = env_tracking(obfuscate::SEED_VAR);
+	quote! {
+		{
+			#seed_tracking
+			#key
+		}
+	}
+}
+
+/// Max size of string in code units including terminator, which is built on stack by separate stores
+const MAX_STACK_STRING_LEN: usize = 256;
+
+/// Transforms passed string to pointer to buffer, which is filled at runtime by one store per code unit
+///
+/// Stores are done in shuffled order, and are volatile, so the optimizer can't merge them to a copy of
+/// constant. Buffer is a temporary, extended to enclosing block when expression is assigned with `let`.
+fn transform_stack(input: &RealCString, transform_type: TransformType) -> TokenStream {
+	let mut units = match transform_units(input, transform_type) {
+		Ok(units) => units,
+		Err(errors) => return error_expression(errors),
+	};
+	units.extend(terminator_units(input));
+	let len = units.len();
+	if len > MAX_STACK_STRING_LEN {
+		let message = format!(
+			"String of size {} is too long to be built on stack, max is {}",
+			len, MAX_STACK_STRING_LEN
+		);
+		return quote! {
+			::core::compile_error!(#message)
+		};
+	}
+	let stores = obfuscate::shuffle(len, obfuscate::key(&units))
+		.into_iter()
+		.map(|i| {
+			let unit = transform_type.unit(units[i], &input.options);
+			quote! {
+				unsafe { ::core::ptr::write_volatile(buffer.as_mut_ptr().add(#i), #unit) };
+			}
+		});
+	let element_type = transform_type.element_type();
+	let seed_tracking = env_tracking(obfuscate::SEED_VAR);
+	quote! {
+		&{
+			#seed_tracking
+			let mut buffer = [0 as #element_type; #len];
+			#(#stores)*
+			buffer
+		} as *const [#element_type; #len] as *const #element_type
+	}
+}
+
+/// Transforms passed string to pointer to buffer, which is assembled at runtime from separate statics
+///
+/// String without terminator is split into chunks of nearly equal size, there are less chunks if string
+/// is shorter than their count. Buffer is a temporary, extended to enclosing block when expression is
+/// assigned with `let`.
+fn transform_split(input: &SplitRealCString, transform_type: TransformType) -> TokenStream {
+	let units = match transform_units(&input.string, transform_type) {
+		Ok(units) => units,
+		Err(errors) => return error_expression(errors),
+	};
+	let chunks = input.chunks.min(units.len());
+	let element_type = transform_type.element_type();
+	let mut statics = Vec::new();
+	let mut copies = Vec::new();
+	let mut start = 0;
+	for chunk in 0..chunks {
+		// First chunks are one code unit longer, if string can't be split evenly
+		let len = units.len() / chunks + usize::from(chunk < units.len() % chunks);
+		let elements = units[start..start + len]
+			.iter()
+			.map(|&unit| transform_type.unit(unit, &input.string.options));
+		let name = quote::format_ident!("CHUNK_{}", chunk);
+		statics.push(quote! {
+			static #name: [#element_type; #len] = [#(#elements,)*];
+		});
+		let end = start + len;
+		copies.push(quote! {
+			buffer[#start..#end].copy_from_slice(::core::hint::black_box(&#name));
+		});
+		start = end;
+	}
+	let terminator = transform_terminator(&input.string, transform_type);
+	let len = units.len() + terminator.len();
+	// Buffer is zeroed, so only custom terminator is copied
+	if terminator_units(&input.string)
+		.iter()
+		.any(|&unit| unit != 0)
+	{
+		let start = units.len();
+		copies.push(quote! {
+			buffer[#start..].copy_from_slice(&[#(#terminator),*]);
+		});
+	}
+	quote! {
+		&{
+			#(#statics)*
+			let mut buffer = [0 as #element_type; #len];
+			#(#copies)*
+			buffer
+		} as *const [#element_type; #len] as *const #element_type
+	}
+}
+
+/// Transforms every string to pointer, and collects them to null-terminated array of pointers
+fn transform_list(input: RealCStringList, transform_type: TransformType) -> TokenStream {
+	let strings = match input.into_strings() {
+		Ok(strings) => strings,
+		Err(errors) => return error_expression(errors),
+	};
+	let pointers: Vec<_> = strings
+		.iter()
+		.map(|string| transform(string, transform_type))
+		.collect();
+	let len = pointers.len() + 1;
+	let element_type = transform_type.element_type();
+	quote! {
+		{
+			const LIST: &[*const #element_type; #len] = &[#(#pointers,)* ::core::ptr::null()];
+			LIST.as_ptr()
+		}
+	}
+}
+
+/// Transforms every string to pointer, and collects them with their lengths to tuple of arrays and count
+fn transform_table(input: RealCStringList, transform_type: TransformType) -> TokenStream {
+	let strings = match input.into_strings() {
+		Ok(strings) => strings,
+		Err(errors) => return error_expression(errors),
+	};
+	let mut pointers = Vec::new();
+	let mut lens = Vec::new();
+	let mut errors = Vec::new();
+	for string in &strings {
+		match transform_units(string, transform_type) {
+			Ok(units) => lens.push(units.len()),
+			Err(error) => errors.push(error),
+		}
+		pointers.push(transform(string, transform_type));
+	}
+	if let Some(errors) = errors.into_iter().reduce(|mut errors, error| {
+		errors.combine(error);
+		errors
+	}) {
+		return error_expression(errors);
+	}
+	let count = strings.len();
+	let element_type = transform_type.element_type();
+	quote! {
+		{
+			const POINTERS: &[*const #element_type; #count] = &[#(#pointers,)*];
+			const LENS: &[usize; #count] = &[#(#lens,)*];
+			(POINTERS, LENS, #count)
+		}
+	}
+}
+
+/// Splits string into lines, and transforms every line to pointer, collecting them to array of pointers
+fn transform_lines(input: RealCString, transform_type: TransformType) -> TokenStream {
+	let pointers: Vec<_> = input
+		.into_lines()
+		.iter()
+		.map(|line| transform(line, transform_type))
+		.collect();
+	let len = pointers.len();
+	let element_type = transform_type.element_type();
+	quote! {
+		{
+			const LINES: &[*const #element_type; #len] = &[#(#pointers,)*];
+			LINES
+		}
+	}
+}
+
+/// Transforms file content to pointer, same as `transform`, tracking file for recompilation
+fn transform_include(input: IncludedFile, transform_type: TransformType) -> TokenStream {
+	let (input, full_path) = match include_file(&input.path, input.options, transform_type) {
+		Ok(file) => file,
+		Err(errors) => return errors.to_compile_error(),
+	};
+	let pointer = transform(&input, transform_type);
+	quote! {
+		{
+			// Makes rustc rebuild invoking crate on file changes
+			const _: &[u8] = ::core::include_bytes!(#full_path);
+			#pointer
+		}
+	}
+}
+
+/// Returns label of encoding of string, i.e `utf-16` or `windows-1251`
+fn encoding_label(options: &Options, transform_type: TransformType) -> String {
+	let encoding = match transform_type.unit_size() {
+		1 if options.utf8 => "utf-8",
+		1 => match options.code_page {
+			Some(code_page) => code_page.name,
+			None => "iso-8859-1",
+		},
+		2 if options.ucs2 => "ucs-2",
+		2 => "utf-16",
+		_ => "utf-32",
+	};
+	// Wide strings are stored in target byte order otherwise
+	if options.big_endian && transform_type.unit_size() > 1 {
+		format!("{}be", encoding)
+	} else {
+		encoding.to_owned()
+	}
+}
+
+/// Returns attributes of static holding string, requested by options
+fn static_attributes(options: &Options) -> TokenStream {
+	let symbol = options.symbol.iter();
+	let section = options.section.iter();
+	let used = if options.keep {
+		quote! {#[used]}
+	} else {
+		quote! {}
+	};
+	quote! {
+		#used
+		#(#[export_name = #symbol])*
+		#(#[link_section = #section])*
+	}
+}
+
+/// Symbols of statics, defined by `dedup` invocations of crate being compiled, with content they are derived from
+///
+/// Proc macro is loaded once for compilation of crate, and rustc rejects symbol defined twice, so only
+/// the first invocation with some content defines static, and the following ones declare it as extern.
+static DEDUP_SYMBOLS: std::sync::Mutex<std::collections::BTreeMap<String, String>> =
+	std::sync::Mutex::new(std::collections::BTreeMap::new());
+
+/// Returns symbol of `dedup` static, derived from crate, encoding and content of string, and whether
+/// it is already defined by previous invocation
+///
+/// Linker rejects symbol defined by two crates, so identity of crate is hashed too, and strings of
+/// different crates are not merged. Content is kept, so hash collision is reported instead of merging
+/// different strings.
+fn dedup_symbol(
+	input: &RealCString,
+	transform_type: TransformType,
+	data: &TokenStream,
+	data_type: &TokenStream,
+) -> Result<(String, bool)> {
+	let var = |name| std::env::var(name).unwrap_or_default();
+	let key = format!(
+		"{}\0{}\0{}\0{}\0{:?}\0{:?}\0[{}] {}",
+		var("CARGO_MANIFEST_DIR"),
+		var("CARGO_CRATE_NAME"),
+		var("CARGO_BIN_NAME"),
+		encoding_label(&input.options, transform_type),
+		input.options.section,
+		input.options.align,
+		data_type,
+		data,
+	);
+	let symbol = format!("__real_c_str_{:016x}", hash::fnv1a64(key.as_bytes()));
+	let mut symbols = DEDUP_SYMBOLS.lock().unwrap_or_else(|e| e.into_inner());
+	match symbols.get(&symbol) {
+		Some(defined) if *defined == key => Ok((symbol, true)),
+		Some(_) => Err(syn::Error::new(
+			input.span,
+			format!(
+				"Symbol `{}` of `dedup` string collides with one of different string, use `symbol` to name it",
+				symbol
+			),
+		)),
+		None => {
+			symbols.insert(symbol.clone(), key);
+			Ok((symbol, false))
+		}
+	}
+}
+
+/// Transforms passed string to pointer to static, with attributes requested by options
+fn transform_static(input: &RealCString, transform_type: TransformType) -> TokenStream {
+	let (data, data_type, len) = match transform_compact(input, transform_type) {
+		Ok(compact) => compact,
+		Err(errors) => return error_expression(errors),
+	};
+	let element_type = transform_type.element_type();
+	let mut attributes = static_attributes(&input.options);
+	if input.options.dedup {
+		if input.options.symbol.is_some() || input.options.addr_stable {
+			let message = "`dedup` can't be combined with `symbol` and `addr_stable`, which give string its own static";
+			return syn::Error::new(input.span, message).to_compile_error();
+		}
+		let (symbol, defined) = match dedup_symbol(input, transform_type, &data, &data_type) {
+			Ok(symbol) => symbol,
+			Err(error) => return error.to_compile_error(),
+		};
+		if defined {
+			let pointer = quote! {::core::ptr::addr_of!(STRING)};
+			let registration = registration(input, transform_type, &pointer, &data_type, len);
+			return quote! {
+				{
+					unsafe extern "C" {
+						#[link_name = #symbol]
+						static STRING: [#data_type; #len];
+					}
+					#registration
+					#pointer as *const #element_type
+				}
+			};
+		}
+		attributes.extend(quote! {#[export_name = #symbol]});
+	}
+	let array = quote! {*#data};
+	let Some(align) = input.options.align else {
+		let pointer = quote! {&STRING as *const [#data_type; #len]};
+		let registration = registration(input, transform_type, &pointer, &data_type, len);
+		return quote! {
+			{
+				#attributes
+				static STRING: [#data_type; #len] = #array;
+				#registration
+				#pointer as *const #element_type
+			}
+		};
+	};
+	// Alignment can only be specified for types, so array is wrapped
+	let align = proc_macro2::Literal::u32_unsuffixed(align);
+	let pointer = quote! {&STRING.0 as *const [#data_type; #len]};
+	let registration = registration(input, transform_type, &pointer, &data_type, len);
+	quote! {
+		{
+			#[repr(C, align(#align))]
+			struct Aligned([#data_type; #len]);
+			#attributes
+			static STRING: Aligned = Aligned(#array);
+			#registration
+			#pointer as *const #element_type
+		}
+	}
+}
+
+/// Returns items adding string at passed pointer to registry, if `registry` feature is enabled
+#[cfg_attr(not(feature = "registry"), allow(unused_variables))]
+fn registration(
+	input: &RealCString,
+	transform_type: TransformType,
+	pointer: &TokenStream,
+	data_type: &TokenStream,
+	len: usize,
+) -> TokenStream {
+	#[cfg(feature = "registry")]
+	return registry::register(
+		&input.options,
+		transform_type,
+		pointer,
+		&quote! {[#data_type; #len]},
+	);
+	#[cfg(not(feature = "registry"))]
+	quote! {}
+}
+
+/// Transforms passed string to needed form, used by proc macro at bottom
+///
+/// With `sync` option, pointer is wrapped in passed type, declared by `real_c_sync_ptr`.
+fn transform(input: &RealCString, transform_type: TransformType) -> TokenStream {
+	transform_sync(input, transform_pointer(input, transform_type))
+}
+
+/// Wraps passed pointer to string in type of `sync` option, if it is passed
+fn transform_sync(input: &RealCString, pointer: TokenStream) -> TokenStream {
+	match &input.options.sync {
+		// Pointed string is immutable, and lives as long as the program
+		Some(wrapper) => quote! { unsafe { #wrapper::new(#pointer) } },
+		None => pointer,
+	}
+}
+
+/// Transforms passed string to pointer to its first code unit
+fn transform_pointer(input: &RealCString, transform_type: TransformType) -> TokenStream {
+	let options = &input.options;
+	if options.symbol.is_some()
+		|| options.section.is_some()
+		|| options.align.is_some()
+		|| options.keep
+		|| options.addr_stable
+		|| options.dedup
+	{
+		return transform_static(input, transform_type);
+	}
+	let (data, data_type, len) = match transform_compact(input, transform_type) {
+		Ok(compact) => compact,
+		Err(errors) => return error_expression(errors),
+	};
+	let element_type = transform_type.element_type();
+	let pointer = quote! {const { #data as *const [#data_type; #len] as *const #element_type }};
+	// Descriptor evaluates the same constant, which is usually merged with returned one by compiler
+	let registration = registration(input, transform_type, &pointer, &data_type, len);
+	if registration.is_empty() {
+		return pointer;
+	}
+	quote! {
+		{
+			#registration
+			#pointer
+		}
+	}
+}
+
+/// Transforms passed string to tuple of pointer and length in code units, excluding terminating zero
+fn transform_ptr_len(input: &RealCString, transform_type: TransformType) -> TokenStream {
+	let (data, data_type, len) = match transform_compact(input, transform_type) {
+		Ok(compact) => compact,
+		Err(errors) => return error_expression(errors),
+	};
+	let string_len = len - transform_terminator(input, transform_type).len();
+	let element_type = transform_type.element_type();
+	quote! {
+		const { (#data as *const [#data_type; #len] as *const #element_type, #string_len) }
+	}
+}
+
+/// Transforms passed string to `str` and C string, sharing the same bytes
+fn transform_dual(input: RealCString) -> TokenStream {
+	let units = match transform_units(&input, TransformType::CStringU8) {
+		Ok(units) => units,
+		Err(errors) => return error_expression(errors),
+	};
+	let mut bytes: Vec<u8> = units.iter().map(|&unit| unit as u8).collect();
+	if let Err(error) = std::str::from_utf8(&bytes) {
+		let message = format!(
+			"String is not valid UTF-8 at byte offset {}, so it can't be viewed as `str`, use `utf8` option for non-ASCII characters",
+			error.valid_up_to()
+		);
+		return syn::Error::new(input.span, message).to_compile_error();
+	}
+	let len = bytes.len();
+	bytes.extend(terminator_units(&input).iter().map(|&unit| unit as u8));
+	let size = bytes.len();
+	let data = proc_macro2::Literal::byte_string(&bytes);
+	let attributes = static_attributes(&input.options);
+	quote! {
+		{
+			#attributes
+			static STRING: [u8; #size] = *#data;
+			(
+				// Bytes before terminator are checked to be valid UTF-8 above
+				unsafe {
+					::core::str::from_utf8_unchecked(::core::slice::from_raw_parts(STRING.as_ptr(), #len))
+				},
+				&STRING as *const [u8; #size] as *const i8,
+			)
+		}
+	}
+}
+
+/// Transforms passed arms to pointer, selected by cfg predicates of invoking crate
+fn transform_cfg(input: CfgRealCString) -> TokenStream {
+	let element_type = input.transform_type.element_type();
+	let mut previous = Vec::new();
+	let mut items = Vec::new();
+	for (predicate, string) in &input.arms {
+		let pointer = transform(string, input.transform_type);
+		items.push(quote! {
+			#[cfg(all(#predicate, not(any(#(#previous,)*))))]
+			const STRING: *const #element_type = #pointer;
+		});
+		previous.push(predicate);
+	}
+	let fallback = transform(&input.fallback, input.transform_type);
+	quote! {
+		{
+			#(#items)*
+			#[cfg(not(any(#(#previous,)*)))]
+			const STRING: *const #element_type = #fallback;
+			STRING
+		}
+	}
+}
+
+/// Transforms passed string to pointer to data of BSTR, preceded by its length in bytes
+fn transform_bstr(input: RealCString) -> TokenStream {
+	let transform_type = TransformType::CWStringU16;
+	let elements = match transform_elements(&input, transform_type) {
+		Ok(elements) => elements,
+		Err(errors) => return error_expression(errors),
+	};
+	let terminator = transform_terminator(&input, transform_type);
+	let len = elements.len() + terminator.len();
+	let byte_len = elements.len() * 2;
+	if byte_len > u32::MAX as usize {
+		return quote! {
+			::core::compile_error!("String is too long for BSTR")
+		};
+	}
+	let byte_len = byte_len as u32;
+	quote! {
+		{
+			#[repr(C)]
+			struct Bstr {
+				byte_len: u32,
+				data: [u16; #len],
+			}
+			const BSTR: &Bstr = &Bstr {
+				byte_len: u32::to_le(#byte_len),
+				data: [#(#elements,)* #(#terminator,)*],
+			};
+			BSTR.data.as_ptr()
+		}
+	}
+}
+
+/// Transforms passed string to Pascal string, preceded by its length
+fn transform_pstring(input: RealCString) -> TokenStream {
+	let transform_type = TransformType::CStringU8;
+	let elements = match transform_elements(&input, transform_type) {
+		Ok(elements) => elements,
+		Err(errors) => return error_expression(errors),
+	};
+	let len = elements.len();
+	if len > 255 {
+		return quote! {
+			::core::compile_error!(
+				::core::concat!("String of size ", #len, " doesn't fit into Pascal string, max is 255")
+			)
+		};
+	}
+	let len = len as u8;
+	let terminator = if input.options.with_nul {
+		transform_terminator(&input, transform_type)
+	} else {
+		Vec::new()
+	};
+	quote! {
+		const { &[#len, #(#elements,)* #(#terminator,)*] as *const u8 }
+	}
+}
+
+/// Transforms passed string to fields of `UNICODE_STRING`
+fn transform_unicode_string(input: RealCString) -> TokenStream {
+	let transform_type = TransformType::CWStringU16;
+	let elements = match transform_elements(&input, transform_type) {
+		Ok(elements) => elements,
+		Err(errors) => return error_expression(errors),
+	};
+	let terminator = transform_terminator(&input, transform_type);
+	let length = elements.len() * 2;
+	let maximum_length = length + terminator.len() * 2;
+	if maximum_length > u16::MAX as usize {
+		return quote! {
+			::core::compile_error!(
+				::core::concat!("String of ", #maximum_length, " bytes doesn't fit into UNICODE_STRING, max is 65535")
+			)
+		};
+	}
+	let length = length as u16;
+	let maximum_length = maximum_length as u16;
+	quote! {
+		const {
+			(
+				#length,
+				#maximum_length,
+				&[#(#elements,)* #(#terminator,)*] as *const u16,
+			)
+		}
+	}
+}
+
+/// Transforms passed string to tuple of narrow and wide strings
+fn transform_pair(input: RealCString) -> TokenStream {
+	let narrow = TransformType::CString;
+	let wide = TransformType::CWString;
+	let text = input.text();
+	let narrow_only = text.chars().enumerate().find(|&(_, c)| {
+		narrow.encode(c, &input.options).is_none() && wide.encode(c, &input.options).is_some()
+	});
+	if let Some((offset, c)) = narrow_only {
+		let message = format!(
+			"Character \"{}\" at offset {} is only supported by wide half of pair, pass `utf8` to encode narrow half as UTF-8",
+			c, offset
+		);
+		return quote! {
+			::core::compile_error!(#message)
+		};
+	}
+	let narrow = transform(&input, narrow);
+	let wide = transform(&input, wide);
+	quote! {
+		(#narrow, #wide)
+	}
+}
+
+/// Transforms passed table to `const` items
+fn transform_strings(input: RealCStringTable) -> TokenStream {
+	let table = input;
+	let element_type = table.transform_type.element_type();
+	let items = table.entries.iter().map(|entry| {
+		let RealCStringTableEntry {
+			attrs,
+			visibility,
+			name,
+			string,
+		} = entry;
+		let pointer = transform(string, table.transform_type);
+		let item_type = match &string.options.sync {
+			Some(wrapper) => quote! { #wrapper },
+			None => quote! { *const #element_type },
+		};
+		quote! {
+			#(#attrs)*
+			#visibility const #name: #item_type = #pointer;
+		}
+	});
+	quote! {
+		#(#items)*
+	}
+}
+
+/// Transforms passed table to `static` arrays
+fn transform_statics(input: RealCStringTable) -> TokenStream {
+	let table = input;
+	let element_type = table.transform_type.element_type();
+	let items = table.entries.iter().map(|entry| {
+		let RealCStringTableEntry {
+			attrs,
+			visibility,
+			name,
+			string,
+		} = entry;
+		// Statics are items, so errors don't need to be an expression
+		let elements = match transform_elements(string, table.transform_type) {
+			Ok(elements) => elements,
+			Err(errors) => return errors.to_compile_error(),
+		};
+		let terminator = transform_terminator(string, table.transform_type);
+		let len = elements.len() + terminator.len();
+		let attributes = static_attributes(&string.options);
+		quote! {
+			#(#attrs)*
+			#attributes
+			#visibility static #name: [#element_type; #len] = [#(#elements,)* #(#terminator,)*];
+		}
+	});
+	quote! {
+		#(#items)*
+	}
+}
+
+/// Transforms passed getters to `#[no_mangle]` functions
+fn transform_fns(input: RealCStringFns) -> TokenStream {
+	let getters = input.0.iter().map(|getter| {
+		let RealCStringFn {
+			attrs,
+			visibility,
+			abi,
+			name,
+			return_type,
+			transform_type,
+			string,
+			export_name,
+		} = getter;
+		let pointer = transform(string, *transform_type);
+		// Attribute is unsafe since Rust 2024, and `unsafe(...)` form is accepted by every edition
+		let no_mangle = (!export_name).then(|| quote! { #[unsafe(no_mangle)] });
+		quote! {
+			#(#attrs)*
+			#no_mangle
+			#visibility #abi fn #name() -> #return_type {
+				#pointer
+			}
+		}
+	});
+	quote! {
+		#(#getters)*
+	}
+}
+
+/// Transforms passed declaration to `Sync` pointer wrapper
+fn transform_sync_ptr(input: SyncPointer) -> TokenStream {
+	let SyncPointer {
+		attrs,
+		visibility,
+		name,
+		pointer,
+	} = input;
+	quote! {
+		#(#attrs)*
+		#[repr(transparent)]
+		#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+		#visibility struct #name(#pointer);
+
+		// Pointed strings are immutable, and live as long as the program
+		unsafe impl ::core::marker::Sync for #name {}
+		unsafe impl ::core::marker::Send for #name {}
+
+		impl #name {
+			/// Wraps pointer to string
+			///
+			/// # Safety
+			///
+			/// Pointed data should be immutable, and live as long as the program.
+			#[inline]
+			pub const unsafe fn new(pointer: #pointer) -> Self {
+				Self(pointer)
+			}
+
+			/// Returns wrapped pointer
+			#[inline]
+			pub const fn as_ptr(self) -> #pointer {
+				self.0
+			}
+		}
+	}
+}
+
+/// Transforms passed pool to `static` array, array of offsets and accessors
+fn transform_pool(input: RealCStringPool) -> TokenStream {
+	let RealCStringPool {
+		attrs,
+		visibility,
+		name,
+		table,
+	} = input;
+	let element_type = table.transform_type.element_type();
+	let mut elements = Vec::new();
+	let mut offsets = Vec::new();
+	let mut stored: Vec<(Vec<u32>, bool, usize)> = Vec::new();
+	let mut errors: Option<syn::Error> = None;
+	for entry in &table.entries {
+		let mut units = match transform_units(&entry.string, table.transform_type) {
+			Ok(units) => units,
+			Err(error) => {
+				match &mut errors {
+					Some(errors) => errors.combine(error),
+					None => errors = Some(error),
+				}
+				continue;
+			}
+		};
+		units.extend(terminator_units(&entry.string));
+		// Byte order is applied to code units in invoking crate, so it's a part of content
+		let big_endian = entry.string.options.big_endian;
+		let shared = stored.iter().find(|(other, other_big_endian, _)| {
+			*other == units && *other_big_endian == big_endian
+		});
+		if let (true, Some(&(_, _, offset))) = (table.dedup, shared) {
+			offsets.push(offset);
+			continue;
+		}
+		let offset = elements.len();
+		elements.extend(
+			units
+				.iter()
+				.map(|&unit| table.transform_type.unit(unit, &entry.string.options)),
+		);
+		offsets.push(offset);
+		stored.push((units, big_endian, offset));
+	}
+	if let Some(errors) = errors {
+		return errors.to_compile_error();
+	}
+	let len = elements.len();
+	let offsets_name = quote::format_ident!("{}_OFFSETS", name);
+	let offsets_len = offsets.len();
+	let offset_values = offsets.iter().map(|&offset| offset as u32);
+	let accessors = table.entries.iter().zip(&offsets).map(|(entry, offset)| {
+		let RealCStringTableEntry {
+			attrs,
+			visibility,
+			name: entry_name,
+			..
+		} = entry;
+		quote! {
+			#(#attrs)*
+			#[allow(non_snake_case)]
+			#[inline]
+			#visibility fn #entry_name() -> *const #element_type {
+				#name.as_ptr().wrapping_add(#offset)
+			}
+		}
+	});
+	quote! {
+		#(#attrs)*
+		#visibility static #name: [#element_type; #len] = [#(#elements,)*];
+		#visibility static #offsets_name: [u32; #offsets_len] = [#(#offset_values,)*];
+		#(#accessors)*
+	}
+}
+
+/// Transforms passed arms to function, matching ID
+fn transform_lookup(input: RealCStringLookup) -> TokenStream {
+	let RealCStringLookup {
+		attrs,
+		visibility,
+		abi,
+		name,
+		id_type,
+		return_type,
+		transform_type,
+		arms,
+		default,
+	} = input;
+	let arms = arms.iter().map(|(id, string)| {
+		let string = transform(string, transform_type);
+		quote! { #id => #string, }
+	});
+	let default = transform(&default, transform_type);
+	quote! {
+		#(#attrs)*
+		#visibility #abi fn #name(id: #id_type) -> #return_type {
+			match id {
+				#(#arms)*
+				_ => #default,
+			}
+		}
+	}
+}
+
+/// Transforms passed string to code, decompressing it on first use
+#[cfg(feature = "compression")]
+fn transform_compressed(input: RealCString) -> TokenStream {
+	let units = match transform_units(&input, TransformType::CString) {
+		Ok(units) => units,
+		Err(errors) => return error_expression(errors),
+	};
+	let bytes: Vec<u8> = units.iter().map(|&unit| unit as u8).collect();
+	let compressed = proc_macro2::Literal::byte_string(&compress::compress(&bytes));
+	let len = bytes.len();
+	let terminator: Vec<u8> = terminator_units(&input)
+		.iter()
+		.map(|&unit| unit as u8)
+		.collect();
+	let size = len + terminator.len();
+	let terminator = proc_macro2::Literal::byte_string(&terminator);
+	let min_match = compress::MIN_MATCH;
+	quote! {
+		{
+			struct Buffer(::core::cell::UnsafeCell<[u8; #size]>);
+			// Buffer is only written once, before `READY` state is published
+			unsafe impl ::core::marker::Sync for Buffer {}
+			static BUFFER: Buffer = Buffer(::core::cell::UnsafeCell::new([0; #size]));
+			static COMPRESSED: &[u8] = #compressed;
+			const EMPTY: u8 = 0;
+			const BUSY: u8 = 1;
+			const READY: u8 = 2;
+			static STATE: ::core::sync::atomic::AtomicU8 = ::core::sync::atomic::AtomicU8::new(EMPTY);
+			use ::core::sync::atomic::Ordering;
+			if STATE.load(Ordering::Acquire) != READY {
+				if STATE
+					.compare_exchange(EMPTY, BUSY, Ordering::Acquire, Ordering::Acquire)
+					.is_ok()
+				{
+					let buffer = unsafe { &mut *BUFFER.0.get() };
+					let mut input = 0;
+					let mut output = 0;
+					while output < #len {
+						let flags = COMPRESSED[input];
+						input += 1;
+						let mut token = 0;
+						while token < 8 && output < #len {
+							if flags & (1 << token) == 0 {
+								buffer[output] = COMPRESSED[input];
+								input += 1;
+								output += 1;
+							} else {
+								let value = COMPRESSED[input] as usize | (COMPRESSED[input + 1] as usize) << 8;
+								input += 2;
+								let distance = (value >> 4) + 1;
+								let end = output + (value & 0xf) + #min_match;
+								while output < end {
+									buffer[output] = buffer[output - distance];
+									output += 1;
+								}
+							}
+							token += 1;
+						}
+					}
+					buffer[#len..].copy_from_slice(#terminator);
+					STATE.store(READY, Ordering::Release);
+				} else {
+					while STATE.load(Ordering::Acquire) != READY {
+						::core::hint::spin_loop();
+					}
+				}
+			}
+			BUFFER.0.get() as *const i8
+		}
+	}
+}
+
+/// Produces a C-string literal with the same representation as that of strings in C at ASM level
+/// Used in vmprotect crate, because vmprotect disassembles code and finds usages like this.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// Byte strings are accepted too, their bytes are passed through verbatim.
+/// Char literals are treated as strings of single character.
+/// Options described in crate documentation may be passed before string.
+///
+/// Adjacent literals are concatenated, as in C, and can be separated by commas to mix them with
+/// integer literals, which are passed through as single code units. Several code units can be passed
+/// as `raw(0x9c, 0x9d)`, i.e for bytes of legacy protocols, which aren't characters of any encoding.
+/// Builtin `concat!`, `env!`, `option_env!` and `stringify!` macros are expanded in place of literals,
+/// unset variables in `option_env!` are expanded to empty string.
+///
+/// ```rust
+/// use real_c_string::real_c_string;
+/// assert_eq!(0i8, unsafe { *real_c_string!("") });
+///
+/// let c_string = real_c_string!("Hello world!");
+/// let same_as_array_of_bytes: [i8; 13] =
+///     [72i8, 101i8, 108i8, 108i8, 111i8, 32i8, 119i8, 111i8, 114i8, 108i8, 100i8, 33i8, 0i8];
+/// for i in 0..13 {
+///     assert_eq!(
+///         same_as_array_of_bytes[i],
+///         unsafe { *c_string.offset(i as isize) }
+///     );
+/// }
+///
+/// let c_string = real_c_string!('A');
+/// assert_eq!([65i8, 0i8], unsafe { *(c_string as *const [i8; 2]) });
+///
+/// let c_string = real_c_string!(b"\x01\x02\x80\xffABC");
+/// let same_as_array_of_bytes: [i8; 8] = [1i8, 2i8, -128i8, -1i8, 65i8, 66i8, 67i8, 0i8];
+/// for i in 0..8 {
+///     assert_eq!(
+///         same_as_array_of_bytes[i],
+///         unsafe { *c_string.offset(i as isize) }
+///     );
+/// }
+///
+/// let c_string = real_c_string!("abc", raw(0x9c), "def");
+/// assert_eq!(b"abc\x9cdef\0".map(|b| b as i8), unsafe { *(c_string as *const [i8; 8]) });
+/// assert_eq!(real_c_string::real_c_string_len!("abc", raw(0x9c, 0x9d), "def"), 8);
+///
+/// let c_string = real_c_string!("SELECT * " r"FROM t " "WHERE x = ?");
+/// let same_as_byte_string = b"SELECT * FROM t WHERE x = ?\0";
+/// for i in 0..same_as_byte_string.len() {
+///     assert_eq!(
+///         same_as_byte_string[i] as i8,
+///         unsafe { *c_string.offset(i as isize) }
+///     );
+/// }
+///
+/// let c_string = real_c_string!(concat!("app-", env!("CARGO_PKG_NAME"), "/", 1, ".", 0));
+/// let same_as_byte_string = b"app-real_c_string/1.0\0";
+/// for i in 0..same_as_byte_string.len() {
+///     assert_eq!(
+///         same_as_byte_string[i] as i8,
+///         unsafe { *c_string.offset(i as isize) }
+///     );
+/// }
+///
+/// let c_string = real_c_string!(concat!(env!("CARGO_PKG_NAME"), "-suffix") " " stringify!(x));
+/// let same_as_byte_string = b"real_c_string-suffix x\0";
+/// for i in 0..same_as_byte_string.len() {
+///     assert_eq!(
+///         same_as_byte_string[i] as i8,
+///         unsafe { *c_string.offset(i as isize) }
+///     );
+/// }
+///
+/// let c_string = real_c_string!("MAGIC", 0x02, 0x1F, b'\xff', "v1");
+/// let same_as_byte_string = b"MAGIC\x02\x1f\xffv1\0";
+/// for i in 0..same_as_byte_string.len() {
+///     assert_eq!(
+///         same_as_byte_string[i] as i8,
+///         unsafe { *c_string.offset(i as isize) }
+///     );
+/// }
+///
+/// let c_string = real_c_string!(option_env!("REAL_C_STRING_SURELY_UNSET_VARIABLE"));
+/// assert_eq!(0i8, unsafe { *c_string });
+/// ```
+///
+/// Other literals are rejected:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string;
+/// // expected string, byte string, char or integer literal, found float literal
+/// let c_string = real_c_string!(4.2);
+/// ```
+///
+/// Errors for other unexpected input point at the offending token, and say what macro takes:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string;
+/// // unexpected end of input, expected string literal, macro takes string literals, optionally preceded by options, ...
+/// let c_string = real_c_string!();
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string;
+/// // expected string literal, found `name`, macro takes string literals, optionally preceded by options, ...
+/// let name = "user";
+/// let c_string = real_c_string!("Hello, ", name);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string;
+/// // expected `,` or string literal, found `+`, macro takes string literals, optionally preceded by options, ...
+/// let c_string = real_c_string!("Hello, " + "world");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string;
+/// // unknown option `hello`, options are followed by string literals, i.e `no_nul "TAG"`
+/// let c_string = real_c_string!(hello);
+/// ```
+///
+/// Same as integers, which don't fit into a single byte:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string;
+/// // Code unit 0x100 at argument 2 doesn't fit, max is 0xff
+/// let c_string = real_c_string!("MAGIC", 0x100);
+/// ```
+///
+/// Every character, which doesn't fit into a single byte, is reported in a single error, pointing at literals:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string;
+/// // Unsupported characters 'Ж' (U+0416) at offset 0, '✓' (U+2713) at offset 2
+/// let c_string = real_c_string!("Ж ✓");
+/// ```
+///
+/// And so are other macros, which can't be expanded by proc macro:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string;
+/// macro_rules! hello {
+///     () => {"hello"};
+/// }
+/// let c_string = real_c_string!(hello!());
+/// ```
+///
+/// Strings are promoted constants, so rustc usually stores identical strings of a crate only once, even if
+/// they are produced by different invocations. It is not guaranteed, and addresses of constants may be equal
+/// or differ, so use `addr_stable` when address matters. Identical strings of different crates are not merged,
+/// as every crate stores its own constants. Strings stored in statics with `symbol`, `section`, `align` or
+/// `keep` options are not merged too.
+///
+/// With `dedup`, invocations of crate with the same string and encoding always share one static:
+///
+/// ```rust
+/// use real_c_string::{real_c_string, real_c_wstring};
+/// mod first {
+///     pub fn kernel32() -> *const i8 {
+///         real_c_string::real_c_string!(dedup "kernel32.dll")
+///     }
+/// }
+/// mod second {
+///     pub fn kernel32() -> *const i8 {
+///         real_c_string::real_c_string!(dedup "kernel32" ".dll")
+///     }
+/// }
+/// assert_eq!(first::kernel32(), second::kernel32());
+/// assert_ne!(first::kernel32(), real_c_string!(dedup "kernel32.DLL"));
+/// assert_ne!(
+///     first::kernel32() as *const u8,
+///     real_c_string!(dedup, encoding = "windows-1251", "kernel32.dll") as *const u8,
+/// );
+/// assert_eq!(real_c_wstring!(dedup "kernel32.dll"), real_c_wstring!(dedup "kernel32.dll"));
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string;
+/// // `dedup` can't be combined with `symbol` and `addr_stable`, which give string its own static
+/// let c_string = real_c_string!(dedup, addr_stable, "kernel32.dll");
+/// ```
+///
+/// With `addr_stable`, address of string may be used as a unique key:
+///
+/// ```rust
+/// use real_c_string::{real_c_string, real_c_wstring};
+/// fn sentinel() -> *const i8 {
+///     real_c_string!(addr_stable "kernel32.dll")
+/// }
+/// assert_eq!(sentinel(), sentinel());
+/// assert_ne!(sentinel(), real_c_string!(addr_stable "kernel32.dll"));
+/// let keys: Vec<_> = (0..2).map(|_| real_c_wstring!(addr_stable "key")).collect();
+/// assert_eq!(keys[0], keys[1]);
+/// assert_ne!(keys[0], real_c_wstring!(addr_stable "key"));
+/// ```
+#[proc_macro]
+pub fn real_c_string(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string`, but produces array by value, usable for initializing `char name[N]` fields
+///
+/// The result of this macro invocation is of type `[i8; N]`, where `N` includes terminating zero.
+///
+/// ```rust
+/// use real_c_string::real_c_string_array;
+/// let a: [i8; 1] = real_c_string_array!("");
+/// assert_eq!(a, [0i8]);
+///
+/// let a: [i8; 4] = real_c_string_array!("abc");
+/// assert_eq!(a, [97i8, 98i8, 99i8, 0i8]);
+///
+/// #[repr(C)]
+/// struct Device {
+///     id: u32,
+///     name: [i8; 8],
+/// }
+/// let device = Device {
+///     id: 1,
+///     name: real_c_string_array!("serial0"),
+/// };
+/// assert_eq!(device.name[6], b'0' as i8);
+/// assert_eq!(device.name[7], 0i8);
+/// ```
+#[proc_macro]
+pub fn real_c_string_array(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_array(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string`, but produces reference to array instead of pointer, so length is not lost
+///
+/// The result of this macro invocation is of type `&'static [i8; N]`, where `N` includes terminating zero.
+///
+/// ```rust
+/// use real_c_string::{real_c_string, real_c_string_ref};
+/// let r: &'static [i8; 1] = real_c_string_ref!("");
+/// assert_eq!(r, &[0i8]);
+///
+/// static HELLO: &[i8; 13] = real_c_string_ref!("Hello world!");
+/// assert_eq!(HELLO.len(), 13);
+/// let c_string = real_c_string!("Hello world!");
+/// for (i, c) in HELLO.iter().enumerate() {
+///     assert_eq!(*c, unsafe { *c_string.add(i) });
+///     assert_eq!(*c, unsafe { *HELLO.as_ptr().add(i) });
+/// }
+/// ```
+#[proc_macro]
+pub fn real_c_string_ref(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let array = transform_array(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CString,
+	);
+	quote! {&#array}.into()
+}
+
+/// Same as `real_c_string`, but produces `NonNull` pointer, because string pointer can never be null
+///
+/// The result of this macro invocation is of type `core::ptr::NonNull<i8>`, it is usable in `const` context.
+/// Pointed data is immutable, even though `NonNull` allows to get `*mut i8`.
+///
+/// ```rust
+/// use core::ptr::NonNull;
+/// use real_c_string::{real_c_string, real_c_string_nonnull};
+/// assert_eq!(0i8, unsafe { *real_c_string_nonnull!("").as_ptr() });
+///
+/// const HELLO: NonNull<i8> = real_c_string_nonnull!("Hello world!");
+/// let c_string = real_c_string!("Hello world!");
+/// for i in 0..13 {
+///     assert_eq!(
+///         unsafe { *c_string.add(i) },
+///         unsafe { *HELLO.as_ptr().add(i) }
+///     );
+/// }
+/// ```
+#[proc_macro]
+pub fn real_c_string_nonnull(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let pointer = transform(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CString,
+	);
+	quote! {
+		{
+			// Pointer is derived from reference, so it is never null
+			const NONNULL: ::core::ptr::NonNull<i8> =
+				unsafe { ::core::ptr::NonNull::new_unchecked(#pointer as *mut i8) };
+			NONNULL
+		}
+	}
+	.into()
+}
+
+/// Produces `CStr` with the same representation as `real_c_string_u8`
+///
+/// The result of this macro invocation is of type `&'static core::ffi::CStr`, it is usable in `const` context.
+/// Because `CStr` can't contain NUL characters, they are rejected.
+///
+/// ```rust
+/// use core::ffi::CStr;
+/// use real_c_string::real_cstr;
+/// assert_eq!(real_cstr!(""), CStr::from_bytes_with_nul(b"\0").unwrap());
+///
+/// const HELLO: &CStr = real_cstr!("hello");
+/// assert_eq!(HELLO, CStr::from_bytes_with_nul(b"hello\0").unwrap());
+/// assert_eq!(HELLO.to_bytes().len(), 5);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_cstr;
+/// let c_str = real_cstr!("hello\0world");
+/// ```
+#[proc_macro]
+pub fn real_cstr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	if let Some(error) = check_c_str(&input, "CStr") {
+		return error.into();
+	}
+	let array = transform_array(&input, TransformType::CStringU8);
+	quote! {
+		{
+			// Array contains single trailing NUL, which is checked above
+			const CSTR: &::core::ffi::CStr =
+				unsafe { ::core::ffi::CStr::from_bytes_with_nul_unchecked(&#array) };
+			CSTR
+		}
+	}
+	.into()
+}
+
+/// Same as `real_c_string_ref`, but produces unsigned bytes
+///
+/// Unlike `b"...\0"`, every character is checked to fit in a single byte, as with `real_c_string`.
+///
+/// The result of this macro invocation is of type `&'static [u8; N]`, where `N` includes terminating zero.
+///
+/// ```rust
+/// use core::ffi::CStr;
+/// use real_c_string::real_c_string_bytes;
+/// assert!(CStr::from_bytes_with_nul(real_c_string_bytes!("hi")).is_ok());
+///
+/// let bytes: &'static [u8; 3] = real_c_string_bytes!("\u{e9}t");
+/// assert_eq!(bytes, b"\xe9t\0");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_bytes;
+/// let bytes = real_c_string_bytes!("été ✓");
+/// ```
+#[proc_macro]
+pub fn real_c_string_bytes(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let array = transform_array(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CStringU8,
+	);
+	quote! {&#array}.into()
+}
+
+/// Produces both `&'static str` and C string pointer, viewing the same bytes, which are only stored once
+///
+/// String slice excludes terminator. Bytes should be valid UTF-8, which holds for ASCII strings, and for
+/// any string with `utf8` option. Without it, characters from `U+0080` to `U+00FF` are stored as single
+/// bytes, and with `encoding` option they are encoded in code page, so such strings are rejected,
+/// as well as byte strings, which aren't valid UTF-8. Options for statics, i.e `section`, are supported,
+/// except for `align`.
+///
+/// The result of this macro invocation is of type `(&'static str, *const i8)`.
+///
+/// ```rust
+/// use real_c_string::real_c_string_dual;
+/// let (name, c_name) = real_c_string_dual!("subsystem.init");
+/// assert_eq!(name, "subsystem.init");
+/// assert_eq!(name.as_ptr() as *const i8, c_name);
+/// assert_eq!(unsafe { std::ffi::CStr::from_ptr(c_name) }.to_str(), Ok(name));
+///
+/// let (greeting, c_greeting) = real_c_string_dual!(utf8 "Привет");
+/// assert_eq!(greeting, "Привет");
+/// assert_eq!(greeting.as_ptr() as *const i8, c_greeting);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_dual;
+/// // Without `utf8`, "é" is stored as single byte 0xE9, which is not valid UTF-8
+/// let (name, c_name) = real_c_string_dual!("café");
+/// ```
+#[proc_macro]
+pub fn real_c_string_dual(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_dual(parse_macro_input!(input as RealCString)).into()
+}
+
+/// Same as `real_c_string`, but produces pointer to fresh mutable buffer, for APIs which want `char*`
+///
+/// Buffer is a temporary created on every evaluation, so when bound using `let` it lives until the end
+/// of enclosing scope, otherwise it only lives until the end of enclosing statement.
+/// Optional size can be passed before string to reserve additional zeroed space at the end of buffer.
+///
+/// The result of this macro invocation is of type `*mut i8`.
+///
+/// ```rust
+/// use real_c_string::real_c_string_buf_mut;
+///
+/// let path = real_c_string_buf_mut!("path");
+/// unsafe { *path = b'b' as i8 };
+/// let same_as_array_of_bytes = [98i8, 97i8, 116i8, 104i8, 0i8];
+/// for i in 0..5 {
+///     assert_eq!(same_as_array_of_bytes[i], unsafe { *path.add(i) });
+/// }
+///
+/// // Every evaluation produces a fresh buffer
+/// for _ in 0..2 {
+///     let buf = real_c_string_buf_mut!("a");
+///     assert_eq!(unsafe { *buf }, b'a' as i8);
+///     unsafe { *buf = b'b' as i8 };
+/// }
+///
+/// let buf = real_c_string_buf_mut!(8, "abc");
+/// for i in 3..8 {
+///     assert_eq!(0i8, unsafe { *buf.add(i) });
+///     unsafe { *buf.add(i) = b'd' as i8 };
+/// }
+/// ```
+///
+/// Size should be enough to fit string with terminating zero:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_buf_mut;
+/// let buf = real_c_string_buf_mut!(3, "abc");
+/// ```
+#[proc_macro]
+pub fn real_c_string_buf_mut(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as SizedRealCString);
+	let array = transform_padded(&input.string, input.size, TransformType::CString);
+	quote! {
+		&mut #array as *mut i8
+	}
+	.into()
+}
+
+/// Same as `real_c_string`, but also produces string length, for APIs which accept explicit length
+///
+/// The result of this macro invocation is of type `(*const i8, usize)`, length doesn't include terminating zero.
+///
+/// ```rust
+/// use real_c_string::real_c_string_ptr_len;
+/// let (_, len) = real_c_string_ptr_len!("");
+/// assert_eq!(len, 0);
+///
+/// let (ptr, len) = real_c_string_ptr_len!("Hello world!");
+/// assert_eq!(len, 12);
+/// assert_eq!(0i8, unsafe { *ptr.add(len) });
+/// ```
+#[proc_macro]
+pub fn real_c_string_ptr_len(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_ptr_len(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string`, but produces unsigned bytes, for bindings where `char` is unsigned
+///
+/// The result of this macro invocation is of type `*const u8`.
+///
+/// ```rust
+/// use real_c_string::real_c_string_u8;
+/// assert_eq!(0u8, unsafe { *real_c_string_u8!("") });
+///
+/// let c_string = real_c_string_u8!("Hello world!");
+/// let same_as_byte_string = b"Hello world!\0";
+/// for i in 0..13 {
+///     assert_eq!(
+///         same_as_byte_string[i],
+///         unsafe { *c_string.offset(i as isize) }
+///     );
+/// }
+///
+/// let c_string = real_c_string_u8!("\u{a9}\u{ff}");
+/// let same_as_byte_string = b"\xa9\xff\0";
+/// for i in 0..3 {
+///     assert_eq!(
+///         same_as_byte_string[i],
+///         unsafe { *c_string.offset(i as isize) }
+///     );
+/// }
+/// ```
+///
+/// Characters above `0xff` are rejected, same as with `real_c_string`:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_u8;
+/// let c_string = real_c_string_u8!("Привет");
+/// ```
+#[proc_macro]
+pub fn real_c_string_u8(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CStringU8,
+	)
+	.into()
+}
+
+/// Same as `real_c_string`, but produces `c_char` elements, which are signed or unsigned depending on target
+///
+/// The result of this macro invocation is of type `*const core::ffi::c_char`, so it can be passed
+/// to bindgen-generated functions on any target without casts.
+///
+/// ```rust
+/// use core::ffi::c_char;
+/// use real_c_string::real_c_char_string;
+/// assert_eq!(0 as c_char, unsafe { *real_c_char_string!("") });
+///
+/// let c_string: *const c_char = real_c_char_string!("Hello world!");
+/// let same_as_byte_string = b"Hello world!\0";
+/// for i in 0..13 {
+///     assert_eq!(
+///         same_as_byte_string[i] as c_char,
+///         unsafe { *c_string.offset(i as isize) }
+///     );
+/// }
+///
+/// let c_string = real_c_char_string!("\u{80}\u{a9}\u{ff}");
+/// let same_as_byte_string = b"\x80\xa9\xff\0";
+/// for i in 0..4 {
+///     assert_eq!(
+///         same_as_byte_string[i] as c_char,
+///         unsafe { *c_string.offset(i as isize) }
+///     );
+///     assert_eq!(
+///         same_as_byte_string[i],
+///         unsafe { *c_string.offset(i as isize) } as u8
+///     );
+/// }
+/// ```
+#[proc_macro]
+pub fn real_c_char_string(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CCharString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string`, but used for wchar_t* strings
+///
+/// The result of this macro invocation is of type `*const i16`.
+///
+/// Char literals are treated as strings of single character:
+///
+/// ```rust
+/// use real_c_string::real_c_wstring;
+/// let c_wstring = real_c_wstring!('Ж');
+/// assert_eq!([1046i16, 0i16], unsafe { *(c_wstring as *const [i16; 2]) });
+/// ```
+///
+/// Strings are encoded as UTF-16, so characters above `U+FFFF` take two code units, and offsets and
+/// lengths are measured in code units:
+///
+/// ```rust
+/// use real_c_string::{real_c_wstring_array, real_c_wstring_ptr_len};
+/// let party = real_c_wstring_array!("🎉 done");
+/// let expected: Vec<i16> = "🎉 done\0".encode_utf16().map(|u| u as i16).collect();
+/// assert_eq!(party[..], expected[..]);
+/// assert_eq!(party[..2], [0xd83cu16 as i16, 0xdf89u16 as i16]);
+///
+/// let (_, len) = real_c_wstring_ptr_len!("Жук 🐞 и 🦀");
+/// assert_eq!(len, "Жук 🐞 и 🦀".encode_utf16().count());
+/// assert_eq!(len, 11);
+/// ```
+///
+/// `ucs2` option rejects characters which need surrogate pairs, for consumers which don't support them.
+/// Offsets in errors are reported relative to the concatenated string:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wstring;
+/// // Unsupported character '🦀' (U+1F980) at offset 6
+/// let c_wstring = real_c_wstring!(ucs2 "Hello " "🦀");
+/// ```
+///
+/// Offsets in errors are reported after applying options:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wstring;
+/// // Unsupported character '🦀' (U+1F980) at offset 9
+/// let c_wstring = real_c_wstring!(ucs2, newlines = "crlf", "a\nb\nc\n🦀");
+/// ```
+///
+/// `big_endian` option makes in-memory representation big-endian regardless of target:
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_array;
+/// let a = real_c_wstring_array!(big_endian "A", 0xfeff);
+/// assert_eq!(a.map(|u| i16::from_be(u) as u16), [0x0041, 0xfeff, 0]);
+/// #[cfg(target_endian = "little")]
+/// assert_eq!(a, [0x4100, 0xfffeu16 as i16, 0]);
+/// let bytes: Vec<u8> = a.iter().flat_map(|u| u.to_ne_bytes()).collect();
+/// assert_eq!(bytes, [0x00, 0x41, 0xfe, 0xff, 0x00, 0x00]);
+/// ```
+///
+/// `max_len` is measured in code units, not bytes:
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_array;
+/// let name: [i16; 7] = real_c_wstring_array!(max_len = 7, "Привет");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wstring;
+/// let name = real_c_wstring!(max_len = 6, "Привет");
+/// ```
+///
+/// Interior NUL characters are rejected, unless `allow_interior_nul` option is passed:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wstring;
+/// let c_wstring = real_c_wstring!("abc\0def");
+/// ```
+///
+/// Integer literals up to `0xffff` are passed through as single code units:
+///
+/// ```rust
+/// use real_c_string::real_c_wstring;
+/// let c_wstring = real_c_wstring!("A", 0xfeff, "B");
+/// assert_eq!([65i16, -257i16, 66i16, 0i16], unsafe { *(c_wstring as *const [i16; 4]) });
+/// ```
+///
+/// Code units passed as `raw(...)` are not checked to form valid UTF-16, so they may be unpaired surrogates,
+/// i.e for WTF-16 file names, and they are counted by length and hash macros as any other code units:
+///
+/// ```rust
+/// use real_c_string::{real_c_wstring, real_c_wstring_len};
+/// let name = real_c_wstring!("prefix", raw(0xd800, 0x0041), "suffix");
+/// let units = unsafe { std::slice::from_raw_parts(name as *const u16, 15) };
+/// assert_eq!(&units[5..9], [0x78, 0xd800, 0x41, 0x73]);
+/// assert_eq!(String::from_utf16(&units[..14]).is_err(), true);
+/// assert_eq!(real_c_wstring_len!("prefix", raw(0xd800, 0x0041), "suffix"), 14);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wstring;
+/// // Code unit 0x10000 at argument 3 doesn't fit, max is 0xffff
+/// let name = real_c_wstring!("prefix", raw(0xd800, 0x10000));
+/// ```
+///
+/// Byte strings are rejected, as there is no single obvious way to widen them, unless they are pre-encoded
+/// UTF-16LE code units, passed with `utf16le` option:
+///
+/// ```rust
+/// use real_c_string::{real_c_wstring, real_c_wstring_len};
+/// let exported = real_c_wstring!(utf16le b"\xff\xfeH\x00i\x00");
+/// assert_eq!([72i16, 105i16, 0i16], unsafe { *(exported as *const [i16; 3]) });
+/// assert_eq!(real_c_wstring_len!(utf16le b"\x3d\xd8\x00\xde"), 2);
+/// let wtf16 = real_c_wstring!(utf16le raw b"\x00\xd8");
+/// assert_eq!(0xd800, unsafe { *(wtf16 as *const u16) });
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wstring;
+/// // UTF-16LE byte string has odd length 3, code units are pairs of bytes
+/// let c_wstring = real_c_wstring!(utf16le b"H\x00i");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wstring;
+/// // Unpaired surrogate 0xd800 at offset 1 of UTF-16LE byte string, use `raw` if this is intended
+/// let c_wstring = real_c_wstring!(utf16le b"H\x00\x00\xd8");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wstring;
+/// let c_wstring = real_c_wstring!(b"Hello");
+/// ```
+///
+/// ```rust
+/// use real_c_string::real_c_wstring;
+/// assert_eq!(0i16, unsafe { *real_c_wstring!("") });
+///
+/// let c_wstring = real_c_wstring!("Hello world!");
+/// let same_as_array_of_bytes: [i16; 13] =
+///     [72i16, 101i16, 108i16, 108i16, 111i16, 32i16, 119i16, 111i16, 114i16, 108i16, 100i16, 33i16, 0i16];
+/// for i in 0..13 {
+///     assert_eq!(
+///         same_as_array_of_bytes[i],
+///         unsafe { *c_wstring.offset(i as isize) },
+///     );
+/// }
+///
+/// let c_wstring = real_c_wstring!("Привет world!");
+/// let same_as_array_of_bytes: [i16; 14]
+///     = [1055i16, 1088i16, 1080i16, 1074i16, 1077i16, 1090i16, 32i16, 119i16, 111i16, 114i16, 108i16, 100i16, 33i16, 0i16];
+/// for i in 0..13 {
+///     assert_eq!(
+///         same_as_array_of_bytes[i],
+///         unsafe { *c_wstring.offset(i as isize) },
+///     );
+/// }
+/// ```
+#[proc_macro]
+pub fn real_c_wstring(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Same as `real_c_wstring`, but produces array by value, usable for initializing `wchar_t name[N]` fields
+///
+/// The result of this macro invocation is of type `[i16; N]`, where `N` includes terminating zero.
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_array;
+/// let a: [i16; 1] = real_c_wstring_array!("");
+/// assert_eq!(a, [0i16]);
+///
+/// let a: [i16; 4] = real_c_wstring_array!("abc");
+/// assert_eq!(a, [97i16, 98i16, 99i16, 0i16]);
+///
+/// // 6 characters take 12 bytes in UTF-8, but only 6 code units here
+/// let a: [i16; 7] = real_c_wstring_array!("Привет");
+/// assert_eq!(a, [1055i16, 1088i16, 1080i16, 1074i16, 1077i16, 1090i16, 0i16]);
+/// ```
+///
+/// `bom` option prepends byte order mark, for consumers detecting byte order by it:
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_array;
+/// let text: [u16; 4] = real_c_wstring_array!(bom, no_nul "abc").map(|u| u as u16);
+/// let bytes: Vec<u8> = text.iter().flat_map(|u| u.to_le_bytes()).collect();
+/// // UTF-16LE byte order mark
+/// assert_eq!(bytes[..2], [0xff, 0xfe]);
+///
+/// let be = real_c_wstring_array!(bom, big_endian "abc");
+/// assert_eq!(be[0].to_ne_bytes(), [0xfe, 0xff]);
+/// ```
+///
+/// Characters above `0xffff` are encoded as surrogate pairs, same as with `real_c_wstring`:
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_array;
+/// let a: [i16; 3] = real_c_wstring_array!("🦀");
+/// assert_eq!(a, [0xd83eu16 as i16, 0xdd80u16 as i16, 0i16]);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wstring_array;
+/// let a = real_c_wstring_array!(ucs2 "🦀");
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_array(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_array(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Same as `real_c_wstring`, but produces reference to array instead of pointer, so length is not lost
+///
+/// The result of this macro invocation is of type `&'static [i16; N]`, where `N` includes terminating zero.
+///
+/// ```rust
+/// use real_c_string::{real_c_wstring, real_c_wstring_ref};
+/// let r: &'static [i16; 1] = real_c_wstring_ref!("");
+/// assert_eq!(r, &[0i16]);
+///
+/// static HELLO: &[i16; 7] = real_c_wstring_ref!("Привет");
+/// assert_eq!(HELLO.len(), 7);
+/// assert_ne!(HELLO.len(), "Привет".len() + 1);
+/// let c_wstring = real_c_wstring!("Привет");
+/// for (i, c) in HELLO.iter().enumerate() {
+///     assert_eq!(*c, unsafe { *c_wstring.add(i) });
+/// }
+/// ```
+///
+/// Characters above `0xffff` are rejected with `ucs2` option, same as with `real_c_wstring`:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wstring_ref;
+/// let r = real_c_wstring_ref!(ucs2 "🦀");
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_ref(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let array = transform_array(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CWString,
+	);
+	quote! {&#array}.into()
+}
+
+/// Same as `real_c_wstring`, but also produces string length, for APIs which accept explicit length
+///
+/// The result of this macro invocation is of type `(*const i16, usize)`, length is measured in code units
+/// and doesn't include terminating zero.
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_ptr_len;
+/// let (_, len) = real_c_wstring_ptr_len!("");
+/// assert_eq!(len, 0);
+///
+/// let (ptr, len) = real_c_wstring_ptr_len!("Привет world!");
+/// assert_eq!(len, 13);
+/// assert_eq!(0i16, unsafe { *ptr.add(len) });
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_ptr_len(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_ptr_len(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Same as `real_c_wstring`, but produces unsigned code units, as expected by WinAPI `LPCWSTR` arguments
+///
+/// The result of this macro invocation is of type `*const u16`.
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_u16;
+///
+/// extern "system" fn wide_len(string: *const u16) -> usize {
+///     let mut len = 0;
+///     while unsafe { *string.add(len) } != 0 {
+///         len += 1;
+///     }
+///     len
+/// }
+///
+/// assert_eq!(0, wide_len(real_c_wstring_u16!("")));
+/// assert_eq!(13, wide_len(real_c_wstring_u16!("Привет world!")));
+///
+/// let c_wstring = real_c_wstring_u16!("Привет world!");
+/// let same_as_utf16: Vec<u16> = "Привет world!\0".encode_utf16().collect();
+/// for i in 0..14 {
+///     assert_eq!(
+///         same_as_utf16[i],
+///         unsafe { *c_wstring.offset(i as isize) },
+///     );
+/// }
+/// ```
+///
+/// Characters above `0xffff` are encoded as surrogate pairs, same as with `real_c_wstring`:
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_u16;
+/// let c_wstring = real_c_wstring_u16!("🦀");
+/// assert_eq!([0xd83e, 0xdd80, 0], unsafe { *(c_wstring as *const [u16; 3]) });
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wstring_u16;
+/// let c_wstring = real_c_wstring_u16!(ucs2 "🦀");
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_u16(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CWStringU16,
+	)
+	.into()
+}
+
+/// Same as `real_c_wstring`, but used for 32-bit wchar_t* strings, found on Linux and macOS
+///
+/// Every character is stored as a single code unit, so there is no unsupported characters for this macro.
+///
+/// The result of this macro invocation is of type `*const i32`.
+///
+/// ```rust
+/// use real_c_string::real_c_lstring;
+/// assert_eq!(0i32, unsafe { *real_c_lstring!("") });
+///
+/// let c_lstring = real_c_lstring!("Привет 🦀!");
+/// let same_as_array_of_code_points: [i32; 10] =
+///     [1055i32, 1088i32, 1080i32, 1074i32, 1077i32, 1090i32, 32i32, 0x1f980i32, 33i32, 0i32];
+/// for i in 0..10 {
+///     assert_eq!(
+///         same_as_array_of_code_points[i],
+///         unsafe { *c_lstring.offset(i as isize) },
+///     );
+/// }
+/// ```
+#[proc_macro]
+pub fn real_c_lstring(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CLString,
+	)
+	.into()
+}
+
+/// Same as `real_c_lstring`, but produces unsigned code units, as C11 `char32_t` strings
+///
+/// The result of this macro invocation is of type `*const u32`.
+///
+/// ```rust
+/// use real_c_string::real_c_u32string;
+/// assert_eq!(0u32, unsafe { *real_c_u32string!("") });
+///
+/// let c_u32string = real_c_u32string!("🦀🎉");
+/// let same_as_chars: Vec<u32> = "🦀🎉\0".chars().map(|c| c as u32).collect();
+/// assert_eq!(same_as_chars.len(), 3);
+/// for i in 0..3 {
+///     assert_eq!(
+///         same_as_chars[i],
+///         unsafe { *c_u32string.offset(i as isize) },
+///     );
+/// }
+///
+/// let with_bom = real_c_u32string!(bom "🦀");
+/// assert_eq!([0xfeff, 0x1f980, 0], unsafe { *(with_bom as *const [u32; 3]) });
+/// ```
+#[proc_macro]
+pub fn real_c_u32string(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CU32String,
+	)
+	.into()
+}
+
+/// Produces wchar_t* string matching the width of `wchar_t` on target platform
+///
+/// On Windows this is the same as `real_c_wstring_u16`, and the result is of type `*const u16`,
+/// elsewhere this is the same as `real_c_lstring`, and the result is of type `*const i32`.
+///
+/// Characters above `0xffff` take two code units on Windows, as they are encoded as surrogate pairs.
+///
+/// ```rust
+/// use real_c_string::real_c_wchar_string;
+///
+/// let c_wchar_string = real_c_wchar_string!("Привет world!");
+/// let mut len = 0;
+/// while unsafe { *c_wchar_string.add(len) } != 0 {
+///     assert_eq!(
+///         "Привет world!".chars().nth(len).unwrap() as u32,
+///         unsafe { *c_wchar_string.add(len) } as u32,
+///     );
+///     len += 1;
+/// }
+/// assert_eq!(len, 13);
+///
+/// #[cfg(not(windows))]
+/// assert_eq!(0x1f980, unsafe { *real_c_wchar_string!("🦀") });
+/// #[cfg(windows)]
+/// assert_eq!(0xd83e, unsafe { *real_c_wchar_string!("🦀") });
+///
+/// // Wrapper of `sync` option is declared for pointer type of target
+/// use real_c_string::real_c_sync_ptr;
+/// #[cfg(windows)]
+/// real_c_sync_ptr!(struct WCharPtr(*const u16));
+/// #[cfg(not(windows))]
+/// real_c_sync_ptr!(struct WCharPtr(*const i32));
+/// static NAME: WCharPtr = real_c_wchar_string!(sync = WCharPtr, "name");
+/// assert_eq!(unsafe { *NAME.as_ptr() }, 'n' as _);
+/// ```
+#[proc_macro]
+pub fn real_c_wchar_string(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	let windows = transform_pointer(&input, TransformType::CWStringU16);
+	let other = transform_pointer(&input, TransformType::CLString);
+	// Wrapper is applied to pointer of selected type, so it should be declared for that type
+	transform_sync(
+		&input,
+		quote! {
+			{
+				#[cfg(windows)]
+				const WCHAR_STRING: *const u16 = #windows;
+				#[cfg(not(windows))]
+				const WCHAR_STRING: *const i32 = #other;
+				WCHAR_STRING
+			}
+		},
+	)
+	.into()
+}
+
+/// Produces `PCSTR` from `windows` crate, holding the same data as `real_c_string_u8`
+///
+/// Only emits path to `::windows::core::PCSTR`, so this crate doesn't depend on `windows` itself,
+/// but the invoking crate should.
+///
+/// The result of this macro invocation is of type `windows::core::PCSTR`.
+///
+/// ```rust,ignore
+/// use real_c_string::real_c_pcstr;
+/// use windows::Win32::System::LibraryLoader::GetModuleHandleA;
+/// let module = unsafe { GetModuleHandleA(real_c_pcstr!("kernel32.dll")) };
+/// ```
+#[cfg(feature = "windows-types")]
+#[proc_macro]
+pub fn real_c_pcstr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let pointer = transform(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CStringU8,
+	);
+	quote! {
+		::windows::core::PCSTR(#pointer)
+	}
+	.into()
+}
+
+/// Produces `PCWSTR` from `windows` crate, holding the same data as `real_c_wstring_u16`
+///
+/// Only emits path to `::windows::core::PCWSTR`, so this crate doesn't depend on `windows` itself,
+/// but the invoking crate should.
+///
+/// The result of this macro invocation is of type `windows::core::PCWSTR`.
+///
+/// ```rust,ignore
+/// use real_c_string::real_c_pcwstr;
+/// use windows::Win32::UI::WindowsAndMessaging::{MessageBoxW, MB_OK};
+/// unsafe { MessageBoxW(None, real_c_pcwstr!("Привет"), real_c_pcwstr!("Title"), MB_OK) };
+/// ```
+#[cfg(feature = "windows-types")]
+#[proc_macro]
+pub fn real_c_pcwstr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let pointer = transform(
+		&parse_macro_input!(input as RealCString),
+		TransformType::CWStringU16,
+	);
+	quote! {
+		::windows::core::PCWSTR(#pointer)
+	}
+	.into()
+}
+
+/// Produces `U16CStr` from `widestring` crate, holding the same data as `real_c_wstring_u16`
+///
+/// Only emits path to `::widestring::U16CStr`, so this crate doesn't depend on `widestring` itself,
+/// but the invoking crate should. Because `U16CStr` can't contain NUL characters, they are rejected.
+///
+/// The result of this macro invocation is of type `&'static widestring::U16CStr`, it is usable in `const` context.
+///
+/// ```rust,ignore
+/// use real_c_string::real_u16cstr;
+/// use widestring::{u16cstr, U16CStr};
+/// const HELLO: &U16CStr = real_u16cstr!("hello");
+/// assert_eq!(HELLO, u16cstr!("hello"));
+/// assert_eq!(real_u16cstr!("Привет"), u16cstr!("Привет"));
+/// ```
+#[cfg(feature = "widestring")]
+#[proc_macro]
+pub fn real_u16cstr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	if let Some(error) = check_c_str(&input, "U16CStr") {
+		return error.into();
+	}
+	let array = transform_array(&input, TransformType::CWStringU16);
+	quote! {
+		{
+			// Array contains single trailing NUL, which is checked above
+			const U16CSTR: &::widestring::U16CStr =
+				unsafe { ::widestring::U16CStr::from_slice_unchecked(&#array) };
+			U16CSTR
+		}
+	}
+	.into()
+}
+
+/// Produces `CStr16` from `uefi` crate, holding strict UCS-2 data
+///
+/// Characters above `0xffff` are rejected, so surrogate pairs are never produced, and because `CStr16`
+/// can't contain NUL characters, they are rejected too. Only emits path to `::uefi::CStr16`,
+/// so this crate doesn't depend on `uefi` itself, but the invoking crate should.
+///
+/// The result of this macro invocation is of type `&'static uefi::CStr16`, it is usable in `const` context,
+/// and `.as_ptr()` gives `*const Char16` for raw protocol calls.
+///
+/// ```rust,ignore
+/// use real_c_string::real_cstr16;
+/// use uefi::{cstr16, CStr16};
+/// const HELLO: &CStr16 = real_cstr16!("Hello, UEFI!");
+/// assert_eq!(HELLO, cstr16!("Hello, UEFI!"));
+/// system_table.stdout().output_string(HELLO).unwrap();
+/// ```
+#[cfg(feature = "uefi")]
+#[proc_macro]
+pub fn real_cstr16(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let mut input = parse_macro_input!(input as RealCString);
+	input.options.ucs2 = true;
+	if let Some(error) = check_c_str(&input, "CStr16") {
+		return error.into();
+	}
+	let array = transform_array(&input, TransformType::CWStringU16);
+	quote! {
+		{
+			// Array contains single trailing NUL, which is checked above, and only UCS-2 characters
+			const CSTR16: &::uefi::CStr16 =
+				unsafe { ::uefi::CStr16::from_u16_with_nul_unchecked(&#array) };
+			CSTR16
+		}
+	}
+	.into()
+}
+
+/// Same as `real_c_string`, but reads string from file, as `include_str!` does
+///
+/// Path is resolved relative to `CARGO_MANIFEST_DIR` of invoking crate, not to the invoking file.
+/// Options may precede path, and are applied to content of file, i.e `strip_comments "shader.glsl"`.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use real_c_string::real_c_include_str;
+/// let c_string = real_c_include_str!("README.md");
+/// let same_as_file = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"));
+/// for (i, byte) in same_as_file.bytes().enumerate() {
+///     assert_eq!(byte as i8, unsafe { *c_string.add(i) });
+/// }
+/// assert_eq!(0i8, unsafe { *c_string.add(same_as_file.len()) });
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_include_str;
+/// let c_string = real_c_include_str!("does/not/exist.txt");
+/// ```
+///
+/// Unsupported characters are reported with their line and column:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_include_str;
+/// // Unsupported character "Л" at .../Cargo.toml:4:13
+/// let c_string = real_c_include_str!("Cargo.toml");
+/// ```
+#[proc_macro]
+pub fn real_c_include_str(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_include(
+		parse_macro_input!(input as IncludedFile),
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_wstring`, but reads string from file, as `include_str!` does
+///
+/// Path is resolved relative to `CARGO_MANIFEST_DIR` of invoking crate, not to the invoking file.
+/// Options may precede path, same as for `real_c_include_str!`.
+///
+/// The result of this macro invocation is of type `*const i16`.
+///
+/// ```rust
+/// use real_c_string::real_c_include_wstr;
+/// let c_wstring = real_c_include_wstr!("Cargo.toml");
+/// let same_as_file = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"));
+/// let mut len = 0;
+/// for (i, unit) in same_as_file.encode_utf16().enumerate() {
+///     assert_eq!(unit as i16, unsafe { *c_wstring.add(i) });
+///     len += 1;
+/// }
+/// assert_eq!(0i16, unsafe { *c_wstring.add(len) });
+/// ```
+#[proc_macro]
+pub fn real_c_include_wstr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_include(
+		parse_macro_input!(input as IncludedFile),
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string`, but encodes string in passed legacy code page, same as `encoding` option
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use real_c_string::real_c_string_encoded;
+/// let c_string = real_c_string_encoded!("windows-1251", "Привет мир");
+/// let same_as_cp1251: [u8; 11] = [0xcf, 0xf0, 0xe8, 0xe2, 0xe5, 0xf2, 0x20, 0xec, 0xe8, 0xf0, 0];
+/// assert_eq!(same_as_cp1251.map(|b| b as i8), unsafe { *(c_string as *const [i8; 11]) });
+///
+/// let c_string = real_c_string_encoded!("koi8-r", no_nul "Ёж");
+/// assert_eq!([0xb3u8 as i8, 0xd6u8 as i8], unsafe { *(c_string as *const [i8; 2]) });
+/// ```
+///
+/// Characters missing in code page are rejected, instead of being replaced with `?`:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_encoded;
+/// // Unsupported character '€' (U+20AC) at offset 0
+/// let c_string = real_c_string_encoded!("koi8-r", "€");
+/// ```
+///
+/// EBCDIC code pages are supported too, and terminator is still `0x00`:
+///
+/// ```rust
+/// use real_c_string::real_c_string_encoded;
+/// let c_string = real_c_string_encoded!("IBM037", "A0");
+/// assert_eq!([0xc1u8 as i8, 0xf0u8 as i8, 0], unsafe { *(c_string as *const [i8; 3]) });
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_encoded;
+/// // Unsupported character '€' (U+20AC) at offset 0
+/// let c_string = real_c_string_encoded!("IBM1047", "€");
+/// ```
+///
+/// Unknown labels are rejected, listing supported code pages:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_encoded;
+/// // unknown encoding `utf-7`, supported encodings are: windows-1250, ...
+/// let c_string = real_c_string_encoded!("utf-7", "Hello");
+/// ```
+///
+/// Only single-byte code pages are supported, multi-byte `Shift_JIS`, `GBK` (including `gb2312` and
+/// `gb18030`) and `Big5` are rejected by any of their labels:
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_encoded;
+/// // multi-byte encoding `Shift_JIS` is not supported, supported encodings are single-byte: windows-1250, ...
+/// let c_string = real_c_string_encoded!("sjis", "こんにちは");
+/// ```
+#[proc_macro]
+pub fn real_c_string_encoded(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as EncodedRealCString).0,
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string`, but replaces substrings of string before transforming it
+///
+/// Arguments after string are pairs of substring and its replacement, which are applied in order,
+/// with the same semantics as `str::replace`.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use real_c_string::real_c_string_replace;
+/// let path = real_c_string_replace!("a/b/c", "/", "\\");
+/// assert_eq!(*b"a\\b\\c\0", unsafe { *(path as *const [u8; 6]) });
+///
+/// let title = real_c_string_replace!("{product} v{version}", "{product}", "Editor", "{version}", "1.0");
+/// assert_eq!(*b"Editor v1.0\0", unsafe { *(title as *const [u8; 12]) });
+///
+/// // Matches don't overlap, and replacements are not rescanned
+/// let replaced = real_c_string_replace!(no_nul "aaaa", "aa", "a");
+/// assert_eq!(*b"aa", unsafe { *(replaced as *const [u8; 2]) });
+/// ```
+///
+/// Replacement is done on string value, not on its source, so escapes match characters they produce:
+///
+/// ```rust
+/// use real_c_string::real_c_string_replace;
+/// let line = real_c_string_replace!("a\tb", "\t", " ");
+/// assert_eq!(*b"a b\0", unsafe { *(line as *const [u8; 4]) });
+/// ```
+#[proc_macro]
+pub fn real_c_string_replace(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as ReplacedRealCString).0,
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string_replace`, but used for wchar_t* strings
+///
+/// The result of this macro invocation is of type `*const i16`.
+///
+/// ```rust
+/// use real_c_string::{real_c_wstring_array, real_c_wstring_replace};
+/// let path = real_c_wstring_replace!("C:/Program Files/App", "/", "\\");
+/// let expected = real_c_wstring_array!("C:\\Program Files\\App");
+/// assert_eq!(expected, unsafe { *(path as *const [i16; 21]) });
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_replace(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as ReplacedRealCString).0,
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Same as `real_c_wstring_u16`, but converts and validates Windows path, for `CreateFileW` and similar APIs
+///
+/// Forward slashes are converted to backslashes, and reserved characters (`<>:"|?*` outside of drive)
+/// or device names (`CON`, `NUL`, `COM1`, ...) fail compilation. `long_path` option adds `\\?\` prefix,
+/// which lifts `MAX_PATH` limit, to absolute path.
+///
+/// The result of this macro invocation is of type `*const u16`.
+///
+/// ```rust
+/// use real_c_string::{real_c_wpath, real_c_wstring_u16};
+/// fn units(mut string: *const u16) -> Vec<u16> {
+///     let mut units = Vec::new();
+///     while unsafe { *string } != 0 {
+///         units.push(unsafe { *string });
+///         string = unsafe { string.add(1) };
+///     }
+///     units
+/// }
+/// let expected: Vec<u16> = "C:\\Program Files\\My App\\plugin.dll".encode_utf16().collect();
+/// assert_eq!(units(real_c_wpath!("C:/Program Files/My App/plugin.dll")), expected);
+///
+/// let expected: Vec<u16> = "\\\\?\\C:\\data\\nul_device.txt".encode_utf16().collect();
+/// assert_eq!(units(real_c_wpath!(long_path "C:/data/nul_device.txt")), expected);
+///
+/// let expected: Vec<u16> = "\\\\?\\UNC\\server\\share".encode_utf16().collect();
+/// assert_eq!(units(real_c_wpath!(long_path "//server/share")), expected);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wpath;
+/// // Character '?' is not allowed in Windows paths, found in segment "what?.txt"
+/// let path = real_c_wpath!("C:/data/what?.txt");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wpath;
+/// // Segment "con.log" is reserved device name in Windows paths
+/// let path = real_c_wpath!("logs/con.log");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wpath;
+/// // `long_path` requires absolute path with drive or UNC share, found "relative\path"
+/// let path = real_c_wpath!(long_path "relative/path");
+/// ```
+#[proc_macro]
+pub fn real_c_wpath(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as WindowsPath).0,
+		TransformType::CWStringU16,
+	)
+	.into()
+}
+
+/// Same as `real_c_wstring`, but validates GUID, and converts it to braced uppercase form, i.e for `CLSIDFromString`
+///
+/// GUID is accepted with or without braces, and hex digits are case-insensitive.
+///
+/// The result of this macro invocation is of type `*const i16`.
+///
+/// ```rust
+/// use real_c_string::{real_c_guid_wstring, real_c_wstring_array};
+/// let clsid = real_c_guid_wstring!("0002df01-0000-0000-c000-000000000046");
+/// let expected = real_c_wstring_array!("{0002DF01-0000-0000-C000-000000000046}");
+/// assert_eq!(unsafe { *(clsid as *const [i16; 39]) }, expected);
+/// let braced = real_c_guid_wstring!("{0002DF01-0000-0000-C000-000000000046}");
+/// assert_eq!(unsafe { *(braced as *const [i16; 39]) }, expected);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_guid_wstring;
+/// // Character 'G' at offset 3 is not a hex digit
+/// let clsid = real_c_guid_wstring!("000GDF01-0000-0000-C000-000000000046");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_guid_wstring;
+/// // Expected `-` at offset 13, found '0'
+/// let clsid = real_c_guid_wstring!("0002DF01-00000-000-C000-000000000046");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_guid_wstring;
+/// // GUID should have 36 characters, i.e `0002DF01-0000-0000-C000-000000000046`, found 35
+/// let clsid = real_c_guid_wstring!("{0002DF01-0000-0000-C000-00000000046}");
+/// ```
+#[proc_macro]
+pub fn real_c_guid_wstring(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as GuidString).string,
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Produces bytes of `GUID` struct, validating GUID the same way `real_c_guid_wstring` does, i.e for `DEFINE_GUID`
+///
+/// `Data1`, `Data2` and `Data3` fields are stored in little-endian order, as on Windows, and `Data4` is stored as is.
+///
+/// The result of this macro invocation is of type `[u8; 16]`.
+///
+/// ```rust
+/// use real_c_string::real_c_guid;
+/// const IID_IUNKNOWN: [u8; 16] = real_c_guid!("{00000000-0000-0000-C000-000000000046}");
+/// assert_eq!(IID_IUNKNOWN, [0, 0, 0, 0, 0, 0, 0, 0, 0xc0, 0, 0, 0, 0, 0, 0, 0x46]);
+/// let guid = real_c_guid!("01234567-89ab-cdef-0123-456789abcdef");
+/// assert_eq!(guid, [
+///     0x67, 0x45, 0x23, 0x01, 0xab, 0x89, 0xef, 0xcd,
+///     0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef,
+/// ]);
+/// ```
+#[proc_macro]
+pub fn real_c_guid(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let bytes = parse_macro_input!(input as GuidString).guid.to_bytes();
+	quote! {
+		[#(#bytes,)*]
+	}
+	.into()
+}
+
+/// Produces bytes decoded from hex dump, i.e copied from documentation of binary format
+///
+/// Bytes are pairs of hex digits, which may be separated by whitespace and commas, and every group
+/// of digits may be prefixed with `0x`. Bytes are not terminated, unless `with_nul` option is passed.
+///
+/// The result of this macro invocation is of type `&'static [u8; N]`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_bytes_hex;
+/// const MAGIC: &[u8; 6] = real_c_bytes_hex!("DEADBEEF0001");
+/// assert_eq!(MAGIC, &[0xde, 0xad, 0xbe, 0xef, 0x00, 0x01]);
+/// assert_eq!(real_c_bytes_hex!("de ad be ef 00 01"), MAGIC);
+/// assert_eq!(real_c_bytes_hex!("0xDEAD, 0xBEEF, 0x0001"), MAGIC);
+/// assert_eq!(real_c_bytes_hex!("
+///     DE AD BE EF
+///     00 01
+/// "), MAGIC);
+/// let terminated: *const u8 = real_c_bytes_hex!(with_nul "4D5A").as_ptr();
+/// assert_eq!(unsafe { *(terminated as *const [u8; 3]) }, *b"MZ\0");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_bytes_hex;
+/// // Hex digit at offset 6 has no pair
+/// let magic = real_c_bytes_hex!("DE AD B EF");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_bytes_hex;
+/// // Character 'G' at offset 3 is not a hex digit
+/// let magic = real_c_bytes_hex!("DE G0");
+/// ```
+#[proc_macro]
+pub fn real_c_bytes_hex(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let literal = proc_macro2::Literal::byte_string(&parse_macro_input!(input as HexBytes).0);
+	quote! {#literal}.into()
+}
+
+/// Produces bytes decoded from base64, i.e public keys and certificates
+///
+/// Alphabet is detected by characters used, or may be specified before options with
+/// `alphabet = "standard"` or `alphabet = "url_safe"`. Whitespace is ignored, so PEM bodies may be
+/// passed as is, and padding is optional, but should be correct if present. Bytes are not terminated,
+/// unless `with_nul` option is passed. Requires `base64` feature.
+///
+/// The result of this macro invocation is of type `&'static [u8; N]`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_bytes_base64;
+/// const KEY: &[u8; 4] = real_c_bytes_base64!("3q2+7w==");
+/// assert_eq!(KEY, &[0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(real_c_bytes_base64!("3q2-7w"), KEY);
+/// assert_eq!(real_c_bytes_base64!(alphabet = "url_safe", "3q2-7w=="), KEY);
+/// assert_eq!(real_c_bytes_base64!("
+///     3q2+
+///     7w==
+/// "), KEY);
+/// let terminated: *const u8 = real_c_bytes_base64!(with_nul "SGk=").as_ptr();
+/// assert_eq!(unsafe { *(terminated as *const [u8; 3]) }, *b"Hi\0");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_bytes_base64;
+/// // Invalid padding at offset 6, expected 2 `=`, found 1
+/// let key = real_c_bytes_base64!("3q2+7w=");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_bytes_base64;
+/// // Character '.' at offset 3 is not in base64 alphabet
+/// let key = real_c_bytes_base64!("3q2.7w==");
+/// ```
+#[cfg(feature = "base64")]
+#[proc_macro]
+pub fn real_c_bytes_base64(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let literal = proc_macro2::Literal::byte_string(&parse_macro_input!(input as Base64Bytes).0);
+	quote! {#literal}.into()
+}
+
+/// Same as `real_c_string`, but formats string from template and arguments at compile time, as `format!` does
+///
+/// Only `{}` and positional `{N}` placeholders are supported, `{{` and `}}` are escapes of braces, and
+/// every argument should be used. Arguments should be string, char, bool or integer literals, or builtin
+/// macros, i.e `env!("CARGO_PKG_VERSION")`.
+/// Format specs, i.e `{:>8}`, and named arguments are rejected.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use real_c_string::real_c_format;
+/// use std::ffi::CStr;
+/// let banner = real_c_format!("{}-{}.{}", "plugin", 2, 7);
+/// assert_eq!(unsafe { CStr::from_ptr(banner) }.to_str().unwrap(), format!("{}-{}.{}", "plugin", 2, 7));
+///
+/// let agent = real_c_format!("{0}/{1} ({0}; {2}) {{{3}}}", "App", 0x10, 'x', true);
+/// assert_eq!(
+///     unsafe { CStr::from_ptr(agent) }.to_str().unwrap(),
+///     format!("{0}/{1} ({0}; {2}) {{{3}}}", "App", 0x10, 'x', true)
+/// );
+///
+/// let offset = real_c_format!(upper "utc{}", -5);
+/// assert_eq!(unsafe { CStr::from_ptr(offset) }.to_bytes(), b"UTC-5");
+///
+/// let limits = real_c_format!("{} {} {} {} {}", -0, 255u8, -128i8, -170141183460469231731687303715884105728i128, 4294967295usize);
+/// assert_eq!(
+///     unsafe { CStr::from_ptr(limits) }.to_str().unwrap(),
+///     format!("{} {} {} {} {}", -0, 255u8, -128i8, -170141183460469231731687303715884105728i128, 4294967295usize)
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_format;
+/// // integer literal `256u8` is out of range for `u8`
+/// let byte = real_c_format!("{}", 256u8);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_format;
+/// // integer literal `4294967296usize` is out of range for `usize` of 32-bit targets
+/// let size = real_c_format!("{}", 4294967296usize);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_format;
+/// // expected literal, found `name`, arguments should be literals or builtin macros, as formatting is done at compile time
+/// let name = "plugin";
+/// let banner = real_c_format!("{}-2.7", name);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_format;
+/// // Format spec in `{:>8}` at offset 0 is not supported, only `{}` and `{N}` are
+/// let column = real_c_format!("{:>8}", "name");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_format;
+/// // Argument 2 is never used
+/// let banner = real_c_format!("{}-{}", "plugin", 2, 7);
+/// ```
+#[proc_macro]
+pub fn real_c_format(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as FormattedRealCString).0,
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_format`, but used for wchar_t* strings
+///
+/// The result of this macro invocation is of type `*const i16`.
+///
+/// ```rust
+/// use real_c_string::real_c_wformat;
+/// let title = real_c_wformat!("{} — {}", "Документ", 1);
+/// let expected: Vec<i16> = format!("{} — {}\0", "Документ", 1).encode_utf16().map(|u| u as i16).collect();
+/// assert_eq!(unsafe { std::slice::from_raw_parts(title, expected.len()) }, &expected[..]);
+/// ```
+#[proc_macro]
+pub fn real_c_wformat(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as FormattedRealCString).0,
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Produces C string with UTC build time in RFC 3339 format, i.e `2024-01-31T23:59:59Z`
+///
+/// Timestamp breaks reproducible builds, so `SOURCE_DATE_EPOCH` environment variable, holding seconds since
+/// Unix epoch, is used as build time when it is set, as specified by reproducible-builds.org.
+/// Otherwise, time is read once per compilation, so every invocation in a crate gets the same value.
+/// Invoking crate is rebuilt when `SOURCE_DATE_EPOCH` changes, but not when time passes, so timestamp
+/// is the time when crate was compiled last.
+///
+/// Options may be passed, i.e `keep`. Timestamp always has 20 characters, so its size with terminator
+/// is returned by `real_c_build_timestamp_size!`.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use real_c_string::{real_c_build_timestamp, real_c_build_timestamp_size};
+/// let timestamp = unsafe { std::ffi::CStr::from_ptr(real_c_build_timestamp!()) };
+/// let timestamp = timestamp.to_str().unwrap();
+/// assert_eq!(timestamp.len() + 1, real_c_build_timestamp_size!());
+/// assert!(timestamp.starts_with("20") && timestamp.ends_with('Z'));
+/// assert_eq!(&timestamp[10..11], "T");
+///
+/// let mut buffer = [0i8; real_c_build_timestamp_size!()];
+/// unsafe { std::ptr::copy_nonoverlapping(real_c_build_timestamp!(), buffer.as_mut_ptr(), buffer.len()) };
+/// assert_eq!(buffer[20], 0);
+/// ```
+#[proc_macro]
+pub fn real_c_build_timestamp(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let pointer = transform(
+		&parse_macro_input!(input as TimestampRealCString).0,
+		TransformType::CString,
+	);
+	let tracking = env_tracking(timestamp::SOURCE_DATE_EPOCH);
+	quote! {
+		{
+			#tracking
+			#pointer
+		}
+	}
+	.into()
+}
+
+/// Returns size of string produced by `real_c_build_timestamp`, including terminator, for sizing buffers
+///
+/// The result of this macro invocation is a `usize` literal.
+///
+/// ```rust
+/// use real_c_string::real_c_build_timestamp_size;
+/// assert_eq!(real_c_build_timestamp_size!(), 21);
+/// ```
+#[proc_macro]
+pub fn real_c_build_timestamp_size(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	if !input.is_empty() {
+		return syn::Error::new(proc_macro2::Span::call_site(), "expected no arguments")
+			.to_compile_error()
+			.into();
+	}
+	let size = timestamp::LEN + 1;
+	quote! {#size}.into()
+}
+
+/// Produces C string with path of file, in which it is invoked, same as `file!()` and `__FILE__` in C
+///
+/// Path is known to proc macro through span of invocation, so it is checked and encoded as every other
+/// string, and options may be passed, i.e `utf8` for paths with non-ASCII characters. For invocations
+/// inside of `macro_rules!` macro, it is the file where the outermost macro is invoked, same as for `file!()`.
+///
+/// The result of this macro invocation is of type `*const i8`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_file;
+/// use std::ffi::CStr;
+/// let file = unsafe { CStr::from_ptr(real_c_file!()) };
+/// assert_eq!(file.to_str().unwrap(), file!());
+/// ```
+#[proc_macro]
+pub fn real_c_file(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input with LocationRealCString::parse_file);
+	transform(&input.0, TransformType::CString).into()
+}
+
+/// Same as `real_c_file`, but line of invocation is appended after colon, i.e `src/main.rs:42`
+///
+/// The result of this macro invocation is of type `*const i8`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_location;
+/// use std::ffi::CStr;
+/// let (location, line) = (real_c_location!(), line!());
+/// let location = unsafe { CStr::from_ptr(location) };
+/// assert_eq!(location.to_str().unwrap(), format!("{}:{}", file!(), line));
+/// ```
+#[proc_macro]
+pub fn real_c_location(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input with LocationRealCString::parse_location);
+	transform(&input.0, TransformType::CString).into()
+}
+
+/// Produces C string with path of module, in which it is invoked, same as `module_path!()`, i.e `app::net`
+///
+/// Module path isn't known to proc macro, so `module_path!()` is concatenated with terminator by `concat!`
+/// at call site. It can't contain NUL, but it isn't checked and encoded by macro, so options are not accepted,
+/// and non-ASCII identifiers are stored as UTF-8.
+///
+/// The result of this macro invocation is of type `*const i8`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_module_path;
+/// use std::ffi::CStr;
+/// mod net {
+///     pub fn module() -> *const i8 {
+///         real_c_string::real_c_module_path!()
+///     }
+/// }
+/// let module = unsafe { CStr::from_ptr(net::module()) };
+/// assert_eq!(module.to_str().unwrap(), format!("{}::net", module_path!()));
+/// ```
+#[proc_macro]
+pub fn real_c_module_path(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	if !input.is_empty() {
+		return syn::Error::new(proc_macro2::Span::call_site(), "expected no arguments")
+			.to_compile_error()
+			.into();
+	}
+	quote! {
+		const { ::core::concat!(::core::module_path!(), "\0").as_ptr() as *const i8 }
+	}
+	.into()
+}
+
+/// Same as `real_c_format`, but template may also refer to build time as `{timestamp}`, i.e for build banners
+///
+/// Build time is the same as returned by `real_c_build_timestamp`, including use of `SOURCE_DATE_EPOCH`.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use real_c_string::{real_c_build_info, real_c_build_timestamp};
+/// use std::ffi::CStr;
+/// let banner = real_c_build_info!("{} {} built {timestamp}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+/// let timestamp = unsafe { CStr::from_ptr(real_c_build_timestamp!()) }.to_str().unwrap();
+/// assert_eq!(
+///     unsafe { CStr::from_ptr(banner) }.to_str().unwrap(),
+///     format!("real_c_string 1.0.1 built {}", timestamp)
+/// );
+/// ```
+#[proc_macro]
+pub fn real_c_build_info(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let pointer = transform(
+		&parse_macro_input!(input as BuildInfoRealCString).0,
+		TransformType::CString,
+	);
+	let tracking = env_tracking(timestamp::SOURCE_DATE_EPOCH);
+	quote! {
+		{
+			#tracking
+			#pointer
+		}
+	}
+	.into()
+}
+
+/// Produces random alphanumeric C string of passed length, unique to every build, i.e for watermarking builds
+///
+/// **Without seed, output differs between builds by design**, so builds are not reproducible.
+/// To make it deterministic, set `REAL_C_STRING_NONCE_SEED` environment variable: with it, string is
+/// derived from seed and location of invocation, so different invocations still get different strings,
+/// and the same source always produces the same binary. Invoking crate is rebuilt when seed changes.
+/// Without seed, the same invocation gets the same string during one compilation.
+///
+/// Options may be passed before length, i.e `keep`, so marker is kept by linker even if it is unused.
+/// Size of string, including terminator, is returned by `real_c_nonce_string_size!`.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use real_c_string::{real_c_nonce_string, real_c_nonce_string_size};
+/// let nonce = unsafe { std::ffi::CStr::from_ptr(real_c_nonce_string!(16)) };
+/// assert_eq!(nonce.to_bytes().len(), 16);
+/// assert!(nonce.to_bytes().iter().all(u8::is_ascii_alphanumeric));
+/// let other = unsafe { std::ffi::CStr::from_ptr(real_c_nonce_string!(16)) };
+/// assert_ne!(nonce, other);
+///
+/// fn watermark() -> *const i8 {
+///     real_c_nonce_string!(keep 8)
+/// }
+/// assert_eq!(watermark(), watermark());
+/// assert_eq!(real_c_nonce_string_size!(keep 8), 9);
+///
+/// // Options changing text are applied to nonce too
+/// let nonce = unsafe { std::ffi::CStr::from_ptr(real_c_nonce_string!(upper 32)) }.to_str().unwrap();
+/// assert!(nonce.bytes().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()));
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_nonce_string;
+/// // nonce length should be from 1 to 4096
+/// let nonce = real_c_nonce_string!(0);
+/// ```
+#[proc_macro]
+pub fn real_c_nonce_string(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let string = match parse_macro_input!(input as NonceRealCString).string(&call_site_location()) {
+		Ok(string) => string,
+		Err(errors) => return errors.to_compile_error().into(),
+	};
+	let pointer = transform(&string, TransformType::CString);
+	let tracking = env_tracking(nonce::SEED_VAR);
+	quote! {
+		{
+			#tracking
+			#pointer
+		}
+	}
+	.into()
+}
+
+/// Returns size of string produced by `real_c_nonce_string` with the same arguments, including terminator
+///
+/// The result of this macro invocation is a `usize` literal.
+///
+/// ```rust
+/// use real_c_string::real_c_nonce_string_size;
+/// const SIZE: usize = real_c_nonce_string_size!(16);
+/// assert_eq!(SIZE, 17);
+/// assert_eq!(real_c_nonce_string_size!(no_nul 16), 16);
+/// ```
+#[proc_macro]
+pub fn real_c_nonce_string_size(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	// Characters are alphanumeric, so every one of them is stored as a single byte
+	let string = match parse_macro_input!(input as NonceRealCString).string(&call_site_location()) {
+		Ok(string) => string,
+		Err(errors) => return errors.to_compile_error().into(),
+	};
+	let size = string.text().len() + transform_terminator(&string, TransformType::CString).len();
+	quote! {#size}.into()
+}
+
+/// Produces C string selected by `cfg` predicates, i.e library names, which differ between platforms
+///
+/// Arms are `predicate => string`, predicates are the same as in `#[cfg(...)]`, and are emitted into
+/// `#[cfg]` attributes as is, so only the selected string is compiled. The first arm with true predicate
+/// is selected, and the last arm should be `_ => string`, which is selected if no predicate is true.
+/// Strings are the same as accepted by `real_c_string`, including options.
+///
+/// The result of this macro invocation is of type `*const i8`, or `*const i16` if `wide,` is passed before arms.
+/// It is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_string_cfg;
+/// use std::ffi::CStr;
+/// let plugin = real_c_string_cfg!(
+///     windows => "plugin.dll",
+///     target_os = "macos" => "libplugin.dylib",
+///     _ => "lib" "plugin.so",
+/// );
+/// let expected = if cfg!(windows) {
+///     "plugin.dll"
+/// } else if cfg!(target_os = "macos") {
+///     "libplugin.dylib"
+/// } else {
+///     "libplugin.so"
+/// };
+/// assert_eq!(unsafe { CStr::from_ptr(plugin) }.to_str().unwrap(), expected);
+///
+/// const SEPARATOR: *const i16 = real_c_string_cfg!(wide, any(windows, target_os = "uefi") => "\\", _ => "/");
+/// assert_eq!(unsafe { *SEPARATOR }, if cfg!(windows) { '\\' } else { '/' } as i16);
+///
+/// let endianness = real_c_string_cfg!(target_endian = "little" => upper "le", _ => upper "be");
+/// assert_eq!(unsafe { *endianness }, if cfg!(target_endian = "little") { 'L' } else { 'B' } as i8);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_cfg;
+/// // expected `_ => "string"` as the last arm, which is used if no predicate is true
+/// let plugin = real_c_string_cfg!(windows => "plugin.dll", unix => "libplugin.so");
+/// ```
+#[proc_macro]
+pub fn real_c_string_cfg(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_cfg(parse_macro_input!(input as CfgRealCString)).into()
+}
+
+/// Produces narrow or wide C string, selected by `real_c_ansi` cfg, same as `_T("...")` and `TCHAR` in C
+///
+/// Without `real_c_ansi`, string is wide, as if `UNICODE` is defined in C, and with it string is narrow.
+/// Both strings are emitted into `#[cfg]`-guarded items, so characters, which can't be stored in narrow
+/// string, only fail compilation when `real_c_ansi` is set. Cfg is checked in invoking crate, so it can be
+/// set by `RUSTFLAGS="--cfg real_c_ansi"`, or by build script of invoking crate, i.e for `unicode` feature:
+///
+/// ```toml
+/// [features]
+/// unicode = []
+///
+/// [lints.rust]
+/// unexpected_cfgs = { level = "warn", check-cfg = ["cfg(real_c_ansi)"] }
+/// ```
+///
+/// ```rust,ignore
+/// // build.rs
+/// fn main() {
+///     if std::env::var_os("CARGO_FEATURE_UNICODE").is_none() {
+///         println!("cargo:rustc-cfg=real_c_ansi");
+///     }
+/// }
+/// ```
+///
+/// The result of this macro invocation is of type `*const u16`, or `*const i8` with `real_c_ansi`.
+/// It is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_tstring;
+/// let title = real_c_tstring!("Window title");
+/// #[cfg(not(real_c_ansi))]
+/// assert_eq!(unsafe { *title.add(7) }, u16::from(b't'));
+/// #[cfg(real_c_ansi)]
+/// assert_eq!(unsafe { *title.add(7) }, b't' as i8);
+///
+/// // Cyrillic doesn't fit in narrow string, which is only compiled with `real_c_ansi`
+/// #[cfg(not(real_c_ansi))]
+/// let greeting = real_c_tstring!("Привет");
+///
+/// use real_c_string::real_c_sync_ptr;
+/// #[cfg(not(real_c_ansi))]
+/// real_c_sync_ptr!(struct TStrPtr(*const u16));
+/// #[cfg(real_c_ansi)]
+/// real_c_sync_ptr!(struct TStrPtr(*const i8));
+/// static TITLE: TStrPtr = real_c_tstring!(sync = TStrPtr, "Title");
+/// assert_eq!(unsafe { *TITLE.as_ptr() }, 'T' as _);
+/// ```
+#[proc_macro]
+pub fn real_c_tstring(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	let narrow = transform_pointer(&input, TransformType::CString);
+	let wide = transform_pointer(&input, TransformType::CWStringU16);
+	transform_sync(
+		&input,
+		quote! {
+			{
+				#[cfg(real_c_ansi)]
+				const STRING: *const i8 = #narrow;
+				#[cfg(not(real_c_ansi))]
+				const STRING: *const u16 = #wide;
+				STRING
+			}
+		},
+	)
+	.into()
+}
+
+/// Produces C string with integer formatted at compile time, i.e service argument of `getaddrinfo`
+///
+/// Integer is formatted in decimal, unless base is passed as `base = 16`, `8` or `2` before options,
+/// and `prefix` flag after base adds C prefix of base, `0x`, `0` or `0b`. Negative integers are
+/// formatted as sign followed by magnitude, i.e `-0xff`, and every integer from `i64::MIN` to
+/// `u64::MAX` is accepted. Options, i.e `upper` for uppercase hex digits, are passed before integer.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use real_c_string::real_c_itoa;
+/// use std::ffi::CStr;
+/// fn text(string: *const i8) -> &'static str {
+///     unsafe { CStr::from_ptr(string) }.to_str().unwrap()
+/// }
+/// assert_eq!(text(real_c_itoa!(8080)), "8080");
+/// assert_eq!(text(real_c_itoa!(0x1f90)), "8080");
+/// assert_eq!(text(real_c_itoa!(18446744073709551615)), u64::MAX.to_string());
+/// assert_eq!(text(real_c_itoa!(-9223372036854775808)), i64::MIN.to_string());
+/// assert_eq!(text(real_c_itoa!(-42)), "-42");
+/// assert_eq!(text(real_c_itoa!(base = 16, 0xc0de_beef)), "c0debeef");
+/// assert_eq!(text(real_c_itoa!(base = 16, prefix, upper 255)), "0XFF");
+/// assert_eq!(text(real_c_itoa!(base = 16, prefix -255)), "-0xff");
+/// assert_eq!(text(real_c_itoa!(base = 8, prefix 0o755)), "0755");
+/// assert_eq!(text(real_c_itoa!(base = 2, 5u8)), "101");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_itoa;
+/// // integer should fit into i64 or u64
+/// let port = real_c_itoa!(-9223372036854775809);
+/// ```
+#[proc_macro]
+pub fn real_c_itoa(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as ItoaRealCString).0,
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_itoa`, but used for wchar_t* strings
+///
+/// The result of this macro invocation is of type `*const i16`.
+///
+/// ```rust
+/// use real_c_string::{real_c_witoa, real_c_wstring_array};
+/// let port = real_c_witoa!(8080);
+/// assert_eq!(unsafe { *(port as *const [i16; 5]) }, real_c_wstring_array!("8080"));
+/// let code = real_c_witoa!(base = 16, prefix 0x8004_0005);
+/// assert_eq!(unsafe { *(code as *const [i16; 11]) }, real_c_wstring_array!("0x80040005"));
+/// ```
+#[proc_macro]
+pub fn real_c_witoa(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as ItoaRealCString).0,
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string`, but interprets C escape sequences in string, usually passed as raw string
+///
+/// Supported escapes are `\n`, `\t`, `\r`, `\a`, `\b`, `\f`, `\v`, `\\`, `\'`, `\"`, `\?`, octal `\NNN`,
+/// hex `\xNN`, and `\uXXXX`/`\UXXXXXXXX`. Escapes denote characters, so values above `0xff` are rejected
+/// the same way as unsupported characters are.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use real_c_string::real_c_string_escaped;
+/// let clear = real_c_string_escaped!(r"\x1b[2J\033[H");
+/// assert_eq!(*b"\x1b[2J\x1b[H\0", unsafe { *(clear as *const [u8; 8]) });
+///
+/// let line = real_c_string_escaped!(r#"say \"hi\"\r\n"#);
+/// assert_eq!(*b"say \"hi\"\r\n\0", unsafe { *(line as *const [u8; 11]) });
+///
+/// let latin = real_c_string_escaped!(r"caf\xe9 \u00e9");
+/// assert_eq!(*b"caf\xe9 \xe9\0", unsafe { *(latin as *const [u8; 7]) });
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_escaped;
+/// // Malformed escape sequence `\q` at offset 3
+/// let c_string = real_c_string_escaped!(r"abc\q");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_escaped;
+/// // Unsupported character 'ǿ' (U+01FF) at offset 0
+/// let c_string = real_c_string_escaped!(r"\777");
+/// ```
+#[proc_macro]
+pub fn real_c_string_escaped(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as EscapedRealCString).0,
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string_escaped`, but used for wchar_t* strings
+///
+/// `\uXXXX` escapes, which are not valid characters, i.e lone surrogates, are passed through as code units.
+///
+/// The result of this macro invocation is of type `*const i16`.
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_escaped;
+/// let party = real_c_wstring_escaped!(r"\ud83c\udf89 \u0416\t\x41");
+/// let expected: Vec<i16> = "🎉 Ж\tA\0".encode_utf16().map(|u| u as i16).collect();
+/// assert_eq!(expected[..], unsafe { *(party as *const [i16; 7]) });
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_escaped(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as EscapedRealCString).0,
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Produces double-NUL-terminated list of strings, as `REG_MULTI_SZ` values and `lpstrFilter` use
+///
+/// Every string is followed by NUL, and the whole list is terminated by another NUL. Empty strings
+/// are rejected, as consumers would treat them as end of list.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use real_c_string::real_c_multi_string;
+/// let filter = real_c_multi_string!("Text files", "*.txt", "All files", "*.*");
+/// assert_eq!(
+///     *b"Text files\0*.txt\0All files\0*.*\0\0",
+///     unsafe { *(filter as *const [u8; 32]) },
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_multi_string;
+/// // Empty string would terminate multi-string early
+/// let list = real_c_multi_string!("first", "", "third");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_multi_string;
+/// let list = real_c_multi_string!();
+/// ```
+#[proc_macro]
+pub fn real_c_multi_string(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as MultiRealCString).0,
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Produces C string of strings joined with separator, which is the first argument, i.e for search paths
+///
+/// Strings are joined at expansion time, they may be builtin macros, i.e `env!`, as for `real_c_string`.
+/// Without strings, result is empty string. `skip_empty` option omits empty strings, so separator isn't
+/// doubled, i.e for `option_env!` of unset variables. Other options are applied to the joined string.
+///
+/// The result of this macro invocation is of type `*const i8`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_string_join;
+/// use std::ffi::CStr;
+/// let text = |string| unsafe { CStr::from_ptr(string) }.to_str().unwrap();
+/// const PATH: *const i8 = real_c_string_join!(":", "/usr/lib", "/usr/local/lib");
+/// assert_eq!(text(PATH), "/usr/lib:/usr/local/lib");
+/// let ciphers = real_c_string_join!(",", "TLS_AES_128_GCM_SHA256", env!("CARGO_PKG_NAME"));
+/// assert_eq!(text(ciphers), "TLS_AES_128_GCM_SHA256,real_c_string");
+/// assert_eq!(text(real_c_string_join!(":")), "");
+///
+/// let path = real_c_string_join!(":", "/lib", option_env!("REAL_C_STRING_SURELY_UNSET_VARIABLE"), "/usr/lib");
+/// assert_eq!(text(path), "/lib::/usr/lib");
+/// let path = real_c_string_join!(skip_empty ":", "/lib", option_env!("REAL_C_STRING_SURELY_UNSET_VARIABLE"), "/usr/lib");
+/// assert_eq!(text(path), "/lib:/usr/lib");
+/// ```
+#[proc_macro]
+pub fn real_c_string_join(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as JoinedRealCString).0,
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string_join`, but used for wchar_t* strings
+///
+/// The result of this macro invocation is of type `*const i16`.
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_join;
+/// let list = real_c_wstring_join!(" · ", "Файл", "Правка");
+/// let expected: Vec<u16> = "Файл · Правка\0".encode_utf16().collect();
+/// assert_eq!(unsafe { std::slice::from_raw_parts(list as *const u16, expected.len()) }, expected);
+/// assert_eq!(unsafe { *real_c_wstring_join!(";") }, 0);
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_join(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as JoinedRealCString).0,
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Produces environment block for `lpEnvironment` of `CreateProcessW`, from `"NAME" => "value"` entries
+///
+/// Every entry is stored as `NAME=value` wide string, terminated by NUL, and the block is terminated by
+/// one more NUL. Entries are sorted by name case-insensitively, as Windows requires. Names should be
+/// non-empty and can't contain `=` or NUL, values can't contain NUL, and names can't be defined twice,
+/// even in different case. Options are applied to the whole block, except for `no_nul` and `terminators`.
+/// Pass `CREATE_UNICODE_ENVIRONMENT` flag to `CreateProcessW`, when using this block.
+///
+/// The result of this macro invocation is of type `*const u16`, it is usable in `const` context.
+/// Size of block in bytes is returned by `real_c_env_block_size!`.
+///
+/// ```rust
+/// use real_c_string::{real_c_env_block, real_c_env_block_size};
+/// const ENVIRONMENT: *const u16 = real_c_env_block!("PATH" => "C:\\app", "MY_FLAG" => "1", "Lang" => "ru");
+/// const SIZE: usize = real_c_env_block_size!("PATH" => "C:\\app", "MY_FLAG" => "1", "Lang" => "ru");
+/// let block = unsafe { std::slice::from_raw_parts(ENVIRONMENT, SIZE / 2) };
+/// let expected: Vec<u16> = "Lang=ru\0MY_FLAG=1\0PATH=C:\\app\0\0".encode_utf16().collect();
+/// assert_eq!(block, expected);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_env_block;
+/// // Environment variable `path` is defined twice, names are case-insensitive
+/// let environment = real_c_env_block!("PATH" => "C:\\app", "path" => "C:\\other");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_env_block;
+/// // Environment variable name can't contain '='
+/// let environment = real_c_env_block!("A=B" => "C");
+/// ```
+#[proc_macro]
+pub fn real_c_env_block(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as EnvBlock).0,
+		TransformType::CWStringU16,
+	)
+	.into()
+}
+
+/// Returns size in bytes of environment block, produced by `real_c_env_block` with the same arguments
+///
+/// The result of this macro invocation is a `usize` literal.
+///
+/// ```rust
+/// use real_c_string::real_c_env_block_size;
+/// // "A=1\0\0"
+/// assert_eq!(real_c_env_block_size!("A" => "1"), 10);
+/// ```
+#[proc_macro]
+pub fn real_c_env_block_size(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as EnvBlock).0;
+	transform_checked_value(&input, TransformType::CWStringU16, |len| (len + 1) * 2).into()
+}
+
+/// Same as `real_c_multi_string`, but used for wchar_t* strings
+///
+/// The result of this macro invocation is of type `*const i16`.
+///
+/// ```rust
+/// use real_c_string::real_c_multi_wstring;
+/// #[repr(C)]
+/// struct OpenFileName {
+///     // Other fields of OPENFILENAMEW are omitted
+///     lpstr_filter: *const u16,
+/// }
+/// let dialog = OpenFileName {
+///     lpstr_filter: real_c_multi_wstring!("Text files", "*.txt", "All files", "*.*") as *const u16,
+/// };
+/// let expected: Vec<u16> = "Text files\0*.txt\0All files\0*.*\0\0".encode_utf16().collect();
+/// for (i, unit) in expected.iter().enumerate() {
+///     assert_eq!(*unit, unsafe { *dialog.lpstr_filter.add(i) });
+/// }
+/// ```
+#[proc_macro]
+pub fn real_c_multi_wstring(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as MultiRealCString).0,
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Produces null-terminated array of pointers to C strings, as `argv` of `execvp` and `posix_spawn`
+///
+/// Options are applied to every string, and the last element of array is a null pointer.
+///
+/// The result of this macro invocation is of type `*const *const i8`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_string_list;
+/// const ARGV: *const *const i8 = real_c_string_list!("ls", "-la", "/tmp");
+/// let mut args = Vec::new();
+/// let mut arg = ARGV;
+/// while !unsafe { *arg }.is_null() {
+///     let string = unsafe { std::ffi::CStr::from_ptr(*arg) };
+///     args.push(string.to_str().unwrap());
+///     arg = unsafe { arg.add(1) };
+/// }
+/// assert_eq!(args, ["ls", "-la", "/tmp"]);
+/// ```
+#[proc_macro]
+pub fn real_c_string_list(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_list(
+		parse_macro_input!(input as RealCStringList),
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string_list`, but used for wchar_t* strings
+///
+/// The result of this macro invocation is of type `*const *const i16`.
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_list;
+/// let argv = real_c_wstring_list!("cmd.exe", "/c", "Привет");
+/// let mut args = Vec::new();
+/// for i in 0.. {
+///     let mut arg = unsafe { *argv.add(i) };
+///     if arg.is_null() {
+///         break;
+///     }
+///     let mut units = Vec::new();
+///     while unsafe { *arg } != 0 {
+///         units.push(unsafe { *arg } as u16);
+///         arg = unsafe { arg.add(1) };
+///     }
+///     args.push(String::from_utf16(&units).unwrap());
+/// }
+/// assert_eq!(args, ["cmd.exe", "/c", "Привет"]);
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_list(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_list(
+		parse_macro_input!(input as RealCStringList),
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Produces parallel arrays of pointers to C strings and their lengths, and their count, from one list of strings
+///
+/// Options are applied to every string. Lengths are in encoded code units, excluding terminator,
+/// so they match what `real_c_string_ptr_len!` returns for every string.
+///
+/// The result of this macro invocation is of type `(&'static [*const i8; N], &'static [usize; N], usize)`,
+/// it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_string_table;
+/// extern "C" fn total_len(names: *const *const i8, name_lens: *const usize, count: usize) -> usize {
+///     let names = unsafe { std::slice::from_raw_parts(names, count) };
+///     let name_lens = unsafe { std::slice::from_raw_parts(name_lens, count) };
+///     names.iter().zip(name_lens).map(|(&name, &len)| {
+///         let name = unsafe { std::slice::from_raw_parts(name as *const u8, len) };
+///         assert!(name.iter().all(|&c| c != 0));
+///         name.len()
+///     }).sum()
+/// }
+/// const NAMES: (&[*const i8; 3], &[usize; 3], usize) =
+///     real_c_string_table!("alpha", "beta", "gamma");
+/// let (names, name_lens, count) = NAMES;
+/// assert_eq!(name_lens, &[5, 4, 5]);
+/// assert_eq!(total_len(names.as_ptr(), name_lens.as_ptr(), count), 14);
+///
+/// let (_, lens, _) = real_c_string_table!(encoding = "windows-1251" "Привет", "€");
+/// assert_eq!(lens, &[6, 1]);
+/// ```
+#[proc_macro]
+pub fn real_c_string_table(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_table(
+		parse_macro_input!(input as RealCStringList),
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string_table`, but used for wchar_t* strings, lengths are in UTF-16 code units
+///
+/// The result of this macro invocation is of type `(&'static [*const i16; N], &'static [usize; N], usize)`.
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_table;
+/// let (names, lens, count) = real_c_wstring_table!("Привет", "🎉", "");
+/// assert_eq!(lens, &[6, 2, 0]);
+/// assert_eq!(count, 3);
+/// let party = unsafe { std::slice::from_raw_parts(names[1] as *const u16, lens[1]) };
+/// assert_eq!(String::from_utf16(party).unwrap(), "🎉");
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_table(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_table(
+		parse_macro_input!(input as RealCStringList),
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Produces array of pointers to C strings, one per line of passed string, i.e for menus of C UI libraries
+///
+/// String is split on `\n` and `\r\n`, as `str::lines` does, after options are applied, so `dedent`
+/// is applied to the whole string first. Line endings are not included in lines, and the last line
+/// is omitted if it is empty. `trim_end` option removes trailing whitespace from every line,
+/// and `skip_empty` omits empty lines.
+///
+/// The result of this macro invocation is of type `&'static [*const i8; N]`, where `N` is the number of lines,
+/// which is returned by `real_c_lines_len!`. It is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::{real_c_lines, real_c_lines_len};
+/// use std::ffi::CStr;
+/// const MENU: &[*const i8] = real_c_lines!(dedent trim_end "
+///     New game   
+///     Load game
+///
+///     Quit
+/// ");
+/// let lines: Vec<_> = MENU
+///     .iter()
+///     .map(|&line| unsafe { CStr::from_ptr(line) }.to_str().unwrap())
+///     .collect();
+/// assert_eq!(lines, ["New game", "Load game", "", "Quit"]);
+///
+/// let help: &[*const i8; 2] = real_c_lines!(skip_empty "Usage: app [FILE]\r\n\r\n  -h  Help\r\n");
+/// assert_eq!(unsafe { CStr::from_ptr(help[1]) }.to_bytes(), b"  -h  Help");
+/// const HELP_LINES: usize = real_c_lines_len!(skip_empty "Usage: app [FILE]\r\n\r\n  -h  Help\r\n");
+/// assert_eq!(HELP_LINES, 2);
+/// assert_eq!(real_c_lines!("").len(), 0);
+/// ```
+#[proc_macro]
+pub fn real_c_lines(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_lines(
+		parse_macro_input!(input as RealCString),
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Returns number of lines in passed string, which is the length of array returned by `real_c_lines!`
+///
+/// The result of this macro invocation is a `usize` literal.
+///
+/// ```rust
+/// use real_c_string::real_c_lines_len;
+/// assert_eq!(real_c_lines_len!("first\nsecond\n"), 2);
+/// assert_eq!(real_c_lines_len!("first\n\nthird"), 3);
+/// assert_eq!(real_c_lines_len!(skip_empty "first\n\nthird"), 2);
+/// ```
+#[proc_macro]
+pub fn real_c_lines_len(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let lines = parse_macro_input!(input as RealCString).into_lines();
+	let errors = lines
+		.iter()
+		.filter_map(|line| transform_units(line, TransformType::CString).err())
+		.reduce(|mut errors, error| {
+			errors.combine(error);
+			errors
+		});
+	match errors {
+		Some(errors) => error_expression(errors),
+		None => {
+			let len = lines.len();
+			quote! {#len}
+		}
+	}
+	.into()
+}
+
+/// Produces `BSTR` for COM interop, UTF-16 string preceded by its length in bytes
+///
+/// Buffer holds little-endian `u32` length in bytes, not including terminator, followed by code units
+/// and terminator, and the result points at the first code unit, as COM consumers expect.
+/// **It is not allocated by `SysAllocString`, and should never be passed to `SysFreeString`**,
+/// or to any API taking ownership of `BSTR`.
+///
+/// The result of this macro invocation is of type `*const u16`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_bstr;
+/// let bstr = real_c_bstr!("hello");
+/// let prefix = unsafe { *(bstr as *const u32).sub(1) };
+/// assert_eq!(u32::from_le(prefix), 10);
+/// let expected: Vec<u16> = "hello\0".encode_utf16().collect();
+/// assert_eq!(expected[..], unsafe { *(bstr as *const [u16; 6]) });
+/// ```
+#[proc_macro]
+pub fn real_c_bstr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_bstr(parse_macro_input!(input as RealCString)).into()
+}
+
+/// Produces Pascal string, which is a length byte followed by up to 255 bytes, without terminator
+///
+/// `with_nul` option appends NUL after string, for APIs which expect both Pascal and C string,
+/// it is not counted by the length byte.
+///
+/// The result of this macro invocation is of type `*const u8`.
+///
+/// ```rust
+/// use real_c_string::real_c_pstring;
+/// let name = real_c_pstring!("Volume Name");
+/// assert_eq!(unsafe { *name }, 11);
+/// assert_eq!(*b"\x0bVolume Name", unsafe { *(name as *const [u8; 12]) });
+///
+/// let hybrid = real_c_pstring!(with_nul "abc");
+/// assert_eq!(*b"\x03abc\0", unsafe { *(hybrid as *const [u8; 5]) });
+/// let framed = real_c_pstring!(terminator = 0xff, with_nul "abc");
+/// assert_eq!(*b"\x03abc\xff", unsafe { *(framed as *const [u8; 5]) });
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_pstring;
+/// // String of size 256 doesn't fit into Pascal string, max is 255
+/// let name = real_c_pstring!(utf8 "ЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖЖ");
+/// ```
+#[proc_macro]
+pub fn real_c_pstring(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_pstring(parse_macro_input!(input as RealCString)).into()
+}
+
+/// Same as `real_c_string_array`, but pads array with zeroes to passed size, as `char name[N]` fields expect
+///
+/// Compilation fails if string with terminator doesn't fit in passed size.
+///
+/// The result of this macro invocation is of type `[i8; N]`, where `N` is passed size.
+///
+/// ```rust
+/// use real_c_string::real_c_string_padded;
+/// #[repr(C)]
+/// struct UtsName {
+///     sysname: [i8; 65],
+///     nodename: [i8; 65],
+/// }
+/// let name = UtsName {
+///     sysname: real_c_string_padded!(65, "Linux"),
+///     nodename: real_c_string_padded!(65, "my-hostname"),
+/// };
+/// assert_eq!(name.sysname[..6], [76i8, 105i8, 110i8, 117i8, 120i8, 0i8]);
+/// assert!(name.sysname[5..].iter().all(|&c| c == 0));
+/// assert_eq!(name.nodename[11], 0);
+///
+/// // Custom terminator is written before padding
+/// let framed = real_c_string_padded!(6, terminator = 0xff, "abc");
+/// assert_eq!(framed, [97i8, 98i8, 99i8, -1i8, 0i8, 0i8]);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_padded;
+/// // String of size 12 doesn't fit into buffer of size 11
+/// let name: [i8; 11] = real_c_string_padded!(11, "my-hostname");
+/// ```
+#[proc_macro]
+pub fn real_c_string_padded(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as PaddedRealCString).0;
+	transform_padded(&input.string, input.size, TransformType::CString).into()
+}
+
+/// Same as `real_c_string_padded`, but produces unsigned bytes
+///
+/// The result of this macro invocation is of type `[u8; N]`, where `N` is passed size.
+///
+/// ```rust
+/// use real_c_string::real_c_string_padded_u8;
+/// // Magic field of old tar headers
+/// let magic: [u8; 8] = real_c_string_padded_u8!(8, no_nul "ustar  ");
+/// assert_eq!(magic, *b"ustar  \0");
+///
+/// let framed = real_c_string_padded_u8!(6, terminator = 0xff, terminators = 2, "abc");
+/// assert_eq!(framed, [97, 98, 99, 0xff, 0xff, 0]);
+/// ```
+#[proc_macro]
+pub fn real_c_string_padded_u8(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as PaddedRealCString).0;
+	transform_padded(&input.string, input.size, TransformType::CStringU8).into()
+}
+
+/// Same as `real_c_string_padded`, but used for `WCHAR name[N]` fields
+///
+/// The result of this macro invocation is of type `[u16; N]`, where `N` is passed size.
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_padded;
+/// #[repr(C)]
+/// struct LogFont {
+///     height: i32,
+///     face_name: [u16; 32],
+/// }
+/// let font = LogFont {
+///     height: 12,
+///     face_name: real_c_wstring_padded!(32, "Консолас"),
+/// };
+/// let expected: Vec<u16> = "Консолас".encode_utf16().collect();
+/// assert_eq!(font.face_name[..8], expected[..]);
+/// assert!(font.face_name[8..].iter().all(|&c| c == 0));
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_padded(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as PaddedRealCString).0;
+	transform_padded(&input.string, input.size, TransformType::CWStringU16).into()
+}
+
+/// Same as `real_c_string_padded`, but produces table of strings, as `char labels[N][M]` fields expect
+///
+/// Size of row is passed first, and every string is padded with zeroes to it. Count of rows is the count
+/// of strings, unless it is passed before size, then missing rows are zeroed. Options are passed before
+/// strings, and are shared by them. Compilation fails if any string with terminator doesn't fit in its row,
+/// or if there are more strings than rows.
+///
+/// The result of this macro invocation is of type `[[i8; M]; N]`, where `M` is passed size of row.
+///
+/// ```rust
+/// use real_c_string::real_c_string_matrix;
+/// #[repr(C)]
+/// struct Compass {
+///     count: u32,
+///     labels: [[i8; 16]; 4],
+/// }
+/// let compass = Compass {
+///     count: 4,
+///     labels: real_c_string_matrix!(16, "north", "south", "east", "west"),
+/// };
+/// assert_eq!(compass.labels[2][..5], [101i8, 97i8, 115i8, 116i8, 0i8]);
+/// assert!(compass.labels[3][4..].iter().all(|&c| c == 0));
+///
+/// let menu: [[i8; 8]; 3] = real_c_string_matrix!(3, 8, upper "open", "save");
+/// assert_eq!(menu[1][..4], [83i8, 65i8, 86i8, 69i8]);
+/// assert_eq!(menu[2], [0; 8]);
+///
+/// let framed: [[i8; 6]; 2] = real_c_string_matrix!(6, terminator = 0xff, "ab", "cde");
+/// assert_eq!(framed, [[97, 98, -1, 0, 0, 0], [99, 100, 101, -1, 0, 0]]);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_matrix;
+/// // String of size 6 at row 1 doesn't fit into row of size 5
+/// let labels = real_c_string_matrix!(5, "east", "south");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_matrix;
+/// // 3 strings don't fit into matrix of 2 rows
+/// let labels = real_c_string_matrix!(2, 8, "a", "b", "c");
+/// ```
+#[proc_macro]
+pub fn real_c_string_matrix(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_matrix(
+		parse_macro_input!(input as MatrixRealCString),
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string_matrix`, but used for `WCHAR labels[N][M]` fields
+///
+/// The result of this macro invocation is of type `[[u16; M]; N]`, where `M` is passed size of row.
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_matrix;
+/// #[repr(C)]
+/// struct Columns {
+///     names: [[u16; 8]; 2],
+/// }
+/// let columns = Columns {
+///     names: real_c_wstring_matrix!(8, "Имя", "Размер"),
+/// };
+/// let expected: Vec<u16> = "Размер\0\0".encode_utf16().collect();
+/// assert_eq!(columns.names[1][..], expected[..]);
+/// assert_eq!(columns.names[0][3..], [0; 5]);
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_matrix(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_matrix(
+		parse_macro_input!(input as MatrixRealCString),
+		TransformType::CWStringU16,
+	)
+	.into()
+}
+
+/// Produces fields of `UNICODE_STRING` for NT native APIs, as `(Length, MaximumLength, Buffer)` tuple
+///
+/// `Length` is size of string in bytes, not including terminator, and `MaximumLength` includes it.
+/// Compilation fails if size in bytes doesn't fit in `u16`.
+///
+/// The result of this macro invocation is of type `(u16, u16, *const u16)`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_unicode_string;
+/// #[repr(C)]
+/// struct UnicodeString {
+///     length: u16,
+///     maximum_length: u16,
+///     buffer: *const u16,
+/// }
+/// let (length, maximum_length, buffer) = real_c_unicode_string!("\\??\\C:\\Windows\\Привет");
+/// let path = UnicodeString { length, maximum_length, buffer };
+/// assert_eq!(path.length, 42);
+/// assert_eq!(path.maximum_length, 44);
+/// let expected: Vec<u16> = "\\??\\C:\\Windows\\Привет\0".encode_utf16().collect();
+/// assert_eq!(expected[..], unsafe { *(path.buffer as *const [u16; 22]) });
+/// ```
+#[proc_macro]
+pub fn real_c_unicode_string(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_unicode_string(parse_macro_input!(input as RealCString)).into()
+}
+
+/// Same as `real_c_string`, but repeats string passed number of times
+///
+/// Count should be an integer literal, zero produces empty string, and repeated strings longer
+/// than 4 MiB characters are rejected.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use real_c_string::real_c_string_repeat;
+/// let separator = real_c_string_repeat!("-", 79);
+/// let expected = [b'-' as i8; 79];
+/// assert_eq!(expected, unsafe { *(separator as *const [i8; 79]) });
+/// assert_eq!(0, unsafe { *separator.add(79) });
+///
+/// let pattern = real_c_string_repeat!("ab", 3);
+/// assert_eq!(*b"ababab\0", unsafe { *(pattern as *const [u8; 7]) });
+///
+/// let empty = real_c_string_repeat!("ab", 0);
+/// assert_eq!(0, unsafe { *empty });
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_repeat;
+/// // Repeated string exceeds limit of 4194304 characters
+/// let typo = real_c_string_repeat!("-", 7900000000);
+/// ```
+#[proc_macro]
+pub fn real_c_string_repeat(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as RepeatedRealCString).0,
+		TransformType::CString,
+	)
+	.into()
+}
+
+/// Same as `real_c_string_repeat`, but used for wchar_t* strings
+///
+/// The result of this macro invocation is of type `*const i16`.
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_repeat;
+/// let line = real_c_wstring_repeat!("═", 3);
+/// assert_eq!([0x2550, 0x2550, 0x2550, 0], unsafe { *(line as *const [i16; 4]) });
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_repeat(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform(
+		&parse_macro_input!(input as RepeatedRealCString).0,
+		TransformType::CWString,
+	)
+	.into()
+}
+
+/// Produces both narrow and wide strings from the same string, for APIs with `A` and `W` variants
+///
+/// Characters, which only fit into wide string, are reported as unsupported by narrow half.
+///
+/// The result of this macro invocation is of type `(*const i8, *const i16)`.
+///
+/// ```rust
+/// use real_c_string::real_c_string_pair;
+/// let (narrow, wide) = real_c_string_pair!("EventSource");
+/// let narrow = unsafe { std::ffi::CStr::from_ptr(narrow) }.to_str().unwrap();
+/// let wide = String::from_utf16(&unsafe { *(wide as *const [u16; 11]) }).unwrap();
+/// assert_eq!(narrow, wide);
+///
+/// let (narrow, wide) = real_c_string_pair!(utf8 "Источник");
+/// let narrow = unsafe { std::ffi::CStr::from_ptr(narrow) }.to_str().unwrap();
+/// let wide = String::from_utf16(&unsafe { *(wide as *const [u16; 8]) }).unwrap();
+/// assert_eq!(narrow, wide);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_pair;
+/// // Character "И" at offset 0 is only supported by wide half of pair, pass `utf8` to encode narrow half as UTF-8
+/// let (narrow, wide) = real_c_string_pair!("Источник");
+/// ```
+#[proc_macro]
+pub fn real_c_string_pair(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_pair(parse_macro_input!(input as RealCString)).into()
+}
+
+/// Produces `const` items holding strings, same as `real_c_string` or `real_c_wstring` does
+///
+/// Every entry is `NAME = "string";`, optionally preceded by attributes and visibility, and with its
+/// own options, `;` after the last entry is optional. Options applied to every entry are passed as `#![...]` at the beginning of table,
+/// `wide` makes every item `*const i16`, same as `real_c_wstring`, and should be the first one.
+///
+/// Items are of type `*const i8`, or `*const i16` with `wide`.
+///
+/// ```rust
+/// mod strings {
+///     real_c_string::real_c_strings! {
+///         /// Name, shown in window titles
+///         pub APP_NAME = "MyApp";
+///         pub REG_KEY = "Software\\MyApp";
+///         pub(crate) VERSION = concat!("v", "1.0");
+///         WINDOW_CLASS = no_nul "MyAppMainWnd";
+///     }
+///
+///     pub mod wide {
+///         real_c_string::real_c_strings! {
+///             #![wide, upper]
+///             pub APP_NAME = "MyApp";
+///             pub GREETING = "Привет";
+///         }
+///     }
+/// }
+/// let name = unsafe { std::ffi::CStr::from_ptr(strings::APP_NAME) };
+/// assert_eq!(name.to_str().unwrap(), "MyApp");
+/// let key = unsafe { std::ffi::CStr::from_ptr(strings::REG_KEY) };
+/// assert_eq!(key.to_str().unwrap(), "Software\\MyApp");
+/// assert_eq!(strings::VERSION.is_null(), false);
+///
+/// let expected: Vec<i16> = "MYAPP\0".encode_utf16().map(|u| u as i16).collect();
+/// assert_eq!(expected[..], unsafe { *(strings::wide::APP_NAME as *const [i16; 6]) });
+/// ```
+///
+/// Items without visibility are private to module, where table is:
+///
+/// ```compile_fail
+/// mod strings {
+///     real_c_string::real_c_strings! {
+///         WINDOW_CLASS = "MyAppMainWnd";
+///     }
+/// }
+/// let class = strings::WINDOW_CLASS;
+/// ```
+#[proc_macro]
+pub fn real_c_strings(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_strings(parse_macro_input!(input as RealCStringTable)).into()
+}
+
+/// Produces `static` arrays holding strings, same as `real_c_string_array` or `real_c_wstring_array` does
+///
+/// Accepts the same entries as `real_c_strings`, but arrays are `Sync`, unlike pointers, so they can
+/// be put in statics, and `.as_ptr()` gives pointer with `'static` lifetime and a stable address.
+///
+/// Items are of type `[i8; N]`, or `[i16; N]` with `wide`, where `N` includes terminating zero.
+///
+/// ```rust
+/// real_c_string::real_c_static!(pub GREETING = "hi");
+/// real_c_string::real_c_static! {
+///     #![wide]
+///     pub(crate) WIDE_GREETING = "привет";
+///     CLASS = "Window";
+/// }
+/// extern "C" fn ffi(string: *const i8) -> usize {
+///     unsafe { std::ffi::CStr::from_ptr(string) }.to_bytes().len()
+/// }
+/// let threads: Vec<_> = (0..4)
+///     .map(|_| std::thread::spawn(|| ffi(GREETING.as_ptr())))
+///     .collect();
+/// for thread in threads {
+///     assert_eq!(thread.join().unwrap(), 2);
+/// }
+/// assert_eq!(WIDE_GREETING.len(), 7);
+/// assert_eq!(CLASS[6], 0);
+/// ```
+///
+/// Options `symbol` and `section` are applied to statics:
+///
+/// ```rust
+/// #[cfg(not(target_vendor = "apple"))]
+/// real_c_string::real_c_static!(LICENSE = section = ".vmp1", symbol = "vmp_license", "MIT");
+/// #[cfg(target_vendor = "apple")]
+/// real_c_string::real_c_static!(LICENSE = section = "__DATA,__vmp1", symbol = "vmp_license", "MIT");
+/// extern "C" {
+///     static vmp_license: [i8; 4];
+/// }
+/// assert_eq!(unsafe { vmp_license.as_ptr() }, LICENSE.as_ptr());
+/// ```
+#[proc_macro]
+pub fn real_c_static(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_statics(parse_macro_input!(input as RealCStringTable)).into()
+}
+
+/// Produces exported functions returning strings, i.e for C consumers calling `const char* app_version(void)`
+///
+/// Accessor functions are easier to import across DLL boundaries than data symbols. Every getter is declared
+/// as `fn name() -> Type = "string"`, with options before string, and getters are separated by `;`. Getters may
+/// have attributes and visibility, and are `extern "C"` when ABI is not specified. They are `#[no_mangle]`,
+/// so name should be a C identifier, unless it is overridden by `#[export_name = "name"]`. Encoding of string
+/// is selected by return type, same as by named macros: `*const i8`, `*const u8`, `*const c_char`, `*const i16`,
+/// `*const u16` for wide strings, or `*const u32`. Attribute is emitted as `#[unsafe(no_mangle)]`, which is
+/// accepted by every edition.
+///
+/// ```rust
+/// use real_c_string::real_c_string_fn;
+/// real_c_string_fn! {
+///     /// Returns version, shown in about dialog
+///     pub extern "C" fn real_c_string_doc_version() -> *const i8 = "1.4.2";
+///     pub fn real_c_string_doc_name() -> *const u16 = upper "app";
+///     #[export_name = "real_c_string_doc_vendor"]
+///     pub fn vendor() -> *const core::ffi::c_char = "Example Inc.";
+/// }
+/// let version = unsafe { std::ffi::CStr::from_ptr(real_c_string_doc_version()) };
+/// assert_eq!(version.to_str().unwrap(), "1.4.2");
+/// assert_eq!(unsafe { *(real_c_string_doc_name() as *const [u16; 4]) }, [65, 80, 80, 0]);
+/// assert_eq!(unsafe { *vendor() }, b'E' as core::ffi::c_char);
+/// ```
+///
+/// ```compile_fail
+/// // `версия` is not a valid C identifier, use `#[export_name = "name"]` to export it with another name
+/// real_c_string::real_c_string_fn!(pub fn версия() -> *const i8 = "1.4.2");
+/// ```
+///
+/// ```compile_fail
+/// // expected `*const i8`, `*const u8`, `*const c_char`, `*const i16`, `*const u16` or `*const u32`
+/// real_c_string::real_c_string_fn!(pub fn app_version() -> &'static str = "1.4.2");
+/// ```
+#[proc_macro]
+pub fn real_c_string_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_fns(parse_macro_input!(input as RealCStringFns)).into()
+}
+
+/// Declares `Sync` wrapper of pointer to string, so strings can be stored in statics, i.e in tables for C callbacks
+///
+/// Proc macro crates can't export types, so wrapper is declared in invoking crate, and is produced by macros
+/// returning pointers with `sync = Type` option, which is accepted by `real_c_strings` and other tables too.
+/// Wrapper is `#[repr(transparent)]`, so it has the same layout as pointer, and `as_ptr()` gives wrapped
+/// pointer. `new` is unsafe, as passed pointer should point to immutable data, which lives as long as the
+/// program, which always holds for strings produced by macros.
+///
+/// ```rust
+/// use real_c_string::{real_c_strings, real_c_sync_ptr, real_c_wstring};
+/// real_c_sync_ptr! {
+///     /// Pointer to wide string, shared between threads
+///     pub struct WStrPtr(*const i16);
+/// }
+/// real_c_strings! {
+///     #![wide, sync = WStrPtr]
+///     OPEN = "Open";
+///     SAVE = "Save";
+/// }
+/// static MENU: [WStrPtr; 3] = [OPEN, SAVE, real_c_wstring!(sync = WStrPtr, "Exit")];
+/// assert_eq!(std::mem::size_of::<WStrPtr>(), std::mem::size_of::<*const i16>());
+/// std::thread::spawn(|| assert_eq!(unsafe { *MENU[1].as_ptr() }, 'S' as i16))
+///     .join()
+///     .unwrap();
+/// ```
+///
+/// ```compile_fail
+/// // expected `*const` pointer, i.e `*const i8`, as strings are immutable
+/// real_c_string::real_c_sync_ptr!(struct CStrPtr(*mut i8));
+/// ```
+#[proc_macro]
+pub fn real_c_sync_ptr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_sync_ptr(parse_macro_input!(input as SyncPointer)).into()
+}
+
+/// Produces one `static` array holding every string, and functions returning pointers into it
+///
+/// Array of pointers needs a relocation per string, while pool is a single array, so accessors are
+/// relocation-free, and strings are adjacent in binary. Pool is declared as `static NAME;` after `#![...]`,
+/// which accepts the same options as `real_c_strings`, and entries are the same too. Every entry becomes
+/// a function with its name, returning `*const i8`, or `*const i16` with `wide`. Offsets of strings in
+/// code units are stored in `NAME_OFFSETS` array, in order of entries, with the same visibility as pool.
+/// With `dedup` flag, which is passed after `wide`, entries with the same code units share storage.
+///
+/// Pool is of type `[i8; N]`, or `[i16; N]` with `wide`, and offsets are of type `[u32; M]`.
+///
+/// ```rust
+/// mod errors {
+///     real_c_string::real_c_string_pool! {
+///         #![dedup]
+///         pub static ERRORS;
+///         pub E_OK = "ok";
+///         /// Returned if device is unavailable
+///         pub E_IO = "io failure";
+///         pub E_AGAIN = "try again";
+///         pub E_SUCCESS = "ok";
+///     }
+/// }
+/// use errors::*;
+/// let io = unsafe { std::ffi::CStr::from_ptr(E_IO()) };
+/// assert_eq!(io.to_str().unwrap(), "io failure");
+/// assert_eq!(E_IO(), ERRORS[3..].as_ptr());
+/// assert_eq!(ERRORS_OFFSETS, [0, 3, 14, 0]);
+/// assert_eq!(E_OK(), E_SUCCESS());
+/// assert_eq!(ERRORS.len(), "ok\0io failure\0try again\0".len());
+///
+/// real_c_string::real_c_string_pool! {
+///     #![wide, utf8]
+///     static MESSAGES;
+///     HELLO = "Привет";
+///     BYE = "Пока";
+/// }
+/// assert_eq!(MESSAGES_OFFSETS, [0, 7]);
+/// assert_eq!(unsafe { *BYE() }, 0x41f);
+/// ```
+#[proc_macro]
+pub fn real_c_string_pool(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_pool(parse_macro_input!(input as RealCStringPool)).into()
+}
+
+/// Produces function, returning string by numeric ID, i.e for C consumers asking for messages by code
+///
+/// Function is declared after `#![...]`, which accepts the same options as `real_c_strings`, except for
+/// `dedup`, and may have attributes, visibility and ABI, i.e `#[no_mangle] pub extern "C"`. Its parameter
+/// is written as type only. Arms are `ID => "string"`, separated by commas, where ID is integer literal
+/// in any base, and default arm `_ => "string"` is required, it is returned for unmatched IDs. Arm strings
+/// are single literals, `concat!` can be used to join several of them.
+///
+/// Function returns `*const i8`, or `*const i16` with `wide`, which should be its declared return type.
+///
+/// ```rust
+/// use real_c_string::real_c_string_lookup;
+/// use std::ffi::CStr;
+/// real_c_string_lookup! {
+///     /// Returns message for passed status
+///     pub extern "C" fn get_msg(u32) -> *const i8;
+///     1 => "ready",
+///     2 => "busy",
+///     0xFFFF => "unknown",
+///     _ => "no such message",
+/// }
+/// let msg = |id| unsafe { CStr::from_ptr(get_msg(id)) }.to_str().unwrap();
+/// assert_eq!(msg(1), "ready");
+/// assert_eq!(msg(0xffff), "unknown");
+/// assert_eq!(msg(3), "no such message");
+///
+/// real_c_string_lookup! {
+///     #![wide]
+///     fn error_text(i32) -> *const i16;
+///     -1 => "ошибка",
+///     0b100 => concat!("io", " failure"),
+///     _ => "",
+/// }
+/// assert_eq!(unsafe { *error_text(-1) }, 0x43e);
+/// assert_eq!(unsafe { *error_text(4).add(2) }, 0x20);
+/// assert_eq!(unsafe { *error_text(0) }, 0);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_lookup;
+/// real_c_string_lookup! {
+///     fn get_msg(u32) -> *const i8;
+///     1 => "ready",
+///     // Duplicate ID 0x1
+///     0x1 => "busy",
+///     _ => "unknown",
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_lookup;
+/// real_c_string_lookup! {
+///     fn get_msg(u32) -> *const i8;
+///     1 => "ready",
+///     // expected default `_ => "string"` arm for unmatched IDs
+/// }
+/// ```
+#[proc_macro]
+pub fn real_c_string_lookup(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_lookup(parse_macro_input!(input as RealCStringLookup)).into()
+}
+
+/// Generates C string versions of `&str` constants of annotated `mod` or `const` item
+///
+/// For every `const X: &str`, `X_C: *const i8` with the same value and visibility is generated,
+/// and with `wide` argument, `X_W: *const i16` as well. Original items are left untouched,
+/// and initializers should be string literals, or builtin macros, such as `concat!`.
+///
+/// ```rust
+/// use real_c_string::real_c_string_consts;
+/// #[real_c_string_consts(wide)]
+/// mod names {
+///     pub const APP_NAME: &str = "MyApp";
+///     pub const VERSION: &'static str = concat!("v", "1.0");
+///     pub const LIMIT: usize = 32;
+/// }
+/// assert_eq!(names::APP_NAME, "MyApp");
+/// let name = unsafe { std::ffi::CStr::from_ptr(names::APP_NAME_C) };
+/// assert_eq!(name.to_str().unwrap(), names::APP_NAME);
+/// let version = unsafe { std::ffi::CStr::from_ptr(names::VERSION_C) };
+/// assert_eq!(version.to_str().unwrap(), "v1.0");
+/// assert_eq!([0x76, 0x31, 0x2e, 0x30, 0], unsafe { *(names::VERSION_W as *const [i16; 5]) });
+///
+/// #[real_c_string_consts]
+/// const CLASS: &str = "Window";
+/// assert_eq!(unsafe { *CLASS_C.add(5) }, b'w' as i8);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_consts;
+/// const NAME: &str = "a";
+/// #[real_c_string_consts]
+/// // Initializers should be literals, which can be evaluated by macro
+/// const COPY: &str = NAME;
+/// ```
+#[proc_macro_attribute]
+pub fn real_c_string_consts(
+	attr: proc_macro::TokenStream,
+	item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+	let wide = match attr.to_string().as_str() {
+		"" => false,
+		"wide" => true,
+		_ => {
+			return quote! {
+				::core::compile_error!("expected no arguments, or `wide`");
+			}
+			.into()
+		}
+	};
+	let mut item = parse_macro_input!(item as syn::Item);
+	let generated = match &mut item {
+		syn::Item::Const(item) => c_string_consts(item, wide),
+		syn::Item::Mod(syn::ItemMod {
+			content: Some((_, items)),
+			..
+		}) => c_string_consts_in(items, wide).map(|()| TokenStream::new()),
+		_ => Err(syn::Error::new_spanned(
+			&item,
+			"expected `const` item or inline `mod`",
+		)),
+	};
+	match generated {
+		Ok(generated) => quote! {
+			#item
+			#generated
+		},
+		Err(error) => error.to_compile_error(),
+	}
+	.into()
+}
+
+/// Appends C string versions of `&str` constants to items, including ones of nested modules
+fn c_string_consts_in(items: &mut Vec<syn::Item>, wide: bool) -> Result<()> {
+	let mut generated = Vec::new();
+	for item in items.iter_mut() {
+		match item {
+			syn::Item::Const(item) => generated.push(c_string_consts(item, wide)?),
+			syn::Item::Mod(syn::ItemMod {
+				content: Some((_, items)),
+				..
+			}) => c_string_consts_in(items, wide)?,
+			_ => {}
+		}
+	}
+	items.extend(generated.into_iter().map(syn::Item::Verbatim));
+	Ok(())
+}
+
+/// Returns C string versions of constant, if it is `&str`
+fn c_string_consts(item: &syn::ItemConst, wide: bool) -> Result<TokenStream> {
+	let is_str = match &*item.ty {
+		syn::Type::Reference(reference) => {
+			matches!(&*reference.elem, syn::Type::Path(path) if path.path.is_ident("str"))
+		}
+		_ => false,
+	};
+	if !is_str {
+		return Ok(TokenStream::new());
+	}
+	let string: RealCString =
+		syn::parse2(quote::ToTokens::to_token_stream(&item.expr)).map_err(|_| {
+			syn::Error::new_spanned(
+				&item.expr,
+				"Initializers should be literals, which can be evaluated by macro",
+			)
+		})?;
+	let cfgs = item.attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+	let visibility = &item.vis;
+	let name = quote::format_ident!("{}_C", item.ident);
+	let pointer = transform(&string, TransformType::CString);
+	let mut out = quote! {
+		#(#cfgs)*
+		#visibility const #name: *const i8 = #pointer;
+	};
+	if wide {
+		let cfgs = item.attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+		let name = quote::format_ident!("{}_W", item.ident);
+		let pointer = transform(&string, TransformType::CWString);
+		out.extend(quote! {
+			#(#cfgs)*
+			#visibility const #name: *const i16 = #pointer;
+		});
+	}
+	Ok(out)
+}
+
+/// Generates `c_name` method of enum, returning name of variant as C string
+///
+/// Method returns `*const i8`, same as `real_c_string` does, and names are checked and encoded the same way.
+/// Only variant names are used, so variants may have fields. Attributes are passed as `#[real_c_string(...)]`:
+/// - `rename = "name"` on variant replaces its name.
+/// - `rename_all = "SCREAMING_SNAKE_CASE"` on enum converts names of variants, which are not renamed, cases are
+///   `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case`
+///   and `SCREAMING-KEBAB-CASE`, same as in serde.
+/// - `wide` on enum also generates `c_wname` method, returning `*const i16`, same as `real_c_wstring` does.
+///
+/// ```rust
+/// use real_c_string::RealCStringNames;
+/// use std::ffi::CStr;
+/// #[derive(RealCStringNames)]
+/// #[real_c_string(rename_all = "SCREAMING_SNAKE_CASE", wide)]
+/// #[repr(C)]
+/// enum Subsystem {
+///     NetworkStack,
+///     Audio { volume: u8 },
+///     #[real_c_string(rename = "gpu")]
+///     Graphics(u32),
+/// }
+/// let name = |subsystem: Subsystem| unsafe { CStr::from_ptr(subsystem.c_name()) }.to_str().unwrap();
+/// assert_eq!(name(Subsystem::NetworkStack), "NETWORK_STACK");
+/// assert_eq!(name(Subsystem::Audio { volume: 3 }), "AUDIO");
+/// assert_eq!(name(Subsystem::Graphics(0)), "gpu");
+/// assert_eq!(unsafe { *Subsystem::Graphics(0).c_wname().add(1) }, 'p' as i16);
+///
+/// #[derive(RealCStringNames)]
+/// enum Level<T> {
+///     Warning(T),
+///     Error,
+/// }
+/// assert_eq!(unsafe { CStr::from_ptr(Level::Warning("disk").c_name()) }.to_bytes(), b"Warning");
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::RealCStringNames;
+/// // `RealCStringNames` can only be derived for enums
+/// #[derive(RealCStringNames)]
+/// struct Subsystem;
+/// ```
+#[proc_macro_derive(RealCStringNames, attributes(real_c_string))]
+pub fn derive_real_c_string_names(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as syn::DeriveInput);
+	match real_c_string_names(&input) {
+		Ok(tokens) => tokens.into(),
+		Err(errors) => errors.to_compile_error().into(),
+	}
+}
+
+/// Returns `impl` block with methods returning names of variants of enum
+fn real_c_string_names(input: &syn::DeriveInput) -> Result<TokenStream> {
+	let syn::Data::Enum(data) = &input.data else {
+		return Err(syn::Error::new_spanned(
+			&input.ident,
+			"`RealCStringNames` can only be derived for enums",
+		));
+	};
+	let mut rename_all = None;
+	let mut wide = false;
+	for attr in input
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("real_c_string"))
+	{
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("wide") {
+				wide = true;
+				return Ok(());
+			}
+			if meta.path.is_ident("rename_all") {
+				let case: syn::LitStr = meta.value()?.parse()?;
+				let cases: Vec<_> = rename::RenameAll::NAMES
+					.iter()
+					.map(|(name, _)| *name)
+					.collect();
+				rename_all = Some(rename::RenameAll::find(&case.value()).ok_or_else(|| {
+					syn::Error::new(
+						case.span(),
+						format!("unknown case, expected one of: {}", cases.join(", ")),
+					)
+				})?);
+				return Ok(());
+			}
+			Err(meta.error("expected `rename_all = \"...\"` or `wide`"))
+		})?;
+	}
+	let mut names = Vec::new();
+	for variant in &data.variants {
+		let mut rename = None;
+		for attr in variant
+			.attrs
+			.iter()
+			.filter(|attr| attr.path().is_ident("real_c_string"))
+		{
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("rename") {
+					rename = Some(meta.value()?.parse::<syn::LitStr>()?);
+					return Ok(());
+				}
+				Err(meta.error("expected `rename = \"...\"`"))
+			})?;
+		}
+		let (name, span) = match rename {
+			Some(rename) => (rename.value(), rename.span()),
+			None => {
+				let name = variant.ident.to_string();
+				let name = match rename_all {
+					Some(case) => case.apply(&name),
+					None => name,
+				};
+				(name, variant.ident.span())
+			}
+		};
+		let mut string = RealCString {
+			options: Options::default(),
+			literals: vec![Literal::Str(name)],
+			span,
+			sources: Vec::new(),
+		};
+		string.apply_options()?;
+		names.push((&variant.ident, string));
+	}
+	let method = |method: TokenStream, transform_type: TransformType| {
+		let element_type = transform_type.element_type();
+		let arms = names.iter().map(|(variant, string)| {
+			let pointer = transform(string, transform_type);
+			quote! {
+				Self::#variant { .. } => #pointer,
+			}
+		});
+		quote! {
+			/// Returns name of variant as C string
+			pub fn #method(&self) -> *const #element_type {
+				match *self {
+					#(#arms)*
+				}
+			}
+		}
+	};
+	let name = &input.ident;
+	let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+	let c_name = method(quote! {c_name}, TransformType::CString);
+	let c_wname = if wide {
+		method(quote! {c_wname}, TransformType::CWString)
+	} else {
+		quote! {}
+	};
+	Ok(quote! {
+		impl #impl_generics #name #type_generics #where_clause {
+			#c_name
+			#c_wname
+		}
+	})
+}
+
+/// Returns length of string produced by `real_c_string`, in code units without terminator
+///
+/// Length is computed from encoded string, so options such as `utf8` are respected.
+/// The result of this macro invocation is of type `usize`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::{real_c_string_len, real_c_string_size};
+/// assert_eq!(real_c_string_len!("hello"), 5);
+/// assert_eq!(real_c_string_len!(utf8 "привет"), 12);
+/// const LEN: usize = real_c_string_len!("");
+/// assert_eq!(LEN, 0);
+/// ```
+#[proc_macro]
+pub fn real_c_string_len(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	transform_checked_value(&input, TransformType::CString, |len| len).into()
+}
+
+/// Returns size of string produced by `real_c_string`, in bytes including terminator
+///
+/// The result of this macro invocation is of type `usize`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_string_size;
+/// assert_eq!(real_c_string_size!("hello"), 6);
+/// assert_eq!(real_c_string_size!(no_nul "hello"), 5);
+/// let buffer = [0u8; real_c_string_size!("x")];
+/// assert_eq!(buffer.len(), 2);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_size;
+/// let size = real_c_string_size!("Привет");
+/// ```
+#[proc_macro]
+pub fn real_c_string_size(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	let terminator = transform_terminator(&input, TransformType::CString).len();
+	transform_checked_value(&input, TransformType::CString, |len| len + terminator).into()
+}
+
+/// Returns length of string produced by `real_c_wstring`, in code units without terminator
+///
+/// The result of this macro invocation is of type `usize`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_len;
+/// // 3 characters, but emoji is encoded as surrogate pair
+/// assert_eq!("Ж🎉!".chars().count(), 3);
+/// assert_eq!(real_c_wstring_len!("Ж🎉!"), 4);
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_len(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	transform_checked_value(&input, TransformType::CWString, |len| len).into()
+}
+
+/// Returns size of string produced by `real_c_wstring`, in bytes including terminator
+///
+/// The result of this macro invocation is of type `usize`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::{real_c_wstring_array, real_c_wstring_size};
+/// assert_eq!(real_c_wstring_size!("Ж🎉!"), 10);
+/// let string = real_c_wstring_array!("Ж🎉!");
+/// assert_eq!(std::mem::size_of_val(&string), real_c_wstring_size!("Ж🎉!"));
+/// let buffer = [0u8; real_c_wstring_size!("hello")];
+/// assert_eq!(buffer.len(), 12);
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_size(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	let terminator = transform_terminator(&input, TransformType::CWString).len();
+	transform_checked_value(&input, TransformType::CWString, |len| {
+		(len + terminator) * 2
+	})
+	.into()
+}
+
+/// Transforms passed string to reference to UTF-16 bytes in passed byte order, including terminator
+fn transform_wide_bytes(input: &RealCString, big_endian: bool) -> TokenStream {
+	let mut units = match transform_units(input, TransformType::CWStringU16) {
+		Ok(units) => units,
+		Err(errors) => return error_expression(errors),
+	};
+	units.extend(terminator_units(input));
+	let bytes: Vec<u8> = units
+		.iter()
+		.flat_map(|&unit| {
+			if big_endian {
+				(unit as u16).to_be_bytes()
+			} else {
+				(unit as u16).to_le_bytes()
+			}
+		})
+		.collect();
+	let literal = proc_macro2::Literal::byte_string(&bytes);
+	quote! {#literal}
+}
+
+/// Produces UTF-16LE bytes of string with two zero bytes of terminator, regardless of target byte order
+///
+/// Use it for data with fixed byte order, i.e registry values, network packets or shortcut files.
+/// `big_endian` option is ignored, use `real_c_wstring_bytes_be` instead. Size of array is returned
+/// by `real_c_wstring_size!`.
+///
+/// The result of this macro invocation is of type `&'static [u8; N]`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::{real_c_wstring_bytes_le, real_c_wstring_size};
+/// let bytes = real_c_wstring_bytes_le!("AB");
+/// assert_eq!(bytes, &[0x41, 0x00, 0x42, 0x00, 0x00, 0x00]);
+/// const VALUE: &[u8; real_c_wstring_size!("Ж")] = real_c_wstring_bytes_le!("Ж");
+/// assert_eq!(VALUE, &[0x16, 0x04, 0, 0]);
+/// assert_eq!(real_c_wstring_bytes_le!(bom no_nul "A"), &[0xff, 0xfe, 0x41, 0x00]);
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_bytes_le(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_wide_bytes(&parse_macro_input!(input as RealCString), false).into()
+}
+
+/// Same as `real_c_wstring_bytes_le`, but bytes are in UTF-16BE order
+///
+/// The result of this macro invocation is of type `&'static [u8; N]`, it is usable in `const` context.
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_bytes_be;
+/// assert_eq!(real_c_wstring_bytes_be!("AB"), &[0x00, 0x41, 0x00, 0x42, 0x00, 0x00]);
+/// assert_eq!(real_c_wstring_bytes_be!(bom "🎉"), &[0xfe, 0xff, 0xd8, 0x3c, 0xdf, 0x89, 0, 0]);
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_bytes_be(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_wide_bytes(&parse_macro_input!(input as RealCString), true).into()
+}
+
+/// Returns 32-bit FNV-1a hash of bytes of string, produced by `real_c_string`, excluding terminator
+///
+/// Hash is computed at expansion time, and is equal to one computed by this reference implementation:
+///
+/// ```rust
+/// fn fnv1a(bytes: &[u8]) -> u32 {
+///     let mut hash = 0x811c9dc5u32;
+///     for &byte in bytes {
+///         hash ^= byte as u32;
+///         hash = hash.wrapping_mul(0x01000193);
+///     }
+///     hash
+/// }
+///
+/// use real_c_string::{real_c_string_bytes, real_c_string_hash_fnv1a};
+/// const CREATE_FILE: u32 = real_c_string_hash_fnv1a!("CreateFileW");
+/// let bytes = real_c_string_bytes!("CreateFileW");
+/// assert_eq!(fnv1a(&bytes[..bytes.len() - 1]), CREATE_FILE);
+///
+/// // Published test vectors
+/// assert_eq!(real_c_string_hash_fnv1a!(""), 0x811c9dc5);
+/// assert_eq!(real_c_string_hash_fnv1a!("a"), 0xe40c292c);
+/// assert_eq!(real_c_string_hash_fnv1a!("foobar"), 0xbf9cf968);
+///
+/// // Hash is computed over encoded bytes
+/// let bytes = real_c_string_bytes!(utf8 "привет");
+/// assert_eq!(fnv1a(&bytes[..bytes.len() - 1]), real_c_string_hash_fnv1a!(utf8 "привет"));
+///
+/// match fnv1a(b"CreateFileW") {
+///     CREATE_FILE => {}
+///     _ => unreachable!(),
+/// }
+/// ```
+#[proc_macro]
+pub fn real_c_string_hash_fnv1a(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	transform_hash(&input, TransformType::CString, |bytes| {
+		let hash = hash::fnv1a32(bytes);
+		quote! {#hash}
+	})
+	.into()
+}
+
+/// Same as `real_c_string_hash_fnv1a`, but returns 64-bit FNV-1a hash
+///
+/// ```rust
+/// fn fnv1a64(bytes: &[u8]) -> u64 {
+///     let mut hash = 0xcbf29ce484222325u64;
+///     for &byte in bytes {
+///         hash ^= byte as u64;
+///         hash = hash.wrapping_mul(0x100000001b3);
+///     }
+///     hash
+/// }
+///
+/// use real_c_string::{real_c_string_bytes, real_c_string_hash_fnv1a64};
+/// assert_eq!(real_c_string_hash_fnv1a64!(""), 0xcbf29ce484222325);
+/// assert_eq!(real_c_string_hash_fnv1a64!("a"), 0xaf63dc4c8601ec8c);
+/// assert_eq!(real_c_string_hash_fnv1a64!("foobar"), 0x85944171f73967e8);
+///
+/// let bytes = real_c_string_bytes!(encoding = "windows-1251" "привет");
+/// assert_eq!(
+///     fnv1a64(&bytes[..bytes.len() - 1]),
+///     real_c_string_hash_fnv1a64!(encoding = "windows-1251" "привет")
+/// );
+/// ```
+#[proc_macro]
+pub fn real_c_string_hash_fnv1a64(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	transform_hash(&input, TransformType::CString, |bytes| {
+		let hash = hash::fnv1a64(bytes);
+		quote! {#hash}
+	})
+	.into()
+}
+
+/// Returns 32-bit FNV-1a hash of bytes of string, produced by `real_c_wstring`, excluding terminator
+///
+/// Bytes are taken in target byte order, as they are laid out in memory, unless `big_endian` is passed.
+///
+/// ```rust
+/// fn fnv1a(bytes: impl IntoIterator<Item = u8>) -> u32 {
+///     let mut hash = 0x811c9dc5u32;
+///     for byte in bytes {
+///         hash ^= byte as u32;
+///         hash = hash.wrapping_mul(0x01000193);
+///     }
+///     hash
+/// }
+/// fn bytes(units: &[i16]) -> impl Iterator<Item = u8> + '_ {
+///     units[..units.len() - 1].iter().flat_map(|unit| unit.to_ne_bytes())
+/// }
+///
+/// use real_c_string::{real_c_wstring_array, real_c_wstring_hash_fnv1a};
+/// let string = real_c_wstring_array!("CreateFileW");
+/// assert_eq!(fnv1a(bytes(&string)), real_c_wstring_hash_fnv1a!("CreateFileW"));
+/// let string = real_c_wstring_array!("Ж🎉!");
+/// assert_eq!(fnv1a(bytes(&string)), real_c_wstring_hash_fnv1a!("Ж🎉!"));
+/// let string = real_c_wstring_array!(big_endian "Ж🎉!");
+/// assert_eq!(fnv1a(bytes(&string)), real_c_wstring_hash_fnv1a!(big_endian "Ж🎉!"));
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_hash_fnv1a(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	transform_hash(&input, TransformType::CWString, |bytes| {
+		let hash = hash::fnv1a32(bytes);
+		quote! {#hash}
+	})
+	.into()
+}
+
+/// Same as `real_c_wstring_hash_fnv1a`, but returns 64-bit FNV-1a hash
+///
+/// ```rust
+/// fn fnv1a64(bytes: impl IntoIterator<Item = u8>) -> u64 {
+///     let mut hash = 0xcbf29ce484222325u64;
+///     for byte in bytes {
+///         hash ^= byte as u64;
+///         hash = hash.wrapping_mul(0x100000001b3);
+///     }
+///     hash
+/// }
+///
+/// use real_c_string::{real_c_wstring_array, real_c_wstring_hash_fnv1a64};
+/// let string = real_c_wstring_array!("Ж🎉!");
+/// let bytes = string[..string.len() - 1].iter().flat_map(|unit| unit.to_ne_bytes());
+/// assert_eq!(fnv1a64(bytes), real_c_wstring_hash_fnv1a64!("Ж🎉!"));
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wstring_hash_fnv1a64;
+/// let hash = real_c_wstring_hash_fnv1a64!(ucs2 "🎉");
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_hash_fnv1a64(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	transform_hash(&input, TransformType::CWString, |bytes| {
+		let hash = hash::fnv1a64(bytes);
+		quote! {#hash}
+	})
+	.into()
+}
+
+/// Returns djb2 hash of bytes of string, produced by `real_c_string`, excluding terminator
+///
+/// Hash is the classic `hash = hash * 33 + byte` with wrapping arithmetic, bytes are unsigned.
+/// Initial state is `5381`, it may be overridden with `seed = N` before options.
+///
+/// ```rust
+/// fn djb2(bytes: &[u8]) -> u32 {
+///     let mut hash = 5381u32;
+///     for &byte in bytes {
+///         hash = hash.wrapping_mul(33).wrapping_add(byte as u32);
+///     }
+///     hash
+/// }
+///
+/// use real_c_string::{real_c_string_bytes, real_c_string_hash_djb2};
+/// assert_eq!(real_c_string_hash_djb2!(""), 5381);
+/// assert_eq!(real_c_string_hash_djb2!("a"), 177670);
+/// assert_eq!(real_c_string_hash_djb2!("hello"), 261238937);
+/// assert_eq!(real_c_string_hash_djb2!(seed = 0, "hello"), 127086708);
+///
+/// let bytes = real_c_string_bytes!("on_frame_rendered");
+/// assert_eq!(djb2(&bytes[..bytes.len() - 1]), real_c_string_hash_djb2!("on_frame_rendered"));
+/// let bytes = real_c_string_bytes!(utf8 "привет");
+/// assert_eq!(djb2(&bytes[..bytes.len() - 1]), real_c_string_hash_djb2!(seed = 5381, utf8 "привет"));
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_hash_djb2;
+/// let hash = real_c_string_hash_djb2!(seed = 0x100000000, "hello");
+/// ```
+#[proc_macro]
+pub fn real_c_string_hash_djb2(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as SeededRealCString);
+	let seed = input.seed.unwrap_or(hash::DJB2_SEED);
+	transform_hash(&input.string, TransformType::CString, |bytes| {
+		let hash = hash::djb2(bytes, seed);
+		quote! {#hash}
+	})
+	.into()
+}
+
+/// Returns CRC-32 of bytes of string, produced by `real_c_string`
+///
+/// Terminator is excluded, unless `with_nul` is passed. Polynomial is selected with `poly` argument
+/// before options: `poly = "ieee"` is the default reflected polynomial used by zlib,
+/// `poly = "crc32c"` is Castagnoli polynomial. Both use initial value and final xor of `0xffffffff`.
+///
+/// ```rust
+/// use real_c_string::real_c_string_crc32;
+/// // Check values of both polynomials
+/// assert_eq!(real_c_string_crc32!("123456789"), 0xcbf43926);
+/// assert_eq!(real_c_string_crc32!(poly = "crc32c", "123456789"), 0xe3069283);
+/// assert_eq!(real_c_string_crc32!(""), 0);
+///
+/// // Values computed by zlib
+/// assert_eq!(real_c_string_crc32!("boot/stage2"), 0x4e5de343);
+/// assert_eq!(real_c_string_crc32!(with_nul "boot/stage2"), 0x3d99a244);
+/// assert_eq!(real_c_string_crc32!(poly = "ieee", with_nul "boot/stage2"), 0x3d99a244);
+/// assert_eq!(real_c_string_crc32!(poly = "crc32c", "boot/stage2"), 0x0ce3b9e8);
+/// assert_eq!(real_c_string_crc32!(poly = "crc32c", with_nul "boot/stage2"), 0x3b98403d);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_crc32;
+/// let crc = real_c_string_crc32!(poly = "crc64", "boot/stage2");
+/// ```
+#[proc_macro]
+pub fn real_c_string_crc32(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as Crc32RealCString);
+	transform_hash(&input.string, TransformType::CString, |bytes| {
+		let crc = hash::crc32(bytes, input.polynomial);
+		quote! {#crc}
+	})
+	.into()
+}
+
+/// Returns four-character code of string, as used by RIFF, AVI and other chunk-based formats
+///
+/// String should be exactly four bytes after encoding, with the same options as `real_c_string`, and
+/// terminator is never included. Bytes are packed to `u32` in little-endian order by default, so first
+/// character is in the lowest byte, as RIFF stores chunk IDs. Order is selected with `endian` argument
+/// before options, `endian = "big"` puts first character in the highest byte, as QuickTime and PNG do.
+///
+/// ```rust
+/// use real_c_string::real_c_fourcc;
+/// assert_eq!(real_c_fourcc!("RIFF"), 0x46464952);
+/// assert_eq!(real_c_fourcc!(endian = "little", "AVI "), u32::from_le_bytes(*b"AVI "));
+/// assert_eq!(real_c_fourcc!(endian = "big", "IHDR"), 0x49484452);
+///
+/// // Matches on chunk IDs
+/// const DATA: u32 = real_c_fourcc!("data");
+/// assert!(matches!(u32::from_le_bytes(*b"data"), DATA));
+/// ```
+///
+/// ```compile_fail
+/// // String of size 3 doesn't match tag of size 4
+/// let fourcc = real_c_string::real_c_fourcc!("AVI");
+/// ```
+///
+/// ```compile_fail
+/// // Unsupported character 'Ж' (U+0416) at offset 0
+/// let fourcc = real_c_string::real_c_fourcc!("Жabc");
+/// ```
+#[proc_macro]
+pub fn real_c_fourcc(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_fourcc(parse_macro_input!(input as FourccRealCString)).into()
+}
+
+/// Produces fixed-size tag without terminator, i.e for magic fields of file headers
+///
+/// Size of tag is passed before string, and string should be exactly that many bytes after encoding,
+/// with the same options as `real_c_string`.
+///
+/// The result of this macro invocation is of type `[u8; N]`.
+///
+/// ```rust
+/// use real_c_string::real_c_tag;
+/// const MAGIC: [u8; 8] = real_c_tag!(8, "\u{89}PNG\r\n\x1a\n");
+/// assert_eq!(&MAGIC, b"\x89PNG\r\n\x1a\n");
+/// assert_eq!(real_c_tag!(2, "MZ"), *b"MZ");
+/// assert_eq!(real_c_tag!(3, encoding = "windows-1251", "Тег"), [0xd2, 0xe5, 0xe3]);
+/// ```
+///
+/// ```compile_fail
+/// // String of size 5 doesn't match tag of size 4
+/// let tag = real_c_string::real_c_tag!(4, "ABCDE");
+/// ```
+#[proc_macro]
+pub fn real_c_tag(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_tag(parse_macro_input!(input as PaddedRealCString)).into()
+}
+
+/// Returns case-insensitive hash of string, produced by `real_c_wstring`
+///
+/// String is uppercased with ASCII case folding, only `a`-`z` are changed, then encoded as UTF-16.
+/// Hashed bytes are bytes of every code unit in memory order of target, i.e UTF-16LE on Windows,
+/// terminator is excluded, unless `with_nul` is passed.
+///
+/// Algorithm is selected with `algorithm` argument before options, `algorithm = "fnv1a"` is 32-bit
+/// FNV-1a, which is the default, and `algorithm = "ror13"` rotates 32-bit state right by 13 bits, and
+/// adds every byte, starting from zero.
+///
+/// ```rust
+/// fn ror13(bytes: impl IntoIterator<Item = u8>) -> u32 {
+///     let mut hash = 0u32;
+///     for byte in bytes {
+///         hash = hash.rotate_right(13).wrapping_add(byte as u32);
+///     }
+///     hash
+/// }
+/// fn fnv1a(bytes: impl IntoIterator<Item = u8>) -> u32 {
+///     let mut hash = 0x811c9dc5u32;
+///     for byte in bytes {
+///         hash ^= byte as u32;
+///         hash = hash.wrapping_mul(0x01000193);
+///     }
+///     hash
+/// }
+/// // Uppercased bytes of wide string, as module names are compared by loader
+/// fn bytes(mut string: *const i16) -> Vec<u8> {
+///     let mut bytes = Vec::new();
+///     while unsafe { *string } != 0 {
+///         let unit = unsafe { *string } as u16;
+///         let unit = if (b'a' as u16..=b'z' as u16).contains(&unit) { unit - 32 } else { unit };
+///         bytes.extend(unit.to_ne_bytes());
+///         string = unsafe { string.add(1) };
+///     }
+///     bytes
+/// }
+///
+/// use real_c_string::{real_c_wstring, real_c_wstring_hash_ci};
+/// const KERNEL32: u32 = real_c_wstring_hash_ci!("kernel32.dll");
+/// assert_eq!(KERNEL32, real_c_wstring_hash_ci!("KERNEL32.DLL"));
+/// assert_eq!(fnv1a(bytes(real_c_wstring!("Kernel32.dll"))), KERNEL32);
+///
+/// const NTDLL: u32 = real_c_wstring_hash_ci!(algorithm = "ror13", "ntdll.dll");
+/// assert_eq!(ror13(bytes(real_c_wstring!("NtDll.dll"))), NTDLL);
+///
+/// // Only ASCII letters are folded
+/// assert_ne!(real_c_wstring_hash_ci!("ж.dll"), real_c_wstring_hash_ci!("Ж.dll"));
+/// assert_eq!(ror13(bytes(real_c_wstring!("ж.dll"))), real_c_wstring_hash_ci!(algorithm = "ror13", "ж.DLL"));
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_wstring_hash_ci;
+/// let hash = real_c_wstring_hash_ci!(algorithm = "crc32", "ntdll.dll");
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_hash_ci(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as CaseInsensitiveRealCString);
+	transform_hash(&input.string, TransformType::CWString, |bytes| {
+		let hash = (input.algorithm)(bytes);
+		quote! {#hash}
+	})
+	.into()
+}
+
+/// Same as `real_c_string`, but string is stored XOR-ed with a key, and decoded at runtime
+///
+/// Every code unit, including terminator, is XOR-ed with the same key. Key is derived from FNV-1a hash of
+/// seed, followed by bytes of string, so repeated builds produce identical binaries. Seed is read from
+/// `REAL_C_STRING_SEED` environment variable at expansion time, and is empty if it isn't set, changing
+/// it changes every key in crate. Fixed key may be passed with `key = 0x5a` before options, i.e to match
+/// external decoder, `real_c_string_obf_key` returns key used for string.
+///
+/// Decoding doesn't allocate, and works in `no_std`: string is decoded into a temporary buffer on stack.
+///
+/// **Returned pointer is only valid while the buffer lives.** When the result is assigned with `let`,
+/// buffer lives until the end of enclosing block, otherwise it is dropped at the end of statement,
+/// i.e it's fine to pass the result directly to a function, but not to return it or store it in a static.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use core::ffi::CStr;
+/// use real_c_string::{real_c_string, real_c_string_obf};
+/// let plain = unsafe { CStr::from_ptr(real_c_string!(utf8 "пароль")) };
+/// assert_eq!(unsafe { CStr::from_ptr(real_c_string_obf!(utf8 "пароль")) }, plain);
+///
+/// // Plaintext is built at runtime, so it is not present in binary too
+/// let plaintext: Vec<u8> = b"moc.elpmaxe.revres-esnecil".iter().rev().copied().collect();
+/// let server = real_c_string_obf!("license-server.example.com");
+/// assert_eq!(unsafe { CStr::from_ptr(server) }.to_bytes(), plaintext);
+///
+/// let binary = std::fs::read(std::env::current_exe().unwrap()).unwrap();
+/// assert!(!binary.windows(plaintext.len()).any(|window| window == plaintext));
+///
+/// // String is XOR-ed with passed key
+/// let encoded: Vec<u8> = plaintext.iter().map(|b| b ^ 0x5a).collect();
+/// let server = real_c_string_obf!(key = 0x5a, "license-server.example.com");
+/// assert_eq!(unsafe { CStr::from_ptr(server) }.to_bytes(), plaintext);
+/// assert!(binary.windows(encoded.len()).any(|window| window == encoded));
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_obf;
+/// let string = real_c_string_obf!(key = 0x100, "license");
+/// ```
+#[proc_macro]
+pub fn real_c_string_obf(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as KeyedRealCString);
+	transform_obfuscated(&input, TransformType::CString).into()
+}
+
+/// Same as `real_c_wstring`, but string is stored XOR-ed with a key, and decoded at runtime
+///
+/// Same as for `real_c_string_obf`, **returned pointer is only valid while the buffer lives**,
+/// which is enclosing block with `let`, or statement otherwise.
+///
+/// The result of this macro invocation is of type `*const i16`.
+///
+/// ```rust
+/// use real_c_string::{real_c_wstring_array, real_c_wstring_obf};
+/// let expected = real_c_wstring_array!("Ж🎉 kernel32");
+/// let string = real_c_wstring_obf!("Ж🎉 kernel32");
+/// let decoded = unsafe { std::slice::from_raw_parts(string, expected.len()) };
+/// assert_eq!(decoded, expected);
+///
+/// let expected = real_c_wstring_array!(big_endian "abc");
+/// let string = real_c_wstring_obf!(big_endian "abc");
+/// assert_eq!(unsafe { std::slice::from_raw_parts(string, 4) }, expected);
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_obf(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as KeyedRealCString);
+	transform_obfuscated(&input, TransformType::CWString).into()
+}
+
+/// Same as `real_c_string`, but string is built on stack at runtime, by storing every code unit separately
+///
+/// Every code unit is stored from an immediate value, in shuffled order, so no contiguous copy of string
+/// is present in binary. Order is derived from string and seed, the same way as keys of
+/// `real_c_string_obf`. Strings are limited to 256 code units, including terminator.
+///
+/// Same as for `real_c_string_obf`, **returned pointer is only valid while the buffer lives**:
+/// with `let` it lives until the end of enclosing block, otherwise until the end of statement.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use core::ffi::CStr;
+/// use real_c_string::{real_c_string, real_c_stack_string};
+/// let url = real_c_stack_string!("http://update.example.com/check");
+/// let expected = unsafe { CStr::from_ptr(real_c_string!("http://update.example.com/check")) };
+/// assert_eq!(unsafe { CStr::from_ptr(url) }, expected);
+///
+/// let empty = real_c_stack_string!("");
+/// assert_eq!(unsafe { *empty }, 0);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_stack_string;
+/// let string = real_c_stack_string!(::core::concat!(
+///     "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+///     "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+///     "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+///     "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+/// ));
+/// ```
+#[proc_macro]
+pub fn real_c_stack_string(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	transform_stack(&input, TransformType::CString).into()
+}
+
+/// Same as `real_c_wstring`, but string is built on stack at runtime, by storing every code unit separately
+///
+/// Same as for `real_c_stack_string`, **returned pointer is only valid while the buffer lives**.
+///
+/// The result of this macro invocation is of type `*const i16`.
+///
+/// ```rust
+/// use real_c_string::{real_c_stack_wstring, real_c_wstring_array};
+/// let expected = real_c_wstring_array!("Ж🎉 ntdll.dll");
+/// let string = real_c_stack_wstring!("Ж🎉 ntdll.dll");
+/// assert_eq!(unsafe { std::slice::from_raw_parts(string, expected.len()) }, expected);
+/// ```
+#[proc_macro]
+pub fn real_c_stack_wstring(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as RealCString);
+	transform_stack(&input, TransformType::CWString).into()
+}
+
+/// Same as `real_c_string`, but string is split into chunks stored in separate statics, and assembled at runtime
+///
+/// Number of chunks is passed with `chunks = N` before options, and is 4 by default. String is split into
+/// chunks of nearly equal size, if it is shorter than number of chunks, every chunk is a single code unit.
+///
+/// Same as for `real_c_string_obf`, **returned pointer is only valid while the buffer lives**:
+/// with `let` it lives until the end of enclosing block, otherwise until the end of statement.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use real_c_string::{real_c_string_array, real_c_string_split};
+/// let expected = real_c_string_array!("https://telemetry.example.com/v2/report");
+/// let string = real_c_string_split!(chunks = 4, "https://telemetry.example.com/v2/report");
+/// assert_eq!(unsafe { std::slice::from_raw_parts(string, expected.len()) }, expected);
+///
+/// let string = real_c_string_split!(chunks = 16, "abc");
+/// assert_eq!(unsafe { std::slice::from_raw_parts(string, 4) }, real_c_string_array!("abc"));
+/// let string = real_c_string_split!("");
+/// assert_eq!(unsafe { *string }, 0);
+///
+/// let string = real_c_string_split!(terminator = 0xff, "abcdef");
+/// assert_eq!(unsafe { *string.add(6) } as u8, 0xff);
+/// ```
+///
+/// ```compile_fail
+/// use real_c_string::real_c_string_split;
+/// let string = real_c_string_split!(chunks = 0, "abc");
+/// ```
+#[proc_macro]
+pub fn real_c_string_split(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as SplitRealCString);
+	transform_split(&input, TransformType::CString).into()
+}
+
+/// Same as `real_c_wstring`, but string is split into chunks stored in separate statics, and assembled at runtime
+///
+/// Same as for `real_c_string_split`, **returned pointer is only valid while the buffer lives**.
+///
+/// The result of this macro invocation is of type `*const i16`.
+///
+/// ```rust
+/// use real_c_string::{real_c_wstring_array, real_c_wstring_split};
+/// let expected = real_c_wstring_array!("Ж🎉 telemetry");
+/// let string = real_c_wstring_split!(chunks = 3, "Ж🎉 telemetry");
+/// assert_eq!(unsafe { std::slice::from_raw_parts(string, expected.len()) }, expected);
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_split(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as SplitRealCString);
+	transform_split(&input, TransformType::CWString).into()
+}
+
+/// Returns key, with which `real_c_string_obf` XORs passed string
+///
+/// Key is derived the same way as by `real_c_string_obf`, so it is the same for the same string and seed,
+/// and may be passed to external decoders. Passed `key = N` is returned as is.
+///
+/// The result of this macro invocation is of type `u8`.
+///
+/// ```rust
+/// use real_c_string::real_c_string_obf_key;
+/// // Expansion is deterministic, and depends on string
+/// assert_eq!(real_c_string_obf_key!("license"), real_c_string_obf_key!("license"));
+/// assert_ne!(real_c_string_obf_key!("license"), 0);
+/// assert_eq!(real_c_string_obf_key!(key = 0x5a, "license"), 0x5a);
+///
+/// // Key is used for every byte, including terminator
+/// let string = real_c_string::real_c_string_obf!("license");
+/// let terminator = unsafe { *string.add(7) };
+/// assert_eq!(terminator, 0);
+/// ```
+#[proc_macro]
+pub fn real_c_string_obf_key(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as KeyedRealCString);
+	transform_obfuscation_key(&input, TransformType::CString).into()
+}
+
+/// Returns key, with which `real_c_wstring_obf` XORs passed string
+///
+/// The result of this macro invocation is of type `u16`.
+///
+/// ```rust
+/// use real_c_string::real_c_wstring_obf_key;
+/// assert_eq!(real_c_wstring_obf_key!("ntdll.dll"), real_c_wstring_obf_key!("ntdll.dll"));
+/// assert_eq!(real_c_wstring_obf_key!(key = 0x5a5a, "ntdll.dll"), 0x5a5a);
+/// ```
+#[proc_macro]
+pub fn real_c_wstring_obf_key(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as KeyedRealCString);
+	transform_obfuscation_key(&input, TransformType::CWString).into()
+}
+
+/// Same as `real_c_string`, but string is stored compressed, and decompressed to static buffer on first use
+///
+/// String is compressed with LZSS at expansion time, decompressor is included in expansion, so no runtime
+/// dependency is needed, and it works in `no_std`. Buffer is decompressed once, concurrent first uses wait for
+/// it, so returned pointer is valid for `'static`. Uncompressed size is returned by `real_c_string_size`
+/// with the same arguments. Requires `compression` feature.
+///
+/// The result of this macro invocation is of type `*const i8`.
+///
+/// ```rust
+/// use core::ffi::CStr;
+/// use real_c_string::{real_c_string_compressed, real_c_string_size};
+/// fn schema() -> &'static CStr {
+///     unsafe { CStr::from_ptr(real_c_string_compressed!("CREATE TABLE a (id INTEGER); CREATE TABLE b (id INTEGER);")) }
+/// }
+/// assert_eq!(schema().to_bytes(), b"CREATE TABLE a (id INTEGER); CREATE TABLE b (id INTEGER);");
+/// assert_eq!(schema().to_bytes_with_nul().len(), real_c_string_size!("CREATE TABLE a (id INTEGER); CREATE TABLE b (id INTEGER);"));
+/// ```
+#[cfg(feature = "compression")]
+#[proc_macro]
+pub fn real_c_string_compressed(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_compressed(parse_macro_input!(input as RealCString)).into()
+}
+
+/// Expands to check, that C string at pointer is equal to expected string, encoded the same way
+fn transform_assert(input: AssertedRealCString, transform_type: TransformType) -> TokenStream {
+	let AssertedRealCString {
+		pointer,
+		mut expected,
+	} = input;
+	expected.options.terminated = false;
+	let (data, data_type, _) = match transform_compact(&expected, transform_type) {
+		Ok(compact) => compact,
+		Err(errors) => return error_expression(errors),
+	};
+	let text = expected.text();
+	let actual_text = if transform_type.unit_size() == 1 {
+		quote! {::core::str::from_utf8(actual).unwrap_or("<not UTF-8>")}
+	} else {
+		quote! {"<wide>"}
+	};
+	quote! {
+		{
+			const EXPECTED: &[#data_type] = #data;
+			// Strings which are a bit longer than expected are still shown in message
+			const LIMIT: usize = EXPECTED.len() + 256;
+			let pointer = (#pointer) as *const #data_type;
+			if pointer.is_null() {
+				::core::panic!(
+					"assertion `c_string == expected` failed: pointer is null\n expected: {:?}",
+					#text
+				);
+			}
+			let mut len = 0;
+			// Reads stop at terminator, so only memory of C string is read
+			while len < LIMIT && unsafe { *pointer.add(len) } != 0 {
+				len += 1;
+			}
+			let actual = unsafe { ::core::slice::from_raw_parts(pointer, len) };
+			if len == LIMIT {
+				::core::panic!(
+					"assertion `c_string == expected` failed: no terminator in first {} code units\n expected: {:?}\n    units: {:x?}",
+					LIMIT, #text, actual
+				);
+			}
+			if actual != EXPECTED {
+				::core::panic!(
+					"assertion `c_string == expected` failed\n expected: {:?}\n   actual: {:?}\n expected units: {:x?}\n   actual units: {:x?}",
+					#text, #actual_text, EXPECTED, actual
+				);
+			}
+		}
+	}
+}
+
+/// Asserts that C string at pointer is equal to expected string, for tests of code producing C strings
+///
+/// Expected string accepts the same options as `real_c_string`, and is encoded the same way. Pointer is
+/// read until terminator, but no further than 256 code units past length of expected string. On mismatch,
+/// panic message shows both strings and their code units. Only `core` is used, so it works in `no_std` tests.
+///
+/// Pointer should be valid for reads until terminator, or the limit, which is not checked.
+///
+/// ```rust
+/// use real_c_string::{assert_c_string_eq, real_c_string};
+/// assert_c_string_eq!(real_c_string!("Hello world!"), "Hello world!");
+/// assert_c_string_eq!(real_c_string!(utf8 "Привет"), utf8 "Привет");
+/// assert_c_string_eq!(b"\xe9t\0".as_ptr(), "\u{e9}t");
+/// ```
+///
+/// ```rust,should_panic
+/// use real_c_string::{assert_c_string_eq, real_c_string};
+/// // Panics with:
+/// // assertion `c_string == expected` failed
+/// //  expected: "Hello"
+/// //    actual: "Hell"
+/// //  expected units: [48, 65, 6c, 6c, 6f]
+/// //    actual units: [48, 65, 6c, 6c]
+/// assert_c_string_eq!(real_c_string!("Hell"), "Hello");
+/// ```
+///
+/// ```rust,should_panic
+/// use real_c_string::assert_c_string_eq;
+/// assert_c_string_eq!(core::ptr::null::<i8>(), "ptr");
+/// ```
+#[proc_macro]
+pub fn assert_c_string_eq(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_assert(
+		parse_macro_input!(input as AssertedRealCString),
+		TransformType::CStringU8,
+	)
+	.into()
+}
+
+/// Same as `assert_c_string_eq`, but used for wchar_t* strings
+///
+/// ```rust
+/// use real_c_string::{assert_c_wstring_eq, real_c_wstring};
+/// assert_c_wstring_eq!(real_c_wstring!("Привет 🎉"), "Привет 🎉");
+/// let units: Vec<u16> = "key".encode_utf16().chain([0]).collect();
+/// assert_c_wstring_eq!(units.as_ptr(), "key");
+/// ```
+///
+/// ```rust,should_panic
+/// use real_c_string::{assert_c_wstring_eq, real_c_wstring};
+/// assert_c_wstring_eq!(real_c_wstring!("Key"), "key");
+/// ```
+#[proc_macro]
+pub fn assert_c_wstring_eq(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	transform_assert(
+		parse_macro_input!(input as AssertedRealCString),
+		TransformType::CWStringU16,
+	)
+	.into()
+}
+
+/// Returns iterator over strings of every crate in binary, registered by macros returning pointers
+///
+/// With `registry` feature, every invocation of `real_c_string!`, `real_c_wstring!` and other macros returning
+/// pointers places descriptor of its string into link section, which linker joins for the whole binary, so
+/// strings can be audited without scanning it. Strings with `no_register` option are skipped, i.e secrets.
+/// Items are tuples of bytes of string as they are stored, including terminator, label of encoding, i.e
+/// `iso-8859-1`, `utf-8`, `windows-1251` or `utf-16`, and path of module, in which string is defined.
+///
+/// Descriptors are placed in `real_c_string_registry` section on ELF targets, in `__DATA,__rcs_registry`
+/// on Mach-O and between `.rcsreg$a` and `.rcsreg$c` on Windows, and other targets are not supported. Order of
+/// strings is unspecified, and invocation, which is expanded once, is registered once, even if it's evaluated
+/// many times.
+///
+/// The result of this macro invocation is of type `impl Iterator<Item = (&'static [u8], &'static str, &'static str)>`.
+///
+/// ```rust
+/// use real_c_string::{real_c_string, real_c_string_registry, real_c_wstring};
+/// let name = real_c_string!("audited.name");
+/// let title = real_c_wstring!("Title");
+/// let token = real_c_string!(no_register "secret-token");
+///
+/// let strings: Vec<_> = real_c_string_registry!().collect();
+/// assert!(strings.contains(&(&b"audited.name\0"[..], "iso-8859-1", module_path!())));
+/// assert!(strings.iter().any(|&(bytes, encoding, _)| encoding == "utf-16" && bytes.len() == 12));
+/// assert!(!strings.iter().any(|(bytes, ..)| bytes.starts_with(b"secret-token")));
+/// ```
+#[cfg(feature = "registry")]
+#[proc_macro]
+pub fn real_c_string_registry(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	parse_macro_input!(input as syn::parse::Nothing);
+	registry::iterate().into()
 }
@@ -0,0 +1,71 @@
+//! Random strings, unique to every build, used by `real_c_nonce_string!`
+//!
+//! Without seed, strings are derived from random keys of std `RandomState` and current time. With seed, read
+//! from `REAL_C_STRING_NONCE_SEED` environment variable, they are derived from FNV-1a hash of seed followed by
+//! location of invocation, so the same source always produces the same binary.
+
+use crate::hash;
+use std::{
+	collections::{hash_map::RandomState, HashMap},
+	hash::{BuildHasher, Hasher},
+	sync::{Mutex, OnceLock},
+	time::SystemTime,
+};
+
+/// Environment variable, from which seed is read
+pub(crate) const SEED_VAR: &str = "REAL_C_STRING_NONCE_SEED";
+
+/// Max length of nonce, protecting from typos in length
+pub(crate) const MAX_LEN: usize = 4096;
+
+const ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Returns random state for nonce at passed invocation location, i.e `src/main.rs:10:5`
+fn random_state(location: &str) -> u64 {
+	let mut hasher = RandomState::new().build_hasher();
+	hasher.write(location.as_bytes());
+	if let Ok(time) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+		hasher.write_u128(time.as_nanos());
+	}
+	hasher.finish()
+}
+
+/// Returns alphanumeric string of passed length for invocation at passed location
+///
+/// Without seed, string is remembered, so repeated expansions of the same invocation in one
+/// compilation produce the same string.
+pub(crate) fn generate(len: usize, location: &str) -> String {
+	static CACHE: OnceLock<Mutex<HashMap<(String, usize), String>>> = OnceLock::new();
+	let Ok(seed) = std::env::var(SEED_VAR) else {
+		let cache = CACHE.get_or_init(Default::default);
+		let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+		return cache
+			.entry((location.to_owned(), len))
+			.or_insert_with(|| alphanumeric(len, random_state(location)))
+			.clone();
+	};
+	let mut bytes = seed.into_bytes();
+	bytes.push(0);
+	bytes.extend(location.as_bytes());
+	alphanumeric(len, hash::fnv1a64(&bytes))
+}
+
+/// Returns alphanumeric string, generated by xorshift64 with passed state
+fn alphanumeric(len: usize, state: u64) -> String {
+	// xorshift64 never leaves zero state, so it's avoided
+	let mut state = state | 1;
+	let mut out = String::with_capacity(len);
+	while out.len() < len {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		// Bytes above the largest multiple of alphabet length are skipped, so every character is equally likely
+		for byte in state.to_le_bytes() {
+			let limit = 256 - 256 % ALPHABET.len();
+			if usize::from(byte) < limit && out.len() < len {
+				out.push(ALPHABET[usize::from(byte) % ALPHABET.len()] as char);
+			}
+		}
+	}
+	out
+}
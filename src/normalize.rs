@@ -0,0 +1,131 @@
+//! Canonical Unicode normalization of string literals, enabled by `normalize = "nfc"` option
+//!
+//! Implements decomposition, canonical ordering and canonical composition described in UAX #15,
+//! using built-in tables instead of depending on `unicode-normalization`.
+
+mod tables;
+
+use std::cmp::Ordering;
+use tables::{COMBINING_CLASSES, COMPOSITIONS, DECOMPOSITIONS};
+
+// Hangul syllables are composed algorithmically, and are not present in tables
+const S_BASE: u32 = 0xac00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11a7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Returns canonical combining class of character, starters have class 0
+fn combining_class(c: char) -> u8 {
+	COMBINING_CLASSES
+		.binary_search_by(|&(first, last, _)| {
+			if last < c {
+				Ordering::Less
+			} else if first > c {
+				Ordering::Greater
+			} else {
+				Ordering::Equal
+			}
+		})
+		.map_or(0, |i| COMBINING_CLASSES[i].2)
+}
+
+/// Pushes full canonical decomposition of character
+fn decompose_char(c: char, out: &mut Vec<char>) {
+	let s = (c as u32).wrapping_sub(S_BASE);
+	if s < S_COUNT {
+		let l = L_BASE + s / N_COUNT;
+		let v = V_BASE + (s % N_COUNT) / T_COUNT;
+		let t = T_BASE + s % T_COUNT;
+		out.extend([l, v].iter().filter_map(|&c| char::from_u32(c)));
+		if t != T_BASE {
+			out.extend(char::from_u32(t));
+		}
+		return;
+	}
+	match DECOMPOSITIONS.binary_search_by_key(&c, |&(c, _, _)| c) {
+		Ok(i) => {
+			let (_, first, second) = DECOMPOSITIONS[i];
+			decompose_char(first, out);
+			if second != '\0' {
+				decompose_char(second, out);
+			}
+		}
+		Err(_) => out.push(c),
+	}
+}
+
+/// Returns canonically decomposed and ordered characters of string
+fn decompose(string: &str) -> Vec<char> {
+	let mut out = Vec::with_capacity(string.len());
+	for c in string.chars() {
+		decompose_char(c, &mut out);
+	}
+	// Sort is stable, so characters with the same class keep their order
+	let mut start = 0;
+	for i in 0..=out.len() {
+		if i == out.len() || combining_class(out[i]) == 0 {
+			out[start..i].sort_by_key(|&c| combining_class(c));
+			start = i + 1;
+		}
+	}
+	out
+}
+
+/// Returns primary composite of two characters, if there is one
+fn compose_pair(first: char, second: char) -> Option<char> {
+	let l = (first as u32).wrapping_sub(L_BASE);
+	let v = (second as u32).wrapping_sub(V_BASE);
+	if l < L_COUNT && v < V_COUNT {
+		return char::from_u32(S_BASE + (l * V_COUNT + v) * T_COUNT);
+	}
+	let s = (first as u32).wrapping_sub(S_BASE);
+	let t = (second as u32).wrapping_sub(T_BASE);
+	if s < S_COUNT && s.is_multiple_of(T_COUNT) && t > 0 && t < T_COUNT {
+		return char::from_u32(first as u32 + t);
+	}
+	COMPOSITIONS
+		.binary_search_by_key(&(first, second), |&(first, second, _)| (first, second))
+		.ok()
+		.map(|i| COMPOSITIONS[i].2)
+}
+
+/// Composes decomposed characters, skipping characters blocked from their starter
+fn compose(chars: Vec<char>) -> String {
+	let mut out: Vec<char> = Vec::with_capacity(chars.len());
+	let mut starter: Option<usize> = None;
+	// Class of last character pushed after starter
+	let mut last_class = 0;
+	for c in chars {
+		let class = combining_class(c);
+		if let Some(starter) = starter {
+			let adjacent = starter + 1 == out.len();
+			if adjacent || last_class < class {
+				if let Some(composed) = compose_pair(out[starter], c) {
+					out[starter] = composed;
+					continue;
+				}
+			}
+		}
+		if class == 0 {
+			starter = Some(out.len());
+		}
+		last_class = class;
+		out.push(c);
+	}
+	out.into_iter().collect()
+}
+
+/// Converts string to Normalization Form D
+pub(crate) fn nfd(string: &str) -> String {
+	decompose(string).into_iter().collect()
+}
+
+/// Converts string to Normalization Form C
+pub(crate) fn nfc(string: &str) -> String {
+	compose(decompose(string))
+}
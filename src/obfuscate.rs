@@ -0,0 +1,37 @@
+//! Obfuscation of strings, so their plaintext is not present in binary, used by `real_c_string_obf!`
+//!
+//! Keys are derived from FNV-1a hash of crate-level seed, followed by little-endian bytes of code units
+//! of string. Seed is read from `REAL_C_STRING_SEED` environment variable at expansion time, and is empty
+//! if it is not set, so the same source always produces the same binary.
+
+use crate::hash;
+
+/// Environment variable, from which seed is read
+pub(crate) const SEED_VAR: &str = "REAL_C_STRING_SEED";
+
+/// Returns key for string with passed code units, derived from them and seed, so expansion is reproducible
+///
+/// Every byte of key is non-zero, so every code unit is changed by it.
+pub(crate) fn key(units: &[u32]) -> u32 {
+	let mut bytes = std::env::var(SEED_VAR).unwrap_or_default().into_bytes();
+	bytes.extend(units.iter().flat_map(|unit| unit.to_le_bytes()));
+	u32::from_le_bytes(
+		hash::fnv1a32(&bytes)
+			.to_le_bytes()
+			.map(|byte| if byte == 0 { 0xa5 } else { byte }),
+	)
+}
+
+/// Returns indices `0..len` in order, shuffled deterministically by passed seed
+pub(crate) fn shuffle(len: usize, seed: u32) -> Vec<usize> {
+	let mut indices: Vec<usize> = (0..len).collect();
+	// xorshift32 never leaves zero state, so it's avoided
+	let mut state = seed | 1;
+	for i in (1..len).rev() {
+		state ^= state << 13;
+		state ^= state >> 17;
+		state ^= state << 5;
+		indices.swap(i, state as usize % (i + 1));
+	}
+	indices
+}
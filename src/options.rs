@@ -0,0 +1,384 @@
+//! Options, which may be passed before string literal, i.e `real_c_string!(no_nul "TAG!")`
+//!
+//! Every option is either a flag (`no_nul`), or has a value (`max_len = 32`), and may be followed by a comma.
+
+use crate::encoding::{self, CodePage};
+use syn::{
+	parse::{Parse, ParseStream, Result},
+	Token,
+};
+
+/// Line ending, to which every line ending in string is normalized, `newlines = "crlf"`
+#[derive(Clone, Copy)]
+pub(crate) enum Newlines {
+	Lf,
+	Crlf,
+}
+
+impl Newlines {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Lf => "\n",
+			Self::Crlf => "\r\n",
+		}
+	}
+}
+
+/// Unicode normalization form, to which strings are converted, `normalize = "nfc"`
+#[cfg(feature = "normalization")]
+#[derive(Clone, Copy)]
+pub(crate) enum Normalization {
+	Nfc,
+	Nfd,
+}
+
+/// Case, to which every character of string is converted, `upper` or `lower`
+#[derive(Clone, Copy)]
+pub(crate) enum Case {
+	Upper,
+	Lower,
+}
+
+/// Set of characters, strings are restricted to, `ascii` or `ascii_whitespace`
+#[derive(Clone, Copy)]
+pub(crate) enum Ascii {
+	/// Only printable characters, `0x20..=0x7e`
+	Printable,
+	/// Printable characters, tabs and line endings
+	Whitespace,
+}
+
+impl Ascii {
+	/// Returns true if character is allowed, NUL is always allowed, as it is checked separately
+	pub fn allows(&self, c: char) -> bool {
+		match c {
+			'\0' | ' '..='~' => true,
+			'\t' | '\r' | '\n' => matches!(self, Self::Whitespace),
+			_ => false,
+		}
+	}
+}
+
+/// Check of `printf` conversion specifiers in string, `printf_safe` or `printf_format`
+#[derive(Clone, Copy)]
+pub(crate) enum Printf {
+	/// String is not a format, and can't contain specifiers other than `%%`
+	Safe,
+	/// String is a format, and its specifiers should be valid
+	Format,
+}
+
+/// Options shared by every macro, macros ignore options which make no sense for them
+#[derive(Clone)]
+pub(crate) struct Options {
+	/// Whether terminating zero is appended, `no_nul` disables it
+	pub terminated: bool,
+	/// Number of terminating zeros appended, if string is terminated, `terminators = 2`
+	pub terminators: usize,
+	/// Code unit appended as terminator, `terminator = 0xff`
+	pub terminator: u32,
+	/// Whether terminator may appear inside of string, `allow_terminator_in_content`
+	pub allow_terminator_in_content: bool,
+	/// Whether NUL is appended to strings, which are not terminated by default, `with_nul`
+	pub with_nul: bool,
+	/// Max size of resulting array in code units including terminator, `max_len = 32`
+	pub max_len: Option<usize>,
+	/// Whether common leading whitespace is removed from every line, `dedent`
+	pub dedent: bool,
+	/// Whether `${NAME}` is replaced with value of environment variable, `interpolate`
+	pub interpolate: bool,
+	/// Whether comments are removed from C-like source, and whitespace is collapsed, `strip_comments`
+	pub strip_comments: bool,
+	/// Whether trailing whitespace is removed from every line, `trim_end`
+	pub trim_end: bool,
+	/// Whether empty lines are skipped by macros splitting string into lines, `skip_empty`
+	pub skip_empty: bool,
+	/// Line ending to normalize line endings to
+	pub newlines: Option<Newlines>,
+	/// Characters strings are restricted to
+	pub ascii: Option<Ascii>,
+	/// Check of printf conversion specifiers
+	pub printf: Option<Printf>,
+	/// Case to convert strings to
+	pub case: Option<Case>,
+	/// Normalization form to convert strings to
+	#[cfg(feature = "normalization")]
+	pub normalize: Option<Normalization>,
+	/// Whether non-ASCII characters are transliterated to ASCII, `translit`
+	#[cfg(feature = "transliteration")]
+	pub translit: bool,
+	/// Character replacing ones, which can't be stored, instead of failing, `lossy` or `replacement = '*'`
+	pub lossy: Option<char>,
+	/// Whether NUL characters are allowed inside of string, `allow_interior_nul`
+	pub allow_interior_nul: bool,
+	/// Whether bidirectional control and invisible characters are allowed, `allow_invisible`
+	pub allow_invisible: bool,
+	/// Whether narrow strings are encoded as UTF-8 instead of one byte per character, `utf8`
+	pub utf8: bool,
+	/// Whether wide strings reject characters above `U+FFFF` instead of using surrogate pairs, `ucs2`
+	pub ucs2: bool,
+	/// Whether code units of wide strings are stored in big-endian order, `big_endian`
+	pub big_endian: bool,
+	/// Whether byte order mark is prepended to wide strings, `bom`
+	pub bom: bool,
+	/// Whether byte strings are pairs of bytes of UTF-16LE code units, `utf16le`
+	pub utf16le: bool,
+	/// Whether code units of `utf16le` byte strings are not checked to form valid UTF-16, `raw`
+	pub raw: bool,
+	/// Whether `\\?\` prefix is added to Windows paths, `long_path`
+	pub long_path: bool,
+	/// Code page, in which narrow strings are encoded, `encoding = "windows-1251"`
+	pub code_page: Option<&'static CodePage>,
+	/// Symbol name of static, in which string is stored, `symbol = "marker"`
+	pub symbol: Option<String>,
+	/// Link section of static, in which string is stored, `section = ".vmp1"`
+	pub section: Option<String>,
+	/// Alignment of static, in which string is stored, `align = 16`
+	pub align: Option<u32>,
+	/// Whether static, in which string is stored, is kept by linker even if unused, `keep`
+	pub keep: bool,
+	/// Whether string is stored in static, unique to invocation, `addr_stable`
+	pub addr_stable: bool,
+	/// Whether string is stored in static, shared by invocations of crate with the same content, `dedup`
+	pub dedup: bool,
+	/// Type declared by `real_c_sync_ptr`, wrapping produced pointer, `sync = CStrPtr`
+	pub sync: Option<syn::Path>,
+	/// Whether string is not added to registry of `registry` feature, `no_register`
+	pub no_register: bool,
+}
+
+impl Default for Options {
+	fn default() -> Self {
+		Self {
+			terminated: true,
+			terminators: 1,
+			terminator: 0,
+			allow_terminator_in_content: false,
+			with_nul: false,
+			max_len: None,
+			dedent: false,
+			interpolate: false,
+			strip_comments: false,
+			trim_end: false,
+			skip_empty: false,
+			newlines: None,
+			case: None,
+			printf: None,
+			ascii: None,
+			#[cfg(feature = "normalization")]
+			normalize: None,
+			#[cfg(feature = "transliteration")]
+			translit: false,
+			lossy: None,
+			allow_interior_nul: false,
+			allow_invisible: false,
+			utf8: false,
+			ucs2: false,
+			big_endian: false,
+			bom: false,
+			utf16le: false,
+			raw: false,
+			long_path: false,
+			code_page: None,
+			symbol: None,
+			section: None,
+			align: None,
+			keep: false,
+			addr_stable: false,
+			dedup: false,
+			sync: None,
+			no_register: false,
+		}
+	}
+}
+
+impl Options {
+	/// Returns true if next tokens are an option, and not a builtin macro invocation or `raw(...)` code units
+	fn peek(input: ParseStream) -> bool {
+		input.peek(syn::Ident)
+			&& !input.peek2(Token![!])
+			&& !input.peek2(Token![::])
+			&& !input.peek2(syn::token::Paren)
+	}
+
+	/// Parses value of option, i.e `= 32`
+	fn value<T: Parse>(input: ParseStream) -> Result<T> {
+		input.parse::<Token![=]>()?;
+		input.parse()
+	}
+
+	/// Finds code page by label, i.e `"windows-1251"`
+	pub fn code_page(label: &syn::LitStr) -> Result<&'static CodePage> {
+		encoding::find(&label.value()).ok_or_else(|| {
+			let message = match encoding::find_multi_byte(&label.value()) {
+				Some(name) => format!(
+					"multi-byte encoding `{}` is not supported, supported encodings are single-byte: {}",
+					name,
+					encoding::names()
+				),
+				None => format!(
+					"unknown encoding `{}`, supported encodings are: {}, multi-byte Shift_JIS, GBK and Big5 are not supported",
+					label.value(),
+					encoding::names()
+				),
+			};
+			syn::Error::new(label.span(), message)
+		})
+	}
+
+	/// Checks that symbol name is accepted by linkers, i.e `"vmp_marker_begin"`
+	pub fn symbol(name: &syn::LitStr) -> Result<String> {
+		let value = name.value();
+		let mut chars = value.chars();
+		let valid_start = chars
+			.next()
+			.is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '.' || c == '$');
+		if !valid_start
+			|| !chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '$')
+		{
+			return Err(syn::Error::new(
+				name.span(),
+				format!(
+					"`{}` is not a valid symbol name, expected ASCII letters, digits, `_`, `.` or `$`, not starting with digit",
+					value
+				),
+			));
+		}
+		Ok(value)
+	}
+
+	/// Parses options until first non-option token
+	pub fn parse(input: ParseStream) -> Result<Self> {
+		Self::parse_with(input, Self::default())
+	}
+
+	/// Same as `parse`, but parsed options override passed ones instead of defaults
+	pub fn parse_with(input: ParseStream, mut options: Self) -> Result<Self> {
+		while Self::peek(input) {
+			let name: syn::Ident = input.parse()?;
+			match name.to_string().as_str() {
+				"no_nul" => options.terminated = false,
+				"with_nul" => options.with_nul = true,
+				"dedent" => options.dedent = true,
+				"interpolate" => options.interpolate = true,
+				"trim_end" => options.trim_end = true,
+				"strip_comments" => options.strip_comments = true,
+				"skip_empty" => options.skip_empty = true,
+				"allow_interior_nul" => options.allow_interior_nul = true,
+				"allow_invisible" => options.allow_invisible = true,
+				"allow_terminator_in_content" => options.allow_terminator_in_content = true,
+				"terminator" => {
+					options.terminator = Self::value::<syn::LitInt>(input)?.base10_parse()?;
+				}
+				"utf8" => options.utf8 = true,
+				"lossy" => {
+					options.lossy.get_or_insert('?');
+				}
+				"replacement" => options.lossy = Some(Self::value::<syn::LitChar>(input)?.value()),
+				"ascii" => options.ascii = Some(Ascii::Printable),
+				"ascii_whitespace" => options.ascii = Some(Ascii::Whitespace),
+				"printf_safe" => options.printf = Some(Printf::Safe),
+				"printf_format" => options.printf = Some(Printf::Format),
+				"upper" => options.case = Some(Case::Upper),
+				"lower" => options.case = Some(Case::Lower),
+				"ucs2" => options.ucs2 = true,
+				"big_endian" => options.big_endian = true,
+				"bom" => options.bom = true,
+				"utf16le" => options.utf16le = true,
+				"raw" => options.raw = true,
+				"long_path" => options.long_path = true,
+				"keep" => options.keep = true,
+				"addr_stable" => options.addr_stable = true,
+				"dedup" => options.dedup = true,
+				"sync" => options.sync = Some(Self::value(input)?),
+				"no_register" => options.no_register = true,
+				"terminators" => {
+					let terminators: syn::LitInt = Self::value(input)?;
+					options.terminators = match terminators.base10_parse()? {
+						0 => {
+							return Err(syn::Error::new(
+								terminators.span(),
+								"at least one terminator is appended, use `no_nul` to omit it",
+							))
+						}
+						value => value,
+					};
+				}
+				"max_len" => {
+					options.max_len = Some(Self::value::<syn::LitInt>(input)?.base10_parse()?);
+				}
+				"ebcdic" => options.code_page = encoding::find("ibm1047"),
+				"encoding" => options.code_page = Some(Self::code_page(&Self::value(input)?)?),
+				"symbol" => options.symbol = Some(Self::symbol(&Self::value(input)?)?),
+				"section" => options.section = Some(Self::value::<syn::LitStr>(input)?.value()),
+				"align" => {
+					let align: syn::LitInt = Self::value(input)?;
+					// Max alignment supported by rustc
+					options.align = match align.base10_parse::<u32>()? {
+						value if value.is_power_of_two() && value <= 1 << 29 => Some(value),
+						_ => {
+							return Err(syn::Error::new(
+								align.span(),
+								"alignment should be a power of two, not greater than 2^29",
+							))
+						}
+					};
+				}
+				"newlines" => {
+					let value: syn::LitStr = Self::value(input)?;
+					options.newlines = Some(match value.value().as_str() {
+						"lf" => Newlines::Lf,
+						"crlf" => Newlines::Crlf,
+						_ => {
+							return Err(syn::Error::new(
+								value.span(),
+								"expected \"lf\" or \"crlf\"",
+							))
+						}
+					});
+				}
+				#[cfg(feature = "transliteration")]
+				"translit" => options.translit = true,
+				#[cfg(not(feature = "transliteration"))]
+				"translit" => {
+					return Err(syn::Error::new(
+						name.span(),
+						"`translit` option requires `transliteration` feature of real_c_string",
+					))
+				}
+				#[cfg(feature = "normalization")]
+				"normalize" => {
+					let value: syn::LitStr = Self::value(input)?;
+					options.normalize = Some(match value.value().as_str() {
+						"nfc" => Normalization::Nfc,
+						"nfd" => Normalization::Nfd,
+						_ => {
+							return Err(syn::Error::new(
+								value.span(),
+								"expected \"nfc\" or \"nfd\"",
+							))
+						}
+					});
+				}
+				#[cfg(not(feature = "normalization"))]
+				"normalize" => {
+					return Err(syn::Error::new(
+						name.span(),
+						"`normalize` option requires `normalization` feature of real_c_string",
+					))
+				}
+				_ => {
+					return Err(syn::Error::new(
+						name.span(),
+						format!(
+							"unknown option `{}`, options are followed by string literals, i.e `no_nul \"TAG\"`",
+							name
+						),
+					))
+				}
+			}
+			input.parse::<Option<Token![,]>>()?;
+		}
+		Ok(options)
+	}
+}
@@ -0,0 +1,64 @@
+//! Conversion and validation of Windows paths, used by `real_c_wpath!`
+
+/// Characters, which can't be used in segments of Win32 paths
+const RESERVED_CHARS: &str = "<>:\"|?*";
+
+/// Device names, which can't be used as file names, even with extension
+const RESERVED_NAMES: &[&str] = &[
+	"CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+	"COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Prefix of paths, which bypass `MAX_PATH` limit
+const LONG_PATH_PREFIX: &str = "\\\\?\\";
+
+/// Converts forward slashes to backslashes, validates every segment, and optionally adds long path prefix
+///
+/// Returns error message naming offending character or segment.
+pub(crate) fn to_windows(path: &str, long_path: bool) -> Result<String, String> {
+	let path = path.replace('/', "\\");
+	let (prefixed, path) = match path.strip_prefix(LONG_PATH_PREFIX) {
+		Some(path) => (true, path),
+		None => (false, path.as_str()),
+	};
+	let bytes = path.as_bytes();
+	let has_drive = bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':';
+	let rest = if has_drive { &path[2..] } else { path };
+	for segment in rest.split('\\') {
+		if let Some(c) = segment
+			.chars()
+			.find(|&c| RESERVED_CHARS.contains(c) || c.is_ascii_control())
+		{
+			return Err(format!(
+				"Character {:?} is not allowed in Windows paths, found in segment \"{}\"",
+				c, segment
+			));
+		}
+		let stem = segment.split('.').next().unwrap_or_default().trim_end();
+		if RESERVED_NAMES
+			.iter()
+			.any(|name| name.eq_ignore_ascii_case(stem))
+		{
+			return Err(format!(
+				"Segment \"{}\" is reserved device name in Windows paths",
+				segment
+			));
+		}
+	}
+	if prefixed {
+		return Ok(format!("{}{}", LONG_PATH_PREFIX, path));
+	}
+	if !long_path {
+		return Ok(path.to_owned());
+	}
+	if let Some(share) = path.strip_prefix("\\\\") {
+		Ok(format!("{}UNC\\{}", LONG_PATH_PREFIX, share))
+	} else if has_drive && rest.starts_with('\\') {
+		Ok(format!("{}{}", LONG_PATH_PREFIX, path))
+	} else {
+		Err(format!(
+			"`long_path` requires absolute path with drive or UNC share, found \"{}\"",
+			path
+		))
+	}
+}
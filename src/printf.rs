@@ -0,0 +1,117 @@
+//! Compile-time checks of `printf` conversion specifiers, enabled by `printf_safe` and `printf_format` options
+
+/// Conversion specifier, i.e `%-08.3lf`
+struct Specifier {
+	/// Offset of `%` in string
+	offset: usize,
+	/// Text of specifier, including `%`
+	text: String,
+	/// Conversion character, `None` if string ends inside of specifier
+	conversion: Option<char>,
+	/// Whether argument is referenced by position, i.e `%1$s`
+	positional: bool,
+}
+
+const FLAGS: &str = "-+ #0'";
+const LENGTH_MODIFIERS: &str = "hljztLq";
+const CONVERSIONS: &str = "diouxXeEfFgGaAcspn";
+
+/// Returns every specifier in string, except for `%%`
+fn specifiers(string: &str) -> Vec<Specifier> {
+	let chars: Vec<char> = string.chars().collect();
+	let mut out = Vec::new();
+	let mut i = 0;
+	while i < chars.len() {
+		if chars[i] != '%' {
+			i += 1;
+			continue;
+		}
+		if chars.get(i + 1) == Some(&'%') {
+			i += 2;
+			continue;
+		}
+		let start = i;
+		i += 1;
+		let take = |i: &mut usize, allowed: &dyn Fn(char) -> bool| {
+			while *i < chars.len() && allowed(chars[*i]) {
+				*i += 1;
+			}
+		};
+		// Position is written the same way as width, so it is only known after `$`
+		let digits_end = {
+			let mut j = i;
+			take(&mut j, &|c| c.is_ascii_digit());
+			j
+		};
+		let positional = digits_end > i && chars.get(digits_end) == Some(&'$');
+		if positional {
+			i = digits_end + 1;
+		}
+		take(&mut i, &|c| FLAGS.contains(c));
+		take(&mut i, &|c| c.is_ascii_digit() || c == '*');
+		if chars.get(i) == Some(&'.') {
+			i += 1;
+			take(&mut i, &|c| c.is_ascii_digit() || c == '*');
+		}
+		take(&mut i, &|c| LENGTH_MODIFIERS.contains(c));
+		let conversion = chars.get(i).copied();
+		if conversion.is_some() {
+			i += 1;
+		}
+		out.push(Specifier {
+			offset: start,
+			text: chars[start..i].iter().collect(),
+			conversion,
+			positional,
+		});
+	}
+	out
+}
+
+/// Checks that string contains no specifiers consuming arguments, only `%%` is allowed
+pub(crate) fn check_safe(string: &str) -> Result<(), String> {
+	match specifiers(string).first() {
+		Some(specifier) => Err(format!(
+			"Format specifier `{}` at offset {} consumes argument, use `%%` for literal percent sign",
+			specifier.text, specifier.offset
+		)),
+		None => Ok(()),
+	}
+}
+
+/// Checks that every specifier is complete, known, not `%n`, and positional arguments are not mixed with others
+pub(crate) fn check_format(string: &str) -> Result<(), String> {
+	let specifiers = specifiers(string);
+	for specifier in &specifiers {
+		match specifier.conversion {
+			None => {
+				return Err(format!(
+					"Incomplete format specifier `{}` at offset {}",
+					specifier.text, specifier.offset
+				))
+			}
+			Some('n') => {
+				return Err(format!(
+					"Format specifier `{}` at offset {} writes to memory, and is not allowed",
+					specifier.text, specifier.offset
+				))
+			}
+			Some(c) if !CONVERSIONS.contains(c) => {
+				return Err(format!(
+					"Unknown conversion `{}` at offset {}",
+					specifier.text, specifier.offset
+				))
+			}
+			Some(_) => {}
+		}
+	}
+	if let Some(first) = specifiers.first() {
+		if let Some(mixed) = specifiers.iter().find(|s| s.positional != first.positional) {
+			return Err(format!(
+				"Format specifier `{}` at offset {} mixes positional and sequential arguments",
+				mixed.text, mixed.offset
+			));
+		}
+	}
+	Ok(())
+}
@@ -0,0 +1,147 @@
+//! Registry of strings, embedded by macros returning pointers, used with `registry` feature
+//!
+//! Every invocation places descriptor of its string into the same link section, and linker concatenates
+//! descriptors of every crate into one array, so strings are enumerated without scanning the binary.
+//! ELF linkers define `__start_` and `__stop_` symbols for sections named as C identifiers, Mach-O linker
+//! defines `section$start$` and `section$end$` ones, and MSVC linker sorts sections by suffix after `$`,
+//! so array is delimited by empty statics there, same as `linkme` does.
+
+use crate::{encoding_label, options::Options, TransformType};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Section on ELF targets, it should be a C identifier, so bounds are defined by linker
+const ELF_SECTION: &str = "real_c_string_registry";
+/// Section on Mach-O targets, name of section is limited to 16 characters
+const MACHO_SECTION: &str = "__DATA,__rcs_registry,regular,no_dead_strip";
+const MACHO_START: &str = "\x01section$start$__DATA$__rcs_registry";
+const MACHO_END: &str = "\x01section$end$__DATA$__rcs_registry";
+/// Sections on Windows targets, descriptors are placed between start and end markers
+const WINDOWS_START: &str = ".rcsreg$a";
+const WINDOWS_SECTION: &str = ".rcsreg$b";
+const WINDOWS_END: &str = ".rcsreg$c";
+
+/// Returns condition of ELF targets, on which linker defines bounds of section
+fn elf() -> TokenStream {
+	quote! {
+		any(
+			target_os = "linux",
+			target_os = "android",
+			target_os = "freebsd",
+			target_os = "netbsd",
+			target_os = "openbsd",
+			target_os = "dragonfly",
+			target_os = "illumos",
+			target_os = "fuchsia",
+		)
+	}
+}
+
+/// Returns declaration of descriptor, which is `repr(C)`, so separately declared types have the same layout
+fn descriptor() -> TokenStream {
+	quote! {
+		#[repr(C)]
+		struct Descriptor {
+			string: *const u8,
+			string_len: usize,
+			encoding: *const u8,
+			encoding_len: usize,
+			module: *const u8,
+			module_len: usize,
+		}
+		// Descriptors only point to immutable data, which lives as long as the program
+		unsafe impl ::core::marker::Sync for Descriptor {}
+	}
+}
+
+/// Returns items, which add string at passed pointer to registry, data type is type of the whole string
+pub(crate) fn register(
+	options: &Options,
+	transform_type: TransformType,
+	pointer: &TokenStream,
+	data_type: &TokenStream,
+) -> TokenStream {
+	if options.no_register {
+		return quote! {};
+	}
+	let elf = elf();
+	let descriptor = descriptor();
+	let encoding = encoding_label(options, transform_type);
+	quote! {
+		#descriptor
+		#[cfg_attr(#elf, unsafe(link_section = #ELF_SECTION))]
+		#[cfg_attr(target_vendor = "apple", unsafe(link_section = #MACHO_SECTION))]
+		#[cfg_attr(windows, unsafe(link_section = #WINDOWS_SECTION))]
+		#[used]
+		static REGISTERED: Descriptor = Descriptor {
+			string: #pointer as *const u8,
+			string_len: ::core::mem::size_of::<#data_type>(),
+			encoding: #encoding.as_ptr(),
+			encoding_len: #encoding.len(),
+			module: ::core::module_path!().as_ptr(),
+			module_len: ::core::module_path!().len(),
+		};
+	}
+}
+
+/// Returns expression iterating over registered strings
+pub(crate) fn iterate() -> TokenStream {
+	let elf = elf();
+	let descriptor = descriptor();
+	quote! {
+		unsafe {
+			#descriptor
+			#[cfg(not(any(#elf, target_vendor = "apple", windows)))]
+			::core::compile_error!("Registry of strings is only supported on ELF, Mach-O and Windows targets");
+			#[cfg(#elf)]
+			unsafe extern "C" {
+				#[link_name = ::core::concat!("__start_", #ELF_SECTION)]
+				static START: [Descriptor; 0];
+				#[link_name = ::core::concat!("__stop_", #ELF_SECTION)]
+				static END: [Descriptor; 0];
+			}
+			#[cfg(target_vendor = "apple")]
+			unsafe extern "C" {
+				#[link_name = #MACHO_START]
+				static START: [Descriptor; 0];
+				#[link_name = #MACHO_END]
+				static END: [Descriptor; 0];
+			}
+			#[cfg(windows)]
+			#[unsafe(link_section = #WINDOWS_START)]
+			#[used]
+			static START: [Descriptor; 0] = [];
+			#[cfg(windows)]
+			#[unsafe(link_section = #WINDOWS_END)]
+			#[used]
+			static END: [Descriptor; 0] = [];
+			// Section is created even if no string is registered, so its bounds are defined
+			#[cfg(not(windows))]
+			#[cfg_attr(#elf, unsafe(link_section = #ELF_SECTION))]
+			#[cfg_attr(target_vendor = "apple", unsafe(link_section = #MACHO_SECTION))]
+			#[used]
+			static EMPTY: [Descriptor; 0] = [];
+
+			let start = ::core::ptr::addr_of!(START) as *const Descriptor;
+			let end = ::core::ptr::addr_of!(END) as *const Descriptor;
+			let len = (end as usize - start as usize) / ::core::mem::size_of::<Descriptor>();
+			::core::slice::from_raw_parts(start, len)
+				.iter()
+				// MSVC linker may pad contributions to section with zeroes
+				.filter(|descriptor| !descriptor.string.is_null())
+				.map(|descriptor| {
+					(
+						::core::slice::from_raw_parts(descriptor.string, descriptor.string_len),
+						::core::str::from_utf8_unchecked(::core::slice::from_raw_parts(
+							descriptor.encoding,
+							descriptor.encoding_len,
+						)),
+						::core::str::from_utf8_unchecked(::core::slice::from_raw_parts(
+							descriptor.module,
+							descriptor.module_len,
+						)),
+					)
+				})
+		}
+	}
+}
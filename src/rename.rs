@@ -0,0 +1,93 @@
+//! Case conversion of enum variant names, used by `rename_all` of `#[derive(RealCStringNames)]`
+
+/// Case, to which names are converted, named the same way as in serde
+#[derive(Clone, Copy)]
+pub(crate) enum RenameAll {
+	Lower,
+	Upper,
+	Pascal,
+	Camel,
+	Snake,
+	ScreamingSnake,
+	Kebab,
+	ScreamingKebab,
+}
+
+impl RenameAll {
+	/// Supported cases, in the same form as they are passed to `rename_all`
+	pub(crate) const NAMES: &'static [(&'static str, RenameAll)] = &[
+		("lowercase", RenameAll::Lower),
+		("UPPERCASE", RenameAll::Upper),
+		("PascalCase", RenameAll::Pascal),
+		("camelCase", RenameAll::Camel),
+		("snake_case", RenameAll::Snake),
+		("SCREAMING_SNAKE_CASE", RenameAll::ScreamingSnake),
+		("kebab-case", RenameAll::Kebab),
+		("SCREAMING-KEBAB-CASE", RenameAll::ScreamingKebab),
+	];
+
+	/// Returns case with passed name, i.e `snake_case`
+	pub(crate) fn find(name: &str) -> Option<Self> {
+		Self::NAMES
+			.iter()
+			.find(|(case, _)| *case == name)
+			.map(|&(_, case)| case)
+	}
+
+	/// Converts variant name, which is expected to be in `PascalCase`, i.e `NotFound`
+	pub(crate) fn apply(self, name: &str) -> String {
+		let words = words(name);
+		let joined = |separator: &str, upper: bool| {
+			let words: Vec<_> = words
+				.iter()
+				.map(|word| {
+					if upper {
+						word.to_uppercase()
+					} else {
+						word.to_lowercase()
+					}
+				})
+				.collect();
+			words.join(separator)
+		};
+		match self {
+			RenameAll::Lower => name.to_lowercase(),
+			RenameAll::Upper => name.to_uppercase(),
+			RenameAll::Pascal => name.to_owned(),
+			RenameAll::Camel => {
+				let mut chars = name.chars();
+				chars
+					.next()
+					.map(|first| first.to_lowercase().chain(chars).collect())
+					.unwrap_or_default()
+			}
+			RenameAll::Snake => joined("_", false),
+			RenameAll::ScreamingSnake => joined("_", true),
+			RenameAll::Kebab => joined("-", false),
+			RenameAll::ScreamingKebab => joined("-", true),
+		}
+	}
+}
+
+/// Splits name into words, which start at uppercase letters, i.e `HttpOk2` into `Http` and `Ok2`
+///
+/// Existing `_` separate words too, and are not included in them.
+fn words(name: &str) -> Vec<String> {
+	let mut words: Vec<String> = Vec::new();
+	let mut current = String::new();
+	for c in name.chars() {
+		if c == '_' || (c.is_uppercase() && !current.is_empty()) {
+			if !current.is_empty() {
+				words.push(std::mem::take(&mut current));
+			}
+			if c == '_' {
+				continue;
+			}
+		}
+		current.push(c);
+	}
+	if !current.is_empty() {
+		words.push(current);
+	}
+	words
+}
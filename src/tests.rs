@@ -0,0 +1,283 @@
+//! Snapshots of expansions, which are checked outside of compiler, as transforms work on `proc_macro2`
+
+use super::*;
+
+/// Returns expansion of `real_c_string`-like macro with passed input
+///
+/// Strings are not registered, so snapshots are the same with `registry` feature.
+fn expand(input: &str, transform_type: TransformType) -> String {
+	let mut input: RealCString = syn::parse_str(input).unwrap();
+	input.options.no_register = true;
+	transform(&input, transform_type).to_string()
+}
+
+/// Returns parse error of passed input
+fn parse_error<T: Parse>(input: &str) -> String {
+	match syn::parse_str::<T>(input) {
+		Ok(_) => panic!("{} should fail to parse", input),
+		Err(error) => error.to_string(),
+	}
+}
+
+#[test]
+fn empty_string() {
+	assert_eq!(
+		expand(r#""""#, TransformType::CString),
+		quote! { const { b"\0" as *const [u8; 1usize] as *const i8 } }.to_string()
+	);
+	assert_eq!(
+		expand(r#""""#, TransformType::CWString),
+		quote! { const { &[0u16] as *const [u16; 1usize] as *const i16 } }.to_string()
+	);
+}
+
+#[test]
+fn high_byte_characters() {
+	assert_eq!(
+		expand(r#""\u{ff}""#, TransformType::CString),
+		quote! { const { b"\xFF\0" as *const [u8; 2usize] as *const i8 } }.to_string()
+	);
+	assert_eq!(
+		expand(r#"encoding = "windows-1251", "Л""#, TransformType::CString),
+		quote! { const { b"\xCB\0" as *const [u8; 2usize] as *const i8 } }.to_string()
+	);
+	assert_eq!(
+		expand(r#"utf8 "Л""#, TransformType::CString),
+		quote! { const { b"\xD0\x9B\0" as *const [u8; 3usize] as *const i8 } }.to_string()
+	);
+}
+
+#[test]
+fn wide_encoding() {
+	assert_eq!(
+		expand(r#""A🦀""#, TransformType::CWString),
+		quote! { const { &[65u16, 55358, 56704, 0] as *const [u16; 4usize] as *const i16 } }
+			.to_string()
+	);
+	assert_eq!(
+		expand(r#"utf16le b"\xff\xfeH\x00""#, TransformType::CWString),
+		quote! { const { &[72u16, 0] as *const [u16; 2usize] as *const i16 } }.to_string()
+	);
+}
+
+#[test]
+fn static_options() {
+	assert_eq!(
+		expand(r#"keep "a""#, TransformType::CString),
+		quote! {
+			{
+				#[used]
+				static STRING: [u8; 2usize] = *b"a\0";
+				&STRING as *const [u8; 2usize] as *const i8
+			}
+		}
+		.to_string()
+	);
+}
+
+/// Returns symbol of `dedup` static, which expansion defines or refers to
+fn dedup_symbol(expansion: &str) -> String {
+	let start = expansion.find("\"__real_c_str_").unwrap() + 1;
+	let len = expansion[start..].find('"').unwrap();
+	expansion[start..start + len].to_owned()
+}
+
+#[test]
+fn dedup_symbols() {
+	let first = expand(r#"dedup "dedup_symbols""#, TransformType::CString);
+	let second = expand(r#"dedup "dedup_" "symbols""#, TransformType::CString);
+	// The first invocation defines static, and the second one refers to it
+	assert!(first.contains("export_name"));
+	assert!(second.contains("link_name"));
+	assert_eq!(dedup_symbol(&first), dedup_symbol(&second));
+	assert_ne!(
+		dedup_symbol(&first),
+		dedup_symbol(&expand(r#"dedup "dedup_Symbols""#, TransformType::CString))
+	);
+	// The same bytes in other encoding or of other type are different strings
+	let encoded = expand(
+		r#"dedup, encoding = "windows-1251", "dedup_symbols""#,
+		TransformType::CString,
+	);
+	assert_ne!(dedup_symbol(&first), dedup_symbol(&encoded));
+	let wide = expand(r#"dedup "dedup_symbols""#, TransformType::CWString);
+	assert_ne!(dedup_symbol(&first), dedup_symbol(&wide));
+}
+
+#[test]
+fn unsupported_character() {
+	assert_eq!(
+		expand(r#""Л""#, TransformType::CString),
+		quote! { ::core::compile_error! { "Unsupported character 'Л' (U+041B) at offset 0" } }
+			.to_string()
+	);
+	// Several errors are grouped, so expansion is still a single expression
+	assert_eq!(
+		expand(r#"ascii "Л\t""#, TransformType::CString),
+		quote! {
+			{
+				::core::compile_error! { "Character 'Л' (U+041B) at offset 0 is not printable ASCII" }
+				::core::compile_error! { "Character '\\t' (U+0009) at offset 1 is not printable ASCII" }
+			}
+		}
+		.to_string()
+	);
+	assert_eq!(
+		expand(r#"utf16le b"H""#, TransformType::CWString),
+		quote! {
+			::core::compile_error! { "UTF-16LE byte string has odd length 1, code units are pairs of bytes" }
+		}
+		.to_string()
+	);
+}
+
+#[test]
+fn parse_errors() {
+	assert_eq!(
+		parse_error::<RealCString>(r#"unknown "a""#),
+		r#"unknown option `unknown`, options are followed by string literals, i.e `no_nul "TAG"`"#
+	);
+	assert!(
+		parse_error::<RealCString>(r#"encoding = "gb2312" "a""#).starts_with(
+			"multi-byte encoding `GBK` is not supported, supported encodings are single-byte: "
+		)
+	);
+	assert!(parse_error::<RealCString>(r#"encoding = "utf-7" "a""#)
+		.ends_with(", multi-byte Shift_JIS, GBK and Big5 are not supported"));
+	assert_eq!(
+		parse_error::<RealCString>(r#"strip_comments "a /* b""#),
+		"Unterminated block comment at offset 2"
+	);
+	assert_eq!(
+		parse_error::<RealCStringLookup>(
+			r#"fn f(u32) -> *const i8; 1 => "a", 0x1 => "b", _ => """#
+		),
+		"Duplicate ID 0x1"
+	);
+	assert_eq!(
+		parse_error::<RealCStringLookup>(r#"fn f(u32) -> *const i8; 1 => "a""#),
+		r#"unexpected end of input, expected default `_ => "string"` arm for unmatched IDs"#
+	);
+}
+
+#[test]
+fn char_ranges() {
+	let ranges = |source| diagnostic::char_ranges(source).unwrap();
+	assert_eq!(ranges(r#""ab""#), [1..2, 2..3]);
+	assert_eq!(
+		ranges(r#""a\nЛ\u{202e}\x41""#),
+		[1..2, 2..4, 4..6, 6..14, 14..18]
+	);
+	assert_eq!(ranges("\"a\\\n    b\""), [1..2, 8..9]);
+	assert_eq!(ranges(r###"r#"a\n"#"###), [3..4, 4..5, 5..6]);
+	assert_eq!(ranges(r"'\''"), vec![1..3; 1]);
+	assert_eq!(diagnostic::char_ranges(r#"b"ab""#), None);
+}
+
+#[test]
+fn getter_is_exported() {
+	let getters: RealCStringFns =
+		syn::parse_str(r#"pub extern "C" fn app_version() -> *const i8 = no_register "1.4.2""#)
+			.unwrap();
+	assert_eq!(
+		transform_fns(getters).to_string(),
+		quote! {
+			#[unsafe(no_mangle)]
+			pub extern "C" fn app_version() -> *const i8 {
+				const { b"1.4.2\0" as *const [u8; 6usize] as *const i8 }
+			}
+		}
+		.to_string()
+	);
+	let getters: RealCStringFns = syn::parse_str(
+		r#"#[export_name = "app_name"] fn name() -> *const u16 = no_register "A"; fn b() -> *const u8 = no_register "B";"#,
+	)
+	.unwrap();
+	assert_eq!(
+		transform_fns(getters).to_string(),
+		quote! {
+			#[export_name = "app_name"]
+			extern "C" fn name() -> *const u16 {
+				const { &[65u16, 0] as *const [u16; 2usize] as *const u16 }
+			}
+			#[unsafe(no_mangle)]
+			extern "C" fn b() -> *const u8 {
+				const { b"B\0" as *const [u8; 2usize] as *const u8 }
+			}
+		}
+		.to_string()
+	);
+}
+
+#[cfg(feature = "registry")]
+#[test]
+fn registered_string() {
+	let expansion = expand(r#""a""#, TransformType::CString);
+	let registered = {
+		let input: RealCString = syn::parse_str(r#""a""#).unwrap();
+		transform(&input, TransformType::CString).to_string()
+	};
+	assert!(registered.contains(r#"link_section = "real_c_string_registry""#));
+	assert!(registered.contains(r#"encoding : "iso-8859-1" . as_ptr ()"#));
+	// Pointer is the same, descriptor is added before it
+	assert!(registered.ends_with(&format!("{} }}", expansion)));
+	let opted_out: RealCString = syn::parse_str(r#"no_register "a""#).unwrap();
+	assert_eq!(
+		transform(&opted_out, TransformType::CString).to_string(),
+		expansion
+	);
+}
+
+#[test]
+fn included_file_positions() {
+	let options = Options {
+		strip_comments: true,
+		..Options::default()
+	};
+	let path: syn::LitStr = syn::parse_quote!("tests/data/unsupported.c");
+	let errors = match include_file(&path, options, TransformType::CString) {
+		Ok(_) => panic!("Cyrillic shouldn't fit into narrow string"),
+		Err(errors) => errors
+			.into_iter()
+			.map(|e| e.to_string())
+			.collect::<Vec<_>>(),
+	};
+	// Positions are in file on disk, not in text without comments
+	assert_eq!(errors.len(), 6);
+	assert!(errors[0].starts_with("Unsupported character \"П\" at "));
+	assert!(errors[0].ends_with("unsupported.c:4:25"));
+	assert!(errors[5].ends_with("unsupported.c:4:30"));
+}
+
+#[test]
+fn nonce_is_parsed_outside_of_macro() {
+	let input: NonceRealCString = syn::parse_str("no_nul lower 12").unwrap();
+	let string = input.string("src/main.rs:1:1").unwrap();
+	assert_eq!(string.text().len(), 12);
+	assert!(string
+		.text()
+		.chars()
+		.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()));
+	assert!(!string.options.terminated);
+}
+
+#[test]
+fn included_file_skips_comments() {
+	let options = Options {
+		strip_comments: true,
+		..Options::default()
+	};
+	let path: syn::LitStr = syn::parse_quote!("tests/data/commented.c");
+	let errors = match include_file(&path, options, TransformType::CString) {
+		Ok(_) => panic!("Cyrillic shouldn't fit into narrow string"),
+		Err(errors) => errors
+			.into_iter()
+			.map(|e| e.to_string())
+			.collect::<Vec<_>>(),
+	};
+	// Characters of removed comments aren't reported, and don't shift positions of the rest
+	assert_eq!(errors.len(), 6);
+	assert!(errors[0].starts_with("Unsupported character \"П\" at "));
+	assert!(errors[0].ends_with("commented.c:1:36"));
+	assert!(errors[5].ends_with("commented.c:1:41"));
+}
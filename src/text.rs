@@ -0,0 +1,172 @@
+//! Transformations applied to string literals before encoding, enabled by options
+
+use std::ops::Range;
+
+/// Counts leading spaces and tabs of line, returns `None` for whitespace-only lines
+fn count_indent(line: &str) -> Option<usize> {
+	line.chars().position(|c| c != ' ' && c != '\t')
+}
+
+/// Removes common leading whitespace from every line, the same way `indoc` does it
+///
+/// First line is neither counted nor dedented, as it starts on the same line as opening quote,
+/// and is removed if empty. Spaces and tabs are both counted as a single character of indentation.
+pub(crate) fn dedent(string: &str) -> String {
+	let ignore_first_line = string.starts_with('\n') || string.starts_with("\r\n");
+	let indent = string
+		.lines()
+		.skip(1)
+		.filter_map(count_indent)
+		.min()
+		.unwrap_or(0);
+
+	let mut out = String::with_capacity(string.len());
+	for (i, line) in string.lines().enumerate() {
+		if i > 1 || (i == 1 && !ignore_first_line) {
+			out.push('\n');
+		}
+		if i == 0 {
+			out.push_str(line);
+		} else {
+			// Whitespace-only lines may be shorter than indentation, and indentation is always ASCII
+			out.extend(line.chars().skip(indent));
+		}
+	}
+	if string.ends_with('\n') {
+		out.push('\n');
+	}
+	out
+}
+
+/// Removes trailing whitespace from every line, keeping line endings
+pub(crate) fn trim_line_ends(string: &str) -> String {
+	let mut out = String::with_capacity(string.len());
+	for line in string.split_inclusive('\n') {
+		let content = line.trim_end_matches(['\r', '\n']);
+		out.push_str(content.trim_end());
+		out.push_str(&line[content.len()..]);
+	}
+	out
+}
+
+/// Replaces every line ending, including lone `\r`, with passed one
+pub(crate) fn normalize_newlines(string: &str, newline: &str) -> String {
+	let mut out = String::with_capacity(string.len());
+	let mut chars = string.chars().peekable();
+	while let Some(c) = chars.next() {
+		match c {
+			'\r' => {
+				chars.next_if_eq(&'\n');
+				out.push_str(newline);
+			}
+			'\n' => out.push_str(newline),
+			c => out.push(c),
+		}
+	}
+	out
+}
+
+/// Converts string to uppercase, only ASCII characters are converted without `unicode-case` feature
+pub(crate) fn to_upper(string: &str) -> String {
+	if cfg!(feature = "unicode-case") {
+		string.to_uppercase()
+	} else {
+		string.to_ascii_uppercase()
+	}
+}
+
+/// Converts string to lowercase, only ASCII characters are converted without `unicode-case` feature
+pub(crate) fn to_lower(string: &str) -> String {
+	if cfg!(feature = "unicode-case") {
+		string.to_lowercase()
+	} else {
+		string.to_ascii_lowercase()
+	}
+}
+
+/// Removes `//` and `/* */` comments from C-like source, and collapses whitespace, i.e for embedded shaders
+///
+/// Comments inside of string and character literals are kept. Block comments are replaced with a space, as
+/// in C, runs of whitespace are collapsed to a newline if they contain one, to keep preprocessor directives
+/// on their own lines, or to a space otherwise, and whitespace at the beginning and the end is removed.
+/// Returns error message with offset of unterminated comment or literal.
+pub(crate) fn strip_comments(source: &str) -> Result<String, String> {
+	scan_comments(source, &mut Vec::new())
+}
+
+/// Returns ranges of character offsets of comments, which are removed by `strip_comments`
+pub(crate) fn comment_ranges(source: &str) -> Vec<Range<usize>> {
+	let mut comments = Vec::new();
+	// Unterminated comment and literal are reported by `strip_comments`, ranges found before them are kept
+	let _ = scan_comments(source, &mut comments);
+	comments
+}
+
+/// Implementation of `strip_comments`, pushing character offsets of every removed comment
+fn scan_comments(source: &str, comments: &mut Vec<Range<usize>>) -> Result<String, String> {
+	let mut out = String::with_capacity(source.len());
+	// Whitespace before the next token, true if it contains newline
+	let mut pending: Option<bool> = None;
+	let mut chars = source.chars().enumerate().peekable();
+	while let Some((offset, c)) = chars.next() {
+		match c {
+			'/' if chars.next_if(|&(_, c)| c == '/').is_some() => {
+				let mut end = offset + 2;
+				while let Some((last, _)) = chars.next_if(|&(_, c)| c != '\n') {
+					end = last + 1;
+				}
+				comments.push(offset..end);
+				pending.get_or_insert(false);
+			}
+			'/' if chars.next_if(|&(_, c)| c == '*').is_some() => {
+				let mut closed = false;
+				while let Some((_, c)) = chars.next() {
+					if c == '*' {
+						if let Some((last, _)) = chars.next_if(|&(_, c)| c == '/') {
+							comments.push(offset..last + 1);
+							closed = true;
+							break;
+						}
+					}
+				}
+				if !closed {
+					return Err(format!("Unterminated block comment at offset {}", offset));
+				}
+				pending.get_or_insert(false);
+			}
+			c if c.is_whitespace() => *pending.get_or_insert(false) |= c == '\n',
+			c => {
+				if let Some(newline) = pending.take() {
+					if !out.is_empty() {
+						out.push(if newline { '\n' } else { ' ' });
+					}
+				}
+				out.push(c);
+				if c != '"' && c != '\'' {
+					continue;
+				}
+				// Literals end at the same quote, and can't span lines, escaped quotes don't end them
+				let closed = loop {
+					match chars.next() {
+						Some((_, '\\')) => {
+							out.push('\\');
+							if let Some((_, escaped)) = chars.next_if(|&(_, c)| c != '\n') {
+								out.push(escaped);
+							}
+						}
+						Some((_, '\n')) | None => break false,
+						Some((_, quote)) if quote == c => {
+							out.push(quote);
+							break true;
+						}
+						Some((_, other)) => out.push(other),
+					}
+				};
+				if !closed {
+					return Err(format!("Unterminated literal at offset {}", offset));
+				}
+			}
+		}
+	}
+	Ok(out)
+}
@@ -0,0 +1,66 @@
+//! Build time, used by `real_c_build_timestamp!` and `real_c_build_info!`
+
+use std::{
+	sync::OnceLock,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Variable overriding build time for reproducible builds, as specified by reproducible-builds.org
+pub(crate) const SOURCE_DATE_EPOCH: &str = "SOURCE_DATE_EPOCH";
+
+/// Length of timestamp in RFC 3339 format, i.e `2024-01-31T23:59:59Z`
+pub(crate) const LEN: usize = 20;
+
+/// Last second, which can be formatted in RFC 3339, `9999-12-31T23:59:59Z`
+const MAX_TIME: u64 = 253_402_300_799;
+
+/// Returns seconds since Unix epoch from `SOURCE_DATE_EPOCH`, or current time
+///
+/// Current time is only read once, so every invocation in one compilation gets the same time.
+pub(crate) fn build_time() -> Result<u64, String> {
+	static NOW: OnceLock<u64> = OnceLock::new();
+	match std::env::var(SOURCE_DATE_EPOCH) {
+		Ok(epoch) => match epoch.trim().parse() {
+			Ok(time) if time <= MAX_TIME => Ok(time),
+			_ => Err(format!(
+				"{} should be a number of seconds since Unix epoch before year 10000, found `{}`",
+				SOURCE_DATE_EPOCH, epoch
+			)),
+		},
+		Err(_) => Ok(*NOW.get_or_init(|| {
+			SystemTime::now()
+				.duration_since(UNIX_EPOCH)
+				.map_or(0, |time| time.as_secs())
+		})),
+	}
+}
+
+/// Formats seconds since Unix epoch as UTC time in RFC 3339 format, i.e `2024-01-31T23:59:59Z`
+pub(crate) fn rfc3339(secs: u64) -> String {
+	let days = secs / 86400;
+	let time = secs % 86400;
+	// Converts days to civil date, counting in eras of 400 years, which start on March 1st
+	let days = days + 719_468;
+	let era = days / 146_097;
+	let day_of_era = days % 146_097;
+	let year_of_era =
+		(day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let month_index = (5 * day_of_year + 2) / 153;
+	let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+	let month = if month_index < 10 {
+		month_index + 3
+	} else {
+		month_index - 9
+	};
+	let year = year_of_era + era * 400 + u64::from(month <= 2);
+	format!(
+		"{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+		year,
+		month,
+		day,
+		time / 3600,
+		time / 60 % 60,
+		time % 60
+	)
+}
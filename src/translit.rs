@@ -0,0 +1,159 @@
+//! Transliteration of non-ASCII characters to ASCII, enabled by `translit` option
+//!
+//! Latin letters are transliterated to their base letters, using decompositions generated from Python
+//! `unicodedata`, and Cyrillic and Greek letters are romanized, the same way for every string.
+
+/// Transliterations, sorted by character
+#[rustfmt::skip]
+const TRANSLITERATIONS: &[(char, &str)] = &[
+	('\u{a0}', " "), ('\u{a1}', "!"), ('\u{a2}', "c"), ('\u{a3}', "GBP"), ('\u{a5}', "JPY"), ('\u{a7}', "S"),
+	('\u{a9}', "(c)"), ('\u{ab}', "<<"), ('\u{ad}', ""), ('\u{ae}', "(R)"), ('\u{b0}', "deg"), ('\u{b1}', "+-"),
+	('\u{b2}', "2"), ('\u{b3}', "3"), ('\u{b7}', "*"), ('\u{b9}', "1"), ('\u{bb}', ">>"), ('\u{bc}', "1/4"),
+	('\u{bd}', "1/2"), ('\u{be}', "3/4"), ('\u{bf}', "?"), ('\u{c0}', "A"), ('\u{c1}', "A"), ('\u{c2}', "A"),
+	('\u{c3}', "A"), ('\u{c4}', "A"), ('\u{c5}', "A"), ('\u{c6}', "AE"), ('\u{c7}', "C"), ('\u{c8}', "E"),
+	('\u{c9}', "E"), ('\u{ca}', "E"), ('\u{cb}', "E"), ('\u{cc}', "I"), ('\u{cd}', "I"), ('\u{ce}', "I"),
+	('\u{cf}', "I"), ('\u{d0}', "D"), ('\u{d1}', "N"), ('\u{d2}', "O"), ('\u{d3}', "O"), ('\u{d4}', "O"),
+	('\u{d5}', "O"), ('\u{d6}', "O"), ('\u{d7}', "x"), ('\u{d8}', "O"), ('\u{d9}', "U"), ('\u{da}', "U"),
+	('\u{db}', "U"), ('\u{dc}', "U"), ('\u{dd}', "Y"), ('\u{de}', "Th"), ('\u{df}', "ss"), ('\u{e0}', "a"),
+	('\u{e1}', "a"), ('\u{e2}', "a"), ('\u{e3}', "a"), ('\u{e4}', "a"), ('\u{e5}', "a"), ('\u{e6}', "ae"),
+	('\u{e7}', "c"), ('\u{e8}', "e"), ('\u{e9}', "e"), ('\u{ea}', "e"), ('\u{eb}', "e"), ('\u{ec}', "i"),
+	('\u{ed}', "i"), ('\u{ee}', "i"), ('\u{ef}', "i"), ('\u{f0}', "d"), ('\u{f1}', "n"), ('\u{f2}', "o"),
+	('\u{f3}', "o"), ('\u{f4}', "o"), ('\u{f5}', "o"), ('\u{f6}', "o"), ('\u{f7}', "/"), ('\u{f8}', "o"),
+	('\u{f9}', "u"), ('\u{fa}', "u"), ('\u{fb}', "u"), ('\u{fc}', "u"), ('\u{fd}', "y"), ('\u{fe}', "th"),
+	('\u{ff}', "y"), ('\u{100}', "A"), ('\u{101}', "a"), ('\u{102}', "A"), ('\u{103}', "a"), ('\u{104}', "A"),
+	('\u{105}', "a"), ('\u{106}', "C"), ('\u{107}', "c"), ('\u{108}', "C"), ('\u{109}', "c"), ('\u{10a}', "C"),
+	('\u{10b}', "c"), ('\u{10c}', "C"), ('\u{10d}', "c"), ('\u{10e}', "D"), ('\u{10f}', "d"), ('\u{110}', "D"),
+	('\u{111}', "d"), ('\u{112}', "E"), ('\u{113}', "e"), ('\u{114}', "E"), ('\u{115}', "e"), ('\u{116}', "E"),
+	('\u{117}', "e"), ('\u{118}', "E"), ('\u{119}', "e"), ('\u{11a}', "E"), ('\u{11b}', "e"), ('\u{11c}', "G"),
+	('\u{11d}', "g"), ('\u{11e}', "G"), ('\u{11f}', "g"), ('\u{120}', "G"), ('\u{121}', "g"), ('\u{122}', "G"),
+	('\u{123}', "g"), ('\u{124}', "H"), ('\u{125}', "h"), ('\u{126}', "H"), ('\u{127}', "h"), ('\u{128}', "I"),
+	('\u{129}', "i"), ('\u{12a}', "I"), ('\u{12b}', "i"), ('\u{12c}', "I"), ('\u{12d}', "i"), ('\u{12e}', "I"),
+	('\u{12f}', "i"), ('\u{130}', "I"), ('\u{131}', "i"), ('\u{132}', "IJ"), ('\u{133}', "ij"), ('\u{134}', "J"),
+	('\u{135}', "j"), ('\u{136}', "K"), ('\u{137}', "k"), ('\u{138}', "k"), ('\u{139}', "L"), ('\u{13a}', "l"),
+	('\u{13b}', "L"), ('\u{13c}', "l"), ('\u{13d}', "L"), ('\u{13e}', "l"), ('\u{141}', "L"), ('\u{142}', "l"),
+	('\u{143}', "N"), ('\u{144}', "n"), ('\u{145}', "N"), ('\u{146}', "n"), ('\u{147}', "N"), ('\u{148}', "n"),
+	('\u{149}', "'n"), ('\u{14a}', "N"), ('\u{14b}', "n"), ('\u{14c}', "O"), ('\u{14d}', "o"), ('\u{14e}', "O"),
+	('\u{14f}', "o"), ('\u{150}', "O"), ('\u{151}', "o"), ('\u{152}', "OE"), ('\u{153}', "oe"), ('\u{154}', "R"),
+	('\u{155}', "r"), ('\u{156}', "R"), ('\u{157}', "r"), ('\u{158}', "R"), ('\u{159}', "r"), ('\u{15a}', "S"),
+	('\u{15b}', "s"), ('\u{15c}', "S"), ('\u{15d}', "s"), ('\u{15e}', "S"), ('\u{15f}', "s"), ('\u{160}', "S"),
+	('\u{161}', "s"), ('\u{162}', "T"), ('\u{163}', "t"), ('\u{164}', "T"), ('\u{165}', "t"), ('\u{166}', "T"),
+	('\u{167}', "t"), ('\u{168}', "U"), ('\u{169}', "u"), ('\u{16a}', "U"), ('\u{16b}', "u"), ('\u{16c}', "U"),
+	('\u{16d}', "u"), ('\u{16e}', "U"), ('\u{16f}', "u"), ('\u{170}', "U"), ('\u{171}', "u"), ('\u{172}', "U"),
+	('\u{173}', "u"), ('\u{174}', "W"), ('\u{175}', "w"), ('\u{176}', "Y"), ('\u{177}', "y"), ('\u{178}', "Y"),
+	('\u{179}', "Z"), ('\u{17a}', "z"), ('\u{17b}', "Z"), ('\u{17c}', "z"), ('\u{17d}', "Z"), ('\u{17e}', "z"),
+	('\u{17f}', "s"), ('\u{18f}', "E"), ('\u{192}', "f"), ('\u{1a0}', "O"), ('\u{1a1}', "o"), ('\u{1af}', "U"),
+	('\u{1b0}', "u"), ('\u{1c4}', "DZ"), ('\u{1c5}', "Dz"), ('\u{1c6}', "dz"), ('\u{1c7}', "LJ"), ('\u{1c8}', "Lj"),
+	('\u{1c9}', "lj"), ('\u{1ca}', "NJ"), ('\u{1cb}', "Nj"), ('\u{1cc}', "nj"), ('\u{1cd}', "A"), ('\u{1ce}', "a"),
+	('\u{1cf}', "I"), ('\u{1d0}', "i"), ('\u{1d1}', "O"), ('\u{1d2}', "o"), ('\u{1d3}', "U"), ('\u{1d4}', "u"),
+	('\u{1d5}', "U"), ('\u{1d6}', "u"), ('\u{1d7}', "U"), ('\u{1d8}', "u"), ('\u{1d9}', "U"), ('\u{1da}', "u"),
+	('\u{1db}', "U"), ('\u{1dc}', "u"), ('\u{1de}', "A"), ('\u{1df}', "a"), ('\u{1e0}', "A"), ('\u{1e1}', "a"),
+	('\u{1e6}', "G"), ('\u{1e7}', "g"), ('\u{1e8}', "K"), ('\u{1e9}', "k"), ('\u{1ea}', "O"), ('\u{1eb}', "o"),
+	('\u{1ec}', "O"), ('\u{1ed}', "o"), ('\u{1f0}', "j"), ('\u{1f1}', "DZ"), ('\u{1f2}', "Dz"), ('\u{1f3}', "dz"),
+	('\u{1f4}', "G"), ('\u{1f5}', "g"), ('\u{1f8}', "N"), ('\u{1f9}', "n"), ('\u{1fa}', "A"), ('\u{1fb}', "a"),
+	('\u{200}', "A"), ('\u{201}', "a"), ('\u{202}', "A"), ('\u{203}', "a"), ('\u{204}', "E"), ('\u{205}', "e"),
+	('\u{206}', "E"), ('\u{207}', "e"), ('\u{208}', "I"), ('\u{209}', "i"), ('\u{20a}', "I"), ('\u{20b}', "i"),
+	('\u{20c}', "O"), ('\u{20d}', "o"), ('\u{20e}', "O"), ('\u{20f}', "o"), ('\u{210}', "R"), ('\u{211}', "r"),
+	('\u{212}', "R"), ('\u{213}', "r"), ('\u{214}', "U"), ('\u{215}', "u"), ('\u{216}', "U"), ('\u{217}', "u"),
+	('\u{218}', "S"), ('\u{219}', "s"), ('\u{21a}', "T"), ('\u{21b}', "t"), ('\u{21e}', "H"), ('\u{21f}', "h"),
+	('\u{226}', "A"), ('\u{227}', "a"), ('\u{228}', "E"), ('\u{229}', "e"), ('\u{22a}', "O"), ('\u{22b}', "o"),
+	('\u{22c}', "O"), ('\u{22d}', "o"), ('\u{22e}', "O"), ('\u{22f}', "o"), ('\u{230}', "O"), ('\u{231}', "o"),
+	('\u{232}', "Y"), ('\u{233}', "y"), ('\u{259}', "e"), ('\u{386}', "A"), ('\u{388}', "E"), ('\u{389}', "I"),
+	('\u{38a}', "I"), ('\u{38c}', "O"), ('\u{38e}', "Y"), ('\u{38f}', "O"), ('\u{390}', "i"), ('\u{391}', "A"),
+	('\u{392}', "V"), ('\u{393}', "G"), ('\u{394}', "D"), ('\u{395}', "E"), ('\u{396}', "Z"), ('\u{397}', "I"),
+	('\u{398}', "Th"), ('\u{399}', "I"), ('\u{39a}', "K"), ('\u{39b}', "L"), ('\u{39c}', "M"), ('\u{39d}', "N"),
+	('\u{39e}', "X"), ('\u{39f}', "O"), ('\u{3a0}', "P"), ('\u{3a1}', "R"), ('\u{3a3}', "S"), ('\u{3a4}', "T"),
+	('\u{3a5}', "Y"), ('\u{3a6}', "F"), ('\u{3a7}', "Ch"), ('\u{3a8}', "Ps"), ('\u{3a9}', "O"), ('\u{3aa}', "I"),
+	('\u{3ab}', "Y"), ('\u{3ac}', "a"), ('\u{3ad}', "e"), ('\u{3ae}', "i"), ('\u{3af}', "i"), ('\u{3b0}', "y"),
+	('\u{3b1}', "a"), ('\u{3b2}', "v"), ('\u{3b3}', "g"), ('\u{3b4}', "d"), ('\u{3b5}', "e"), ('\u{3b6}', "z"),
+	('\u{3b7}', "i"), ('\u{3b8}', "th"), ('\u{3b9}', "i"), ('\u{3ba}', "k"), ('\u{3bb}', "l"), ('\u{3bc}', "m"),
+	('\u{3bd}', "n"), ('\u{3be}', "x"), ('\u{3bf}', "o"), ('\u{3c0}', "p"), ('\u{3c1}', "r"), ('\u{3c2}', "s"),
+	('\u{3c3}', "s"), ('\u{3c4}', "t"), ('\u{3c5}', "y"), ('\u{3c6}', "f"), ('\u{3c7}', "ch"), ('\u{3c8}', "ps"),
+	('\u{3c9}', "o"), ('\u{3ca}', "i"), ('\u{3cb}', "y"), ('\u{3cc}', "o"), ('\u{3cd}', "y"), ('\u{3ce}', "o"),
+	('\u{401}', "Yo"), ('\u{404}', "Ye"), ('\u{406}', "I"), ('\u{407}', "Yi"), ('\u{40e}', "U"), ('\u{410}', "A"),
+	('\u{411}', "B"), ('\u{412}', "V"), ('\u{413}', "G"), ('\u{414}', "D"), ('\u{415}', "E"), ('\u{416}', "Zh"),
+	('\u{417}', "Z"), ('\u{418}', "I"), ('\u{419}', "Y"), ('\u{41a}', "K"), ('\u{41b}', "L"), ('\u{41c}', "M"),
+	('\u{41d}', "N"), ('\u{41e}', "O"), ('\u{41f}', "P"), ('\u{420}', "R"), ('\u{421}', "S"), ('\u{422}', "T"),
+	('\u{423}', "U"), ('\u{424}', "F"), ('\u{425}', "Kh"), ('\u{426}', "Ts"), ('\u{427}', "Ch"), ('\u{428}', "Sh"),
+	('\u{429}', "Shch"), ('\u{42a}', ""), ('\u{42b}', "Y"), ('\u{42c}', ""), ('\u{42d}', "E"), ('\u{42e}', "Yu"),
+	('\u{42f}', "Ya"), ('\u{430}', "a"), ('\u{431}', "b"), ('\u{432}', "v"), ('\u{433}', "g"), ('\u{434}', "d"),
+	('\u{435}', "e"), ('\u{436}', "zh"), ('\u{437}', "z"), ('\u{438}', "i"), ('\u{439}', "y"), ('\u{43a}', "k"),
+	('\u{43b}', "l"), ('\u{43c}', "m"), ('\u{43d}', "n"), ('\u{43e}', "o"), ('\u{43f}', "p"), ('\u{440}', "r"),
+	('\u{441}', "s"), ('\u{442}', "t"), ('\u{443}', "u"), ('\u{444}', "f"), ('\u{445}', "kh"), ('\u{446}', "ts"),
+	('\u{447}', "ch"), ('\u{448}', "sh"), ('\u{449}', "shch"), ('\u{44a}', ""), ('\u{44b}', "y"), ('\u{44c}', ""),
+	('\u{44d}', "e"), ('\u{44e}', "yu"), ('\u{44f}', "ya"), ('\u{451}', "yo"), ('\u{454}', "ye"), ('\u{456}', "i"),
+	('\u{457}', "yi"), ('\u{45e}', "u"), ('\u{490}', "G"), ('\u{491}', "g"), ('\u{1e00}', "A"), ('\u{1e01}', "a"),
+	('\u{1e02}', "B"), ('\u{1e03}', "b"), ('\u{1e04}', "B"), ('\u{1e05}', "b"), ('\u{1e06}', "B"), ('\u{1e07}', "b"),
+	('\u{1e08}', "C"), ('\u{1e09}', "c"), ('\u{1e0a}', "D"), ('\u{1e0b}', "d"), ('\u{1e0c}', "D"), ('\u{1e0d}', "d"),
+	('\u{1e0e}', "D"), ('\u{1e0f}', "d"), ('\u{1e10}', "D"), ('\u{1e11}', "d"), ('\u{1e12}', "D"), ('\u{1e13}', "d"),
+	('\u{1e14}', "E"), ('\u{1e15}', "e"), ('\u{1e16}', "E"), ('\u{1e17}', "e"), ('\u{1e18}', "E"), ('\u{1e19}', "e"),
+	('\u{1e1a}', "E"), ('\u{1e1b}', "e"), ('\u{1e1c}', "E"), ('\u{1e1d}', "e"), ('\u{1e1e}', "F"), ('\u{1e1f}', "f"),
+	('\u{1e20}', "G"), ('\u{1e21}', "g"), ('\u{1e22}', "H"), ('\u{1e23}', "h"), ('\u{1e24}', "H"), ('\u{1e25}', "h"),
+	('\u{1e26}', "H"), ('\u{1e27}', "h"), ('\u{1e28}', "H"), ('\u{1e29}', "h"), ('\u{1e2a}', "H"), ('\u{1e2b}', "h"),
+	('\u{1e2c}', "I"), ('\u{1e2d}', "i"), ('\u{1e2e}', "I"), ('\u{1e2f}', "i"), ('\u{1e30}', "K"), ('\u{1e31}', "k"),
+	('\u{1e32}', "K"), ('\u{1e33}', "k"), ('\u{1e34}', "K"), ('\u{1e35}', "k"), ('\u{1e36}', "L"), ('\u{1e37}', "l"),
+	('\u{1e38}', "L"), ('\u{1e39}', "l"), ('\u{1e3a}', "L"), ('\u{1e3b}', "l"), ('\u{1e3c}', "L"), ('\u{1e3d}', "l"),
+	('\u{1e3e}', "M"), ('\u{1e3f}', "m"), ('\u{1e40}', "M"), ('\u{1e41}', "m"), ('\u{1e42}', "M"), ('\u{1e43}', "m"),
+	('\u{1e44}', "N"), ('\u{1e45}', "n"), ('\u{1e46}', "N"), ('\u{1e47}', "n"), ('\u{1e48}', "N"), ('\u{1e49}', "n"),
+	('\u{1e4a}', "N"), ('\u{1e4b}', "n"), ('\u{1e4c}', "O"), ('\u{1e4d}', "o"), ('\u{1e4e}', "O"), ('\u{1e4f}', "o"),
+	('\u{1e50}', "O"), ('\u{1e51}', "o"), ('\u{1e52}', "O"), ('\u{1e53}', "o"), ('\u{1e54}', "P"), ('\u{1e55}', "p"),
+	('\u{1e56}', "P"), ('\u{1e57}', "p"), ('\u{1e58}', "R"), ('\u{1e59}', "r"), ('\u{1e5a}', "R"), ('\u{1e5b}', "r"),
+	('\u{1e5c}', "R"), ('\u{1e5d}', "r"), ('\u{1e5e}', "R"), ('\u{1e5f}', "r"), ('\u{1e60}', "S"), ('\u{1e61}', "s"),
+	('\u{1e62}', "S"), ('\u{1e63}', "s"), ('\u{1e64}', "S"), ('\u{1e65}', "s"), ('\u{1e66}', "S"), ('\u{1e67}', "s"),
+	('\u{1e68}', "S"), ('\u{1e69}', "s"), ('\u{1e6a}', "T"), ('\u{1e6b}', "t"), ('\u{1e6c}', "T"), ('\u{1e6d}', "t"),
+	('\u{1e6e}', "T"), ('\u{1e6f}', "t"), ('\u{1e70}', "T"), ('\u{1e71}', "t"), ('\u{1e72}', "U"), ('\u{1e73}', "u"),
+	('\u{1e74}', "U"), ('\u{1e75}', "u"), ('\u{1e76}', "U"), ('\u{1e77}', "u"), ('\u{1e78}', "U"), ('\u{1e79}', "u"),
+	('\u{1e7a}', "U"), ('\u{1e7b}', "u"), ('\u{1e7c}', "V"), ('\u{1e7d}', "v"), ('\u{1e7e}', "V"), ('\u{1e7f}', "v"),
+	('\u{1e80}', "W"), ('\u{1e81}', "w"), ('\u{1e82}', "W"), ('\u{1e83}', "w"), ('\u{1e84}', "W"), ('\u{1e85}', "w"),
+	('\u{1e86}', "W"), ('\u{1e87}', "w"), ('\u{1e88}', "W"), ('\u{1e89}', "w"), ('\u{1e8a}', "X"), ('\u{1e8b}', "x"),
+	('\u{1e8c}', "X"), ('\u{1e8d}', "x"), ('\u{1e8e}', "Y"), ('\u{1e8f}', "y"), ('\u{1e90}', "Z"), ('\u{1e91}', "z"),
+	('\u{1e92}', "Z"), ('\u{1e93}', "z"), ('\u{1e94}', "Z"), ('\u{1e95}', "z"), ('\u{1e96}', "h"), ('\u{1e97}', "t"),
+	('\u{1e98}', "w"), ('\u{1e99}', "y"), ('\u{1e9b}', "s"), ('\u{1e9e}', "SS"), ('\u{1ea0}', "A"), ('\u{1ea1}', "a"),
+	('\u{1ea2}', "A"), ('\u{1ea3}', "a"), ('\u{1ea4}', "A"), ('\u{1ea5}', "a"), ('\u{1ea6}', "A"), ('\u{1ea7}', "a"),
+	('\u{1ea8}', "A"), ('\u{1ea9}', "a"), ('\u{1eaa}', "A"), ('\u{1eab}', "a"), ('\u{1eac}', "A"), ('\u{1ead}', "a"),
+	('\u{1eae}', "A"), ('\u{1eaf}', "a"), ('\u{1eb0}', "A"), ('\u{1eb1}', "a"), ('\u{1eb2}', "A"), ('\u{1eb3}', "a"),
+	('\u{1eb4}', "A"), ('\u{1eb5}', "a"), ('\u{1eb6}', "A"), ('\u{1eb7}', "a"), ('\u{1eb8}', "E"), ('\u{1eb9}', "e"),
+	('\u{1eba}', "E"), ('\u{1ebb}', "e"), ('\u{1ebc}', "E"), ('\u{1ebd}', "e"), ('\u{1ebe}', "E"), ('\u{1ebf}', "e"),
+	('\u{1ec0}', "E"), ('\u{1ec1}', "e"), ('\u{1ec2}', "E"), ('\u{1ec3}', "e"), ('\u{1ec4}', "E"), ('\u{1ec5}', "e"),
+	('\u{1ec6}', "E"), ('\u{1ec7}', "e"), ('\u{1ec8}', "I"), ('\u{1ec9}', "i"), ('\u{1eca}', "I"), ('\u{1ecb}', "i"),
+	('\u{1ecc}', "O"), ('\u{1ecd}', "o"), ('\u{1ece}', "O"), ('\u{1ecf}', "o"), ('\u{1ed0}', "O"), ('\u{1ed1}', "o"),
+	('\u{1ed2}', "O"), ('\u{1ed3}', "o"), ('\u{1ed4}', "O"), ('\u{1ed5}', "o"), ('\u{1ed6}', "O"), ('\u{1ed7}', "o"),
+	('\u{1ed8}', "O"), ('\u{1ed9}', "o"), ('\u{1eda}', "O"), ('\u{1edb}', "o"), ('\u{1edc}', "O"), ('\u{1edd}', "o"),
+	('\u{1ede}', "O"), ('\u{1edf}', "o"), ('\u{1ee0}', "O"), ('\u{1ee1}', "o"), ('\u{1ee2}', "O"), ('\u{1ee3}', "o"),
+	('\u{1ee4}', "U"), ('\u{1ee5}', "u"), ('\u{1ee6}', "U"), ('\u{1ee7}', "u"), ('\u{1ee8}', "U"), ('\u{1ee9}', "u"),
+	('\u{1eea}', "U"), ('\u{1eeb}', "u"), ('\u{1eec}', "U"), ('\u{1eed}', "u"), ('\u{1eee}', "U"), ('\u{1eef}', "u"),
+	('\u{1ef0}', "U"), ('\u{1ef1}', "u"), ('\u{1ef2}', "Y"), ('\u{1ef3}', "y"), ('\u{1ef4}', "Y"), ('\u{1ef5}', "y"),
+	('\u{1ef6}', "Y"), ('\u{1ef7}', "y"), ('\u{1ef8}', "Y"), ('\u{1ef9}', "y"), ('\u{2002}', " "), ('\u{2003}', " "),
+	('\u{2004}', " "), ('\u{2005}', " "), ('\u{2006}', " "), ('\u{2007}', " "), ('\u{2008}', " "), ('\u{2009}', " "),
+	('\u{200a}', " "), ('\u{2010}', "-"), ('\u{2011}', "-"), ('\u{2012}', "-"), ('\u{2013}', "-"), ('\u{2014}', "-"),
+	('\u{2015}', "-"), ('\u{2018}', "'"), ('\u{2019}', "'"), ('\u{201a}', "'"), ('\u{201b}', "'"), ('\u{201c}', "\""),
+	('\u{201d}', "\""), ('\u{201e}', "\""), ('\u{201f}', "\""), ('\u{2022}', "*"), ('\u{2026}', "..."), ('\u{202f}', " "),
+	('\u{2032}', "'"), ('\u{2033}', "\""), ('\u{2039}', "<"), ('\u{203a}', ">"), ('\u{20ac}', "EUR"), ('\u{20bd}', "RUB"),
+	('\u{2116}', "No"), ('\u{2122}', "TM"), ('\u{2190}', "<-"), ('\u{2192}', "->"), ('\u{2212}', "-"),
+];
+
+/// Replaces non-ASCII characters with their transliterations, i.e `"Müller – café"` with `"Muller - cafe"`
+///
+/// Characters without transliteration are replaced with passed replacement if any, otherwise error with
+/// their offset is returned.
+pub(crate) fn transliterate(string: &str, replacement: Option<char>) -> Result<String, String> {
+	let mut out = String::with_capacity(string.len());
+	for (offset, c) in string.chars().enumerate() {
+		if c.is_ascii() {
+			out.push(c);
+			continue;
+		}
+		match TRANSLITERATIONS.binary_search_by_key(&c, |&(from, _)| from) {
+			Ok(index) => out.push_str(TRANSLITERATIONS[index].1),
+			Err(_) => match replacement {
+				Some(replacement) => out.push(replacement),
+				None => {
+					return Err(format!(
+						"Character {:?} (U+{:04X}) at offset {} has no transliteration, use `lossy` to replace it",
+						c, c as u32, offset
+					))
+				}
+			},
+		}
+	}
+	Ok(out)
+}
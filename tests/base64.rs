@@ -0,0 +1,63 @@
+//! Checks `real_c_bytes_base64!` of `base64` feature against RFC 4648 test vectors and a runtime encoder.
+#![cfg(feature = "base64")]
+
+use real_c_string::real_c_bytes_base64;
+
+/// Encodes bytes with padding, in standard or URL-safe alphabet
+fn encode(bytes: &[u8], url_safe: bool) -> String {
+	let mut alphabet: Vec<u8> =
+		b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/".to_vec();
+	if url_safe {
+		alphabet[62] = b'-';
+		alphabet[63] = b'_';
+	}
+	let mut out = String::new();
+	for chunk in bytes.chunks(3) {
+		let mut group = [0; 3];
+		group[..chunk.len()].copy_from_slice(chunk);
+		let value = u32::from_be_bytes([0, group[0], group[1], group[2]]);
+		for i in 0..4 {
+			if i <= chunk.len() {
+				out.push(alphabet[(value >> (18 - 6 * i) & 0x3f) as usize] as char);
+			} else {
+				out.push('=');
+			}
+		}
+	}
+	out
+}
+
+#[test]
+fn rfc4648_vectors() {
+	assert_eq!(real_c_bytes_base64!(""), b"");
+	assert_eq!(real_c_bytes_base64!("Zg=="), b"f");
+	assert_eq!(real_c_bytes_base64!("Zm8="), b"fo");
+	assert_eq!(real_c_bytes_base64!("Zm9v"), b"foo");
+	assert_eq!(real_c_bytes_base64!("Zm9vYg=="), b"foob");
+	assert_eq!(real_c_bytes_base64!("Zm9vYmE="), b"fooba");
+	assert_eq!(real_c_bytes_base64!("Zm9vYmFy"), b"foobar");
+	assert_eq!(real_c_bytes_base64!("Zm9vYg"), b"foob");
+}
+
+#[test]
+fn round_trip() {
+	// Every byte value, so both extra characters of alphabets are used
+	let expected: Vec<u8> = (0..=255).collect();
+	let standard = real_c_bytes_base64!(
+		"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4vMDEyMzQ1Njc4OTo7PD0+P0BBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWltcXV5fYGFiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6e3x9fn+AgYKDhIWGh4iJiouMjY6PkJGSk5SVlpeYmZqbnJ2en6ChoqOkpaanqKmqq6ytrq+wsbKztLW2t7i5uru8vb6/wMHCw8TFxsfIycrLzM3Oz9DR0tPU1dbX2Nna29zd3t/g4eLj5OXm5+jp6uvs7e7v8PHy8/T19vf4+fr7/P3+/w=="
+	);
+	assert_eq!(standard[..], expected[..]);
+	assert_eq!(
+		real_c_bytes_base64!(alphabet = "standard", "/+8="),
+		&[0xff, 0xef]
+	);
+	assert_eq!(encode(&[0xff, 0xef], false), "/+8=");
+	let url_safe = real_c_bytes_base64!(
+		"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4vMDEyMzQ1Njc4OTo7PD0-P0BBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWltcXV5fYGFiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6e3x9fn-AgYKDhIWGh4iJiouMjY6PkJGSk5SVlpeYmZqbnJ2en6ChoqOkpaanqKmqq6ytrq-wsbKztLW2t7i5uru8vb6_wMHCw8TFxsfIycrLzM3Oz9DR0tPU1dbX2Nna29zd3t_g4eLj5OXm5-jp6uvs7e7v8PHy8_T19vf4-fr7_P3-_w"
+	);
+	assert_eq!(url_safe, standard);
+	assert_eq!(
+		encode(url_safe, true).trim_end_matches('='),
+		"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4vMDEyMzQ1Njc4OTo7PD0-P0BBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWltcXV5fYGFiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6e3x9fn-AgYKDhIWGh4iJiouMjY6PkJGSk5SVlpeYmZqbnJ2en6ChoqOkpaanqKmqq6ytrq-wsbKztLW2t7i5uru8vb6_wMHCw8TFxsfIycrLzM3Oz9DR0tPU1dbX2Nna29zd3t_g4eLj5OXm5-jp6uvs7e7v8PHy8_T19vf4-fr7_P3-_w"
+	);
+}
@@ -0,0 +1,344 @@
+//! Checks round trip of strings through `real_c_string_compressed!` of `compression` feature.
+#![cfg(feature = "compression")]
+
+use core::ffi::CStr;
+use real_c_string::{real_c_string, real_c_string_compressed, real_c_string_size};
+
+/// Checks that compressed string is decompressed to the same bytes as emitted by `real_c_string!`
+macro_rules! assert_round_trip {
+	($($string:tt)*) => {{
+		let compressed = unsafe { CStr::from_ptr(real_c_string_compressed!($($string)*)) };
+		let plain = unsafe { CStr::from_ptr(real_c_string!($($string)*)) };
+		assert_eq!(compressed, plain);
+		assert_eq!(compressed.to_bytes_with_nul().len(), real_c_string_size!($($string)*));
+	}};
+}
+
+#[test]
+fn schema() {
+	assert_round_trip!(
+		"CREATE TABLE table_0 (
+    created_at INTEGER,
+    status BLOB,
+    body INTEGER,
+    id TEXT,
+    name INTEGER
+);
+CREATE TABLE table_1 (
+    status TEXT,
+    tag INTEGER,
+    name REAL
+);
+CREATE TABLE table_2 (
+    title INTEGER,
+    name REAL,
+    updated_at INTEGER
+);
+CREATE TABLE table_3 (
+    id REAL,
+    checksum TEXT,
+    created_at INTEGER,
+    owner_id BLOB
+);
+CREATE TABLE table_4 (
+    body INTEGER,
+    created_at INTEGER,
+    name TEXT,
+    title REAL,
+    updated_at REAL,
+    parent_id BLOB,
+    id REAL
+);
+CREATE TABLE table_5 (
+    payload BLOB,
+    parent_id REAL,
+    owner_id BLOB,
+    updated_at REAL,
+    created_at BLOB,
+    title INTEGER,
+    id INTEGER
+);
+CREATE TABLE table_6 (
+    status INTEGER,
+    created_at BLOB,
+    parent_id BLOB,
+    author BLOB,
+    payload REAL,
+    tag REAL,
+    id INTEGER
+);
+CREATE TABLE table_7 (
+    owner_id INTEGER,
+    payload INTEGER,
+    body BLOB
+);
+CREATE TABLE table_8 (
+    body BLOB,
+    payload TEXT,
+    owner_id INTEGER,
+    status REAL,
+    parent_id INTEGER,
+    id TEXT,
+    updated_at BLOB
+);
+CREATE TABLE table_9 (
+    author REAL,
+    updated_at INTEGER,
+    status TEXT,
+    body REAL
+);
+CREATE TABLE table_10 (
+    checksum REAL,
+    owner_id BLOB,
+    created_at REAL,
+    status TEXT,
+    tag TEXT,
+    author INTEGER
+);
+CREATE TABLE table_11 (
+    created_at INTEGER,
+    updated_at REAL,
+    body TEXT,
+    author BLOB
+);
+CREATE TABLE table_12 (
+    id BLOB,
+    created_at TEXT,
+    status INTEGER,
+    checksum REAL,
+    parent_id REAL
+);
+CREATE TABLE table_13 (
+    status TEXT,
+    tag INTEGER,
+    name TEXT,
+    payload REAL,
+    author TEXT,
+    id INTEGER
+);
+CREATE TABLE table_14 (
+    title INTEGER,
+    id BLOB,
+    name INTEGER,
+    author INTEGER,
+    created_at TEXT
+);
+CREATE TABLE table_15 (
+    status INTEGER,
+    created_at INTEGER,
+    body REAL,
+    owner_id REAL,
+    parent_id REAL,
+    checksum REAL,
+    updated_at BLOB
+);
+CREATE TABLE table_16 (
+    created_at BLOB,
+    name REAL,
+    parent_id TEXT
+);
+CREATE TABLE table_17 (
+    id BLOB,
+    updated_at INTEGER,
+    checksum BLOB,
+    parent_id BLOB,
+    created_at TEXT,
+    tag BLOB,
+    status TEXT
+);
+CREATE TABLE table_18 (
+    checksum REAL,
+    tag TEXT,
+    parent_id TEXT,
+    updated_at REAL,
+    title BLOB,
+    author INTEGER,
+    status INTEGER
+);
+CREATE TABLE table_19 (
+    payload REAL,
+    owner_id BLOB,
+    updated_at BLOB,
+    title INTEGER,
+    parent_id TEXT
+);
+CREATE TABLE table_20 (
+    updated_at BLOB,
+    payload TEXT,
+    tag REAL
+);
+CREATE TABLE table_21 (
+    title TEXT,
+    id REAL,
+    payload TEXT,
+    parent_id REAL,
+    name BLOB,
+    checksum INTEGER,
+    updated_at REAL
+);
+CREATE TABLE table_22 (
+    status INTEGER,
+    author TEXT,
+    name REAL,
+    created_at TEXT,
+    title REAL,
+    checksum BLOB
+);
+CREATE TABLE table_23 (
+    checksum INTEGER,
+    tag INTEGER,
+    created_at TEXT,
+    id REAL
+);
+CREATE TABLE table_24 (
+    updated_at BLOB,
+    id TEXT,
+    owner_id BLOB,
+    tag BLOB
+);
+CREATE TABLE table_25 (
+    status TEXT,
+    created_at TEXT,
+    id INTEGER,
+    parent_id REAL,
+    payload TEXT,
+    tag INTEGER,
+    checksum TEXT
+);
+CREATE TABLE table_26 (
+    created_at INTEGER,
+    payload BLOB,
+    title REAL,
+    name INTEGER
+);
+CREATE TABLE table_27 (
+    id REAL,
+    updated_at INTEGER,
+    author INTEGER,
+    owner_id REAL,
+    tag BLOB,
+    name TEXT,
+    title BLOB
+);
+CREATE TABLE table_28 (
+    checksum TEXT,
+    tag REAL,
+    payload TEXT,
+    author REAL,
+    updated_at INTEGER,
+    owner_id REAL
+);
+CREATE TABLE table_29 (
+    parent_id TEXT,
+    name BLOB,
+    body INTEGER,
+    updated_at TEXT,
+    status BLOB,
+    author TEXT
+);
+CREATE TABLE table_30 (
+    created_at REAL,
+    payload TEXT,
+    updated_at TEXT,
+    name TEXT,
+    status REAL
+);
+CREATE TABLE table_31 (
+    status BLOB,
+    parent_id INTEGER,
+    tag BLOB,
+    updated_at REAL,
+    author REAL,
+    checksum INTEGER,
+    id REAL
+);
+CREATE TABLE table_32 (
+    checksum INTEGER,
+    title TEXT,
+    owner_id INTEGER,
+    tag INTEGER,
+    name BLOB
+);
+CREATE TABLE table_33 (
+    id BLOB,
+    created_at REAL,
+    owner_id TEXT,
+    author REAL,
+    status BLOB
+);
+CREATE TABLE table_34 (
+    owner_id REAL,
+    id INTEGER,
+    created_at BLOB
+);
+CREATE TABLE table_35 (
+    body INTEGER,
+    name TEXT,
+    owner_id INTEGER
+);
+CREATE TABLE table_36 (
+    name REAL,
+    payload BLOB,
+    id TEXT,
+    parent_id INTEGER,
+    checksum TEXT
+);
+CREATE TABLE table_37 (
+    created_at TEXT,
+    owner_id TEXT,
+    id BLOB
+);
+CREATE TABLE table_38 (
+    checksum TEXT,
+    updated_at BLOB,
+    owner_id BLOB,
+    payload INTEGER,
+    tag BLOB
+);
+CREATE TABLE table_39 (
+    id TEXT,
+    tag REAL,
+    checksum TEXT
+);
+"
+	);
+}
+
+#[test]
+fn repeated() {
+	// Matches overlap with bytes they produce
+	assert_round_trip!("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa");
+	assert_round_trip!("abcabcabcabcabcabcabcabcabcabcabcabcabcabcabcabcabcabcabcabcabcabcabc!");
+}
+
+#[test]
+fn short() {
+	assert_round_trip!("");
+	assert_round_trip!("a");
+	assert_round_trip!("ab");
+	assert_round_trip!(utf8 "привет, привет, привет");
+}
+
+#[test]
+fn first_use() {
+	// Every use returns the same buffer, which is only decompressed once
+	fn license() -> *const i8 {
+		real_c_string_compressed!("MIT License MIT License MIT License")
+	}
+	let threads: Vec<_> = (0..8)
+		.map(|_| std::thread::spawn(|| license() as usize))
+		.collect();
+	for thread in threads {
+		assert_eq!(thread.join().unwrap(), license() as usize);
+	}
+	let license = unsafe { CStr::from_ptr(license()) };
+	assert_eq!(license.to_bytes(), b"MIT License MIT License MIT License");
+}
+
+#[test]
+fn custom_terminator() {
+	let size = real_c_string_size!(terminator = 0xff, terminators = 2, "abcabcabc");
+	let string = real_c_string_compressed!(terminator = 0xff, terminators = 2, "abcabcabc");
+	let bytes = unsafe { std::slice::from_raw_parts(string as *const u8, size) };
+	assert_eq!(bytes, b"abcabcabc\xff\xff");
+}
@@ -0,0 +1,73 @@
+//! Checks that macros returning pointers can be used in `const` items, `const fn` and statics.
+
+use core::ffi::CStr;
+use real_c_string::{
+	real_c_pstring, real_c_static, real_c_string, real_c_string_ptr_len, real_c_string_size,
+	real_c_unicode_string, real_c_wstring, real_c_wstring_ptr_len,
+};
+
+const GREETING: *const i8 = real_c_string!("hi");
+const WIDE_GREETING: *const i16 = real_c_wstring!("привет");
+const PTR_LEN: (*const i8, usize) = real_c_string_ptr_len!("hello");
+const WIDE_PTR_LEN: (*const i16, usize) = real_c_wstring_ptr_len!("hello");
+const PASCAL: *const u8 = real_c_pstring!("pascal");
+const UNICODE_STRING: (u16, u16, *const u16) = real_c_unicode_string!("unicode");
+
+const fn greeting(wide: bool) -> *const u8 {
+	if wide {
+		real_c_wstring!("hi") as *const u8
+	} else {
+		real_c_string!("hi") as *const u8
+	}
+}
+
+/// Wrapper, which allows putting pointer to string in static, as pointers are not `Sync`
+struct StaticCString(*const i8);
+
+// Pointed string is immutable, and lives as long as the program
+unsafe impl Sync for StaticCString {}
+
+static WRAPPED: StaticCString = StaticCString(real_c_string!("wrapped"));
+
+real_c_static!(STATIC = "static");
+
+struct Protocol;
+
+impl Protocol {
+	const NAME: *const i8 = real_c_string!("HTTP/1.1");
+	// Length is only known to macro, but the buffer can still be sized by it
+	const BUFFER: [u8; real_c_string_size!("HTTP/1.1")] = [0; real_c_string_size!("HTTP/1.1")];
+}
+
+fn c_str(string: *const i8) -> &'static [u8] {
+	unsafe { CStr::from_ptr(string) }.to_bytes()
+}
+
+#[test]
+fn const_items() {
+	assert_eq!(c_str(GREETING), b"hi");
+	assert_eq!(unsafe { *(WIDE_GREETING as *const [i16; 7]) }[6], 0);
+	assert_eq!(c_str(PTR_LEN.0), b"hello");
+	assert_eq!(PTR_LEN.1, 5);
+	assert_eq!(WIDE_PTR_LEN.1, 5);
+	assert_eq!(unsafe { *(PASCAL as *const [u8; 7]) }, *b"\x06pascal");
+	assert_eq!(UNICODE_STRING.0, 14);
+	assert_eq!(UNICODE_STRING.1, 16);
+	assert_eq!(unsafe { *UNICODE_STRING.2.add(6) }, u16::from(b'e'));
+	assert_eq!(c_str(Protocol::NAME), b"HTTP/1.1");
+	assert_eq!(Protocol::BUFFER.len(), 9);
+}
+
+#[test]
+fn const_fn() {
+	const NARROW: *const u8 = greeting(false);
+	assert_eq!(unsafe { *(NARROW as *const [u8; 3]) }, *b"hi\0");
+	let wide = greeting(true) as *const [u16; 3];
+	assert_eq!(unsafe { *wide }, [b'h' as u16, b'i' as u16, 0]);
+}
+
+#[test]
+fn statics() {
+	assert_eq!(c_str(WRAPPED.0), b"wrapped");
+	assert_eq!(c_str(STATIC.as_ptr()), b"static");
+}
@@ -0,0 +1 @@
+/* Пока */ const char *greeting = "Привет"; // Приветствие
@@ -0,0 +1,11 @@
+#version 330 core
+
+/*
+ * Fills fragment with uniform color.
+ * Comment markers inside of the strings are kept: "/* not closed
+ */
+uniform vec4 color; // set by the host
+
+void main() {
+	gl_FragColor = color;   /* no lighting */
+}
@@ -0,0 +1,4 @@
+/*
+ * Greeting, shown on start
+ */
+const char *greeting = "Привет";
@@ -0,0 +1,27 @@
+//! Checks that functions of `real_c_string_fn!` are exported, and can be imported as C would.
+
+use core::ffi::CStr;
+use real_c_string::real_c_string_fn;
+
+real_c_string_fn! {
+	pub extern "C" fn real_c_string_test_app_version() -> *const i8 = "1.4.2";
+	#[export_name = "real_c_string_test_app_name"]
+	pub fn app_name() -> *const u16 = "Приложение";
+}
+
+mod c {
+	extern "C" {
+		pub fn real_c_string_test_app_version() -> *const i8;
+		pub fn real_c_string_test_app_name() -> *const u16;
+	}
+}
+
+#[test]
+fn imported() {
+	let version = unsafe { CStr::from_ptr(c::real_c_string_test_app_version()) };
+	assert_eq!(version.to_str().unwrap(), "1.4.2");
+	let name = unsafe { std::slice::from_raw_parts(c::real_c_string_test_app_name(), 11) };
+	let expected: Vec<u16> = "Приложение\0".encode_utf16().collect();
+	assert_eq!(name, expected);
+	assert_eq!(app_name(), unsafe { c::real_c_string_test_app_name() });
+}
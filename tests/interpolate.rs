@@ -0,0 +1,68 @@
+//! Checks `interpolate` option with variables set by cargo when compiling tests.
+
+use core::ffi::CStr;
+use real_c_string::{real_c_string, real_c_string_list, real_c_wstring_array};
+
+fn c_str(string: *const i8) -> &'static str {
+	unsafe { CStr::from_ptr(string) }.to_str().unwrap()
+}
+
+#[test]
+fn variables() {
+	assert_eq!(
+		c_str(real_c_string!(interpolate "${CARGO_PKG_NAME}-${CARGO_PKG_VERSION}")),
+		format!("{}-{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+	);
+	assert_eq!(
+		c_str(real_c_string!(interpolate "https://" "${CARGO_PKG_NAME}.example.com")),
+		concat!("https://", env!("CARGO_PKG_NAME"), ".example.com")
+	);
+}
+
+#[test]
+fn fallback() {
+	assert_eq!(
+		c_str(
+			real_c_string!(interpolate "${REAL_C_STRING_SURELY_UNSET_VARIABLE:-https://localhost}")
+		),
+		"https://localhost"
+	);
+	assert_eq!(
+		c_str(real_c_string!(interpolate "${CARGO_PKG_NAME:-unknown}")),
+		env!("CARGO_PKG_NAME")
+	);
+	assert_eq!(
+		c_str(real_c_string!(interpolate "${REAL_C_STRING_SURELY_UNSET_VARIABLE:-}")),
+		""
+	);
+}
+
+#[test]
+fn escapes() {
+	assert_eq!(
+		c_str(real_c_string!(interpolate "$$5 ${CARGO_PKG_NAME}$$")),
+		"$5 real_c_string$"
+	);
+	// Without option, strings are kept as is
+	assert_eq!(
+		c_str(real_c_string!("${CARGO_PKG_NAME}")),
+		"${CARGO_PKG_NAME}"
+	);
+}
+
+#[test]
+fn applied_before_options() {
+	assert_eq!(
+		c_str(real_c_string!(interpolate upper "${CARGO_PKG_NAME}")),
+		"REAL_C_STRING"
+	);
+	let wide = real_c_wstring_array!(interpolate "${CARGO_PKG_AUTHORS}");
+	let expected: Vec<i16> = env!("CARGO_PKG_AUTHORS")
+		.encode_utf16()
+		.map(|u| u as i16)
+		.chain([0])
+		.collect();
+	assert_eq!(wide[..], expected[..]);
+	let list = real_c_string_list!(interpolate "${CARGO_PKG_NAME}", "$$");
+	assert_eq!(c_str(unsafe { *list.add(1) }), "$");
+}
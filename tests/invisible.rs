@@ -0,0 +1,32 @@
+//! Checks that bidirectional control and invisible characters are passed through with `allow_invisible`.
+//!
+//! Rejection without the option is covered by `compile_fail` doc tests of crate documentation.
+
+use real_c_string::{real_c_string_array, real_c_wstring_array};
+
+#[test]
+fn right_to_left_override() {
+	let narrow = real_c_string_array!(allow_invisible utf8 "abc\u{202e}def");
+	let expected: Vec<i8> = "abc\u{202e}def\0".bytes().map(|b| b as i8).collect();
+	assert_eq!(narrow[..], expected[..]);
+	let wide = real_c_wstring_array!(allow_invisible "\u{202e}lmth.exe");
+	assert_eq!(wide[0], 0x202e);
+	assert_eq!(wide.len(), 10);
+}
+
+#[test]
+fn zero_width_joiner() {
+	let family = real_c_wstring_array!(allow_invisible "👨\u{200d}👩\u{200d}👧");
+	let expected: Vec<i16> = "👨\u{200d}👩\u{200d}👧\0"
+		.encode_utf16()
+		.map(|u| u as i16)
+		.collect();
+	assert_eq!(family[..], expected[..]);
+}
+
+#[test]
+fn visible_characters() {
+	// Characters around rejected ranges are not affected
+	let wide = real_c_wstring_array!("\u{200a}\u{2010}\u{2029}\u{206a}");
+	assert_eq!(wide, [0x200a, 0x2010, 0x2029, 0x206a, 0]);
+}
@@ -0,0 +1,41 @@
+//! Checks that large strings are emitted bit-identical to the input, which is emitted compactly for them.
+
+use real_c_string::{real_c_string, real_c_string_repeat, real_c_wstring_repeat};
+
+/// Text of 16 characters, every one of which is stored in a single byte
+const NARROW: &str = "\tÿ\"\\ Ac9\0\x7f\r\n~é\x01!";
+/// Text of 16 characters, including ones stored as surrogate pairs
+const WIDE: &str = "a\"\\ 😀Жё\u{ffff}\n\0\u{10ffff}\u{7f}z🦀.";
+
+#[test]
+fn narrow_100kb() {
+	let string = real_c_string_repeat!(allow_interior_nul "\tÿ\"\\ Ac9\0\x7f\r\n~é\x01!", 6400);
+	let expected: Vec<i8> = NARROW.chars().map(|c| c as u32 as i8).collect();
+	assert_eq!(expected.len(), 16);
+	let string = unsafe { std::slice::from_raw_parts(string, 16 * 6400 + 1) };
+	for chunk in string[..16 * 6400].chunks(16) {
+		assert_eq!(chunk, expected.as_slice());
+	}
+	assert_eq!(string[16 * 6400], 0);
+}
+
+#[test]
+fn wide_100kb() {
+	let string = real_c_wstring_repeat!(
+		allow_interior_nul "a\"\\ 😀Жё\u{ffff}\n\0\u{10ffff}\u{7f}z🦀.",
+		3200
+	);
+	let expected: Vec<i16> = WIDE.encode_utf16().map(|u| u as i16).collect();
+	let len = expected.len() * 3200;
+	let string = unsafe { std::slice::from_raw_parts(string, len + 1) };
+	for chunk in string[..len].chunks(expected.len()) {
+		assert_eq!(chunk, expected.as_slice());
+	}
+	assert_eq!(string[len], 0);
+}
+
+#[test]
+fn empty() {
+	assert_eq!(0, unsafe { *real_c_string!("") });
+	assert_eq!(0, unsafe { *real_c_string::real_c_wstring!("") });
+}
@@ -0,0 +1,27 @@
+use real_c_string::{real_c_file, real_c_location, real_c_module_path};
+use std::ffi::CStr;
+
+#[test]
+fn file_is_terminated_and_names_invoking_file() {
+	let file = real_c_file!();
+	let bytes = unsafe { CStr::from_ptr(file) }.to_bytes_with_nul();
+	assert_eq!(bytes.last(), Some(&0));
+	assert!(std::str::from_utf8(bytes).unwrap().contains("location.rs"));
+}
+
+#[test]
+fn location_contains_file_and_line() {
+	let line = line!() + 1;
+	let location = unsafe { CStr::from_ptr(real_c_location!()) }
+		.to_str()
+		.unwrap();
+	assert_eq!(location, format!("{}:{}", file!(), line));
+	assert!(location.ends_with(&format!("location.rs:{}", line)));
+}
+
+#[test]
+fn module_path_is_terminated() {
+	let module = unsafe { CStr::from_ptr(real_c_module_path!()) };
+	assert_eq!(module.to_str().unwrap(), module_path!());
+	assert_eq!(module.to_bytes_with_nul().last(), Some(&0));
+}
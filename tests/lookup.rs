@@ -0,0 +1,34 @@
+//! Checks that function of `real_c_string_lookup!` can be exported to C.
+
+use core::ffi::CStr;
+use real_c_string::real_c_string_lookup;
+
+real_c_string_lookup! {
+	#[no_mangle]
+	pub extern "C" fn real_c_string_test_get_msg(u32) -> *const i8;
+	1 => "ready",
+	2 => "busy",
+	0xFFFF => "unknown",
+	_ => "no such message",
+}
+
+mod c {
+	extern "C" {
+		pub fn real_c_string_test_get_msg(id: u32) -> *const i8;
+	}
+}
+
+fn msg(id: u32) -> &'static str {
+	unsafe { CStr::from_ptr(c::real_c_string_test_get_msg(id)) }
+		.to_str()
+		.unwrap()
+}
+
+#[test]
+fn exported() {
+	assert_eq!(msg(1), "ready");
+	assert_eq!(msg(2), "busy");
+	assert_eq!(msg(0xFFFF), "unknown");
+	assert_eq!(msg(3), "no such message");
+	assert_eq!(msg(u32::MAX), "no such message");
+}
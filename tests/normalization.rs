@@ -0,0 +1,36 @@
+//! Checks `normalize` option, which is only available with `normalization` feature.
+#![cfg(feature = "normalization")]
+
+use real_c_string::{real_c_string_array, real_c_wstring_array};
+
+#[test]
+fn nfc() {
+	let decomposed = real_c_string_array!(normalize = "nfc", "cafe\u{301}");
+	let precomposed = real_c_string_array!(normalize = "nfc", "caf\u{e9}");
+	assert_eq!(decomposed, precomposed);
+	assert_eq!(decomposed, real_c_string_array!("caf\u{e9}"));
+
+	let decomposed = real_c_wstring_array!(normalize = "nfc", "cafe\u{301}");
+	assert_eq!(decomposed, real_c_wstring_array!("caf\u{e9}"));
+}
+
+#[test]
+fn nfd() {
+	let decomposed = real_c_string_array!(utf8, normalize = "nfd", "caf\u{e9}");
+	let expected: Vec<i8> = "cafe\u{301}\0".bytes().map(|b| b as i8).collect();
+	assert_eq!(decomposed[..], expected[..]);
+
+	// Combining marks are reordered by their combining class
+	let reordered = real_c_wstring_array!(normalize = "nfd", "\u{1e0b}\u{323}");
+	let expected: Vec<i16> = "d\u{323}\u{307}\0"
+		.encode_utf16()
+		.map(|u| u as i16)
+		.collect();
+	assert_eq!(reordered[..], expected[..]);
+}
+
+#[test]
+fn hangul() {
+	let composed = real_c_wstring_array!(normalize = "nfc", "\u{1100}\u{1161}\u{11a8}");
+	assert_eq!(composed, real_c_wstring_array!("\u{ac01}"));
+}
@@ -0,0 +1,42 @@
+//! Checks that strings are enumerated by `real_c_string_registry!`, unless they opt out with `no_register`.
+#![cfg(feature = "registry")]
+
+use real_c_string::{real_c_string, real_c_string_registry};
+
+mod defined {
+	use real_c_string::{real_c_string, real_c_wstring};
+
+	pub fn names() -> (*const i8, *const i16) {
+		(
+			real_c_string!(encoding = "windows-1251", "Реестр"),
+			real_c_wstring!(keep "registry.wide"),
+		)
+	}
+}
+
+#[test]
+fn registered() {
+	let (_, wide) = defined::names();
+	let strings: Vec<_> = real_c_string_registry!().collect();
+	assert!(strings.contains(&(
+		&b"\xd0\xe5\xe5\xf1\xf2\xf0\0"[..],
+		"windows-1251",
+		"registry::defined"
+	)));
+	let expected: Vec<u8> = "registry.wide\0"
+		.encode_utf16()
+		.flat_map(u16::to_ne_bytes)
+		.collect();
+	assert!(strings.contains(&(&expected[..], "utf-16", "registry::defined")));
+	// Strings in statics are registered at their addresses
+	assert!(strings
+		.iter()
+		.any(|(bytes, ..)| bytes.as_ptr() == wide as *const u8));
+}
+
+#[test]
+fn opted_out() {
+	let secret = real_c_string!(no_register "registry-secret");
+	assert_eq!(unsafe { *secret }, b'r' as i8);
+	assert!(!real_c_string_registry!().any(|(bytes, ..)| bytes.starts_with(b"registry-secret")));
+}
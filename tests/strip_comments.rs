@@ -0,0 +1,38 @@
+//! Checks `strip_comments` option with included files.
+
+use core::ffi::CStr;
+use real_c_string::{real_c_include_str, real_c_include_wstr, real_c_string};
+
+fn c_str(string: *const i8) -> &'static str {
+	unsafe { CStr::from_ptr(string) }.to_str().unwrap()
+}
+
+#[test]
+fn included_shader() {
+	assert_eq!(
+		c_str(real_c_include_str!(strip_comments "tests/data/shader.frag")),
+		"#version 330 core\nuniform vec4 color;\nvoid main() {\ngl_FragColor = color;\n}"
+	);
+	let wide = real_c_include_wstr!(strip_comments "tests/data/shader.frag");
+	assert_eq!(unsafe { *wide }, i16::from(b'#'));
+}
+
+#[test]
+fn literals() {
+	assert_eq!(
+		c_str(real_c_string!(strip_comments r#"url = "http://example.com"; // comment"#)),
+		r#"url = "http://example.com";"#
+	);
+	assert_eq!(
+		c_str(real_c_string!(strip_comments r#"quote = '"'; /* " */ slash = '/'"#)),
+		r#"quote = '"'; slash = '/'"#
+	);
+	assert_eq!(
+		c_str(real_c_string!(strip_comments r#"escaped = "\" // \\"; a/b"#)),
+		r#"escaped = "\" // \\"; a/b"#
+	);
+	assert_eq!(
+		c_str(real_c_string!(strip_comments "a/**/b /***/ c")),
+		"a b c"
+	);
+}
@@ -0,0 +1,40 @@
+//! Checks `translit` option, which is only available with `transliteration` feature.
+#![cfg(feature = "transliteration")]
+
+use real_c_string::{real_c_string_array, real_c_wstring_array};
+
+#[test]
+fn german() {
+	let name = real_c_string_array!(translit "Müller – naïve Straße");
+	assert_eq!(name, b"Muller - naive Strasse\0".map(|b| b as i8));
+	let upper = real_c_string_array!(translit "ÄÖÜ äöü");
+	assert_eq!(upper, b"AOU aou\0".map(|b| b as i8));
+}
+
+#[test]
+fn french() {
+	let phrase = real_c_string_array!(translit "Ça coûte 10 € — déjà vu, «œuvre»…");
+	assert_eq!(
+		phrase,
+		b"Ca coute 10 EUR - deja vu, <<oeuvre>>...\0".map(|b| b as i8)
+	);
+}
+
+#[test]
+fn cyrillic() {
+	let greeting = real_c_string_array!(translit "Привет, Щукин! Съешь ёжика");
+	assert_eq!(
+		greeting,
+		b"Privet, Shchukin! Sesh yozhika\0".map(|b| b as i8)
+	);
+	let wide = real_c_wstring_array!(translit "Київ");
+	assert_eq!(wide, real_c_wstring_array!("Kiyiv"));
+}
+
+#[test]
+fn untransliterated_characters_are_replaced_with_lossy() {
+	let label = real_c_string_array!(translit lossy "日本 café");
+	assert_eq!(label, b"?? cafe\0".map(|b| b as i8));
+	let label = real_c_string_array!(translit, replacement = '_', "✓ done");
+	assert_eq!(label, b"_ done\0".map(|b| b as i8));
+}
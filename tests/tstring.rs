@@ -0,0 +1,22 @@
+use real_c_string::real_c_tstring;
+
+#[cfg(not(real_c_ansi))]
+#[test]
+fn wide_without_ansi() {
+	const TITLE: *const u16 = real_c_tstring!("Окно");
+	let units: Vec<u16> = "Окно".encode_utf16().chain([0]).collect();
+	assert_eq!(
+		unsafe { std::slice::from_raw_parts(TITLE, units.len()) },
+		units
+	);
+}
+
+#[cfg(real_c_ansi)]
+#[test]
+fn narrow_with_ansi() {
+	const TITLE: *const i8 = real_c_tstring!("Window");
+	assert_eq!(
+		unsafe { std::ffi::CStr::from_ptr(TITLE) }.to_bytes(),
+		b"Window"
+	);
+}
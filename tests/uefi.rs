@@ -0,0 +1,34 @@
+//! Checks expansion of `uefi` macros against type with the same layout and path as
+//! `uefi::CStr16`, so the expansion is checked without depending on uefi.
+#![cfg(feature = "uefi")]
+
+extern crate self as uefi;
+
+#[repr(transparent)]
+pub struct CStr16([u16]);
+
+impl CStr16 {
+	/// # Safety
+	/// Slice should be terminated by its only NUL, and contain only UCS-2 characters
+	pub const unsafe fn from_u16_with_nul_unchecked(codes: &[u16]) -> &Self {
+		&*(codes as *const [u16] as *const Self)
+	}
+
+	pub fn as_ptr(&self) -> *const u16 {
+		self.0.as_ptr()
+	}
+
+	pub fn to_u16_slice_with_nul(&self) -> &[u16] {
+		&self.0
+	}
+}
+
+use real_c_string::real_cstr16;
+
+#[test]
+fn ucs2() {
+	const HELLO: &CStr16 = real_cstr16!("Привет, UEFI!");
+	let expected: Vec<u16> = "Привет, UEFI!\0".encode_utf16().collect();
+	assert_eq!(HELLO.to_u16_slice_with_nul(), &expected[..]);
+	assert_eq!(unsafe { *HELLO.as_ptr() }, expected[0]);
+}
@@ -0,0 +1,20 @@
+//! Checks `upper` and `lower` options with full Unicode case mapping of `unicode-case` feature.
+#![cfg(feature = "unicode-case")]
+
+use real_c_string::{real_c_string_array, real_c_wstring_array};
+
+#[test]
+fn upper() {
+	// Full mapping may change length of string
+	let street = real_c_wstring_array!(upper "Straße");
+	assert_eq!(street, real_c_wstring_array!("STRASSE"));
+
+	let mixed = real_c_string_array!(upper "Ärger mit Öl");
+	assert_eq!(mixed, real_c_string_array!("ÄRGER MIT ÖL"));
+}
+
+#[test]
+fn lower() {
+	let greeting = real_c_wstring_array!(lower "ПРИВЕТ, World");
+	assert_eq!(greeting, real_c_wstring_array!("привет, world"));
+}
@@ -0,0 +1,37 @@
+//! Checks expansion of `widestring` macros against type with the same layout and path as
+//! `widestring::U16CStr`, so the expansion is checked without depending on widestring.
+#![cfg(feature = "widestring")]
+
+extern crate self as widestring;
+
+#[repr(transparent)]
+pub struct U16CStr {
+	inner: [u16],
+}
+
+impl U16CStr {
+	/// # Safety
+	/// Slice should be terminated by its only NUL
+	pub const unsafe fn from_slice_unchecked(slice: &[u16]) -> &Self {
+		&*(slice as *const [u16] as *const Self)
+	}
+
+	pub fn as_slice_with_nul(&self) -> &[u16] {
+		&self.inner
+	}
+}
+
+use real_c_string::real_u16cstr;
+
+#[test]
+fn ascii() {
+	const HELLO: &U16CStr = real_u16cstr!("hello");
+	let expected: Vec<u16> = "hello\0".encode_utf16().collect();
+	assert_eq!(HELLO.as_slice_with_nul(), &expected[..]);
+}
+
+#[test]
+fn non_ascii() {
+	let expected: Vec<u16> = "Привет\0".encode_utf16().collect();
+	assert_eq!(real_u16cstr!("Привет").as_slice_with_nul(), &expected[..]);
+}
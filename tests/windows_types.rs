@@ -0,0 +1,33 @@
+//! Checks expansion of `windows-types` macros against newtypes with the same layout and paths as
+//! `windows::core::{PCSTR, PCWSTR}`, so the expansion is checked without depending on windows-rs.
+#![cfg(feature = "windows-types")]
+
+extern crate self as windows;
+
+pub mod core {
+	#[repr(transparent)]
+	pub struct PCSTR(pub *const u8);
+
+	#[repr(transparent)]
+	pub struct PCWSTR(pub *const u16);
+}
+
+use real_c_string::{real_c_pcstr, real_c_pcwstr};
+
+#[test]
+fn pcstr() {
+	let windows::core::PCSTR(ptr) = real_c_pcstr!("kernel32.dll");
+	let expected = b"kernel32.dll\0";
+	for (i, byte) in expected.iter().enumerate() {
+		assert_eq!(*byte, unsafe { *ptr.add(i) });
+	}
+}
+
+#[test]
+fn pcwstr() {
+	let windows::core::PCWSTR(ptr) = real_c_pcwstr!("Привет");
+	let expected: Vec<u16> = "Привет\0".encode_utf16().collect();
+	for (i, unit) in expected.iter().enumerate() {
+		assert_eq!(*unit, unsafe { *ptr.add(i) });
+	}
+}